@@ -9,16 +9,35 @@
 //! - Mark any Enum Bitfield Variant as invalid instead of forcing it to be the last one.
 //! - Implement Tuple Structs.
 //! - Allow the user to capture the id value in the fields list of a Enum Variant.
-//! - Allow assumed id sizing. we do check the provided id size is large enough so if non is defined
-//!     we could just use the calculated smallest allowable.
 //! - Enable `hex` and `setter` features for enums.
-//! - We may be able to detect `repr(TYPE)` attribute and if so we can get useful information from that.
 //!
 //! # Derive Bitfields
 //! - Implements the [`Bitfields`](https://docs.rs/bondrewd/latest/bondrewd/trait.Bitfields.html) trait
 //! which offers from\into bytes functions that are non-failable and convert the struct from/into sized
 //! u8 arrays ([u8; {total_bit_length * 8}]).
+//!   `to_bytes` and `write_to` encode values by reference, and `update_from` decodes into an existing
+//!   value.
+//! - Implements the [`TryBitfields`](https://docs.rs/bondrewd/latest/bondrewd/trait.TryBitfields.html) trait
+//!   which offers a `try_from_bytes` function that rejects unknown enum ids (when no variant is marked
+//!   `invalid`), invalid `char` code points and non-zero `reserve` fields. Nested bitfields fields
+//!   (including array elements) are decoded with `Bitfields::checked_from_bytes`, which is their own
+//!   `try_from_bytes` when derived, errors name the outer field and the bits within the outer structure.
 //! - `read` and `write` functions that allow the field to be accessed or overwritten within a sized u8 array.
+//! - `{name}View<B>` and `{name}ViewMut<B>` types which wrap any byte container (`[u8; N]`, `&[u8]`,
+//!   `Vec<u8>`, ..) after checking its length and provide the same `read`/`write` functions as methods,
+//!   `into_inner` returns the container. See [Views](#views).
+//! - Enums get `into_bytes_compact`, which returns the bitfield form along with the amount of bytes the
+//!   variant uses (leaving out the fill of smaller variants), and `from_slice_compact` which only needs
+//!   as many bytes as the variant id says the variant uses.
+//! - Enums also get a fieldless `{name}Kind` enum with one variant per variant, `{name}::read_kind` to
+//!   find out which variant a byte array holds without decoding it, `kind(&self)` and `{name}Kind::id()`.
+//! - With the `std` feature of bondrewd, `bondrewd::io::read_from` and `bondrewd::io::write_to` stream
+//!   values through [`std::io::Read`]/[`std::io::Write`]. Enums read their id first then only the bytes
+//!   the variant uses (unless `reverse` is used).
+//! - With the `serde` feature of bondrewd, `#[serde(with = "bondrewd::serde_bytes_form")]` (de)serializes
+//!   a field through its bitfield form (hex for human readable formats, bytes otherwise), and the
+//!   `#[bondrewd(serde)]` attribute derives `Serialize`/`Deserialize` which reject values too large for
+//!   their bit length.
 //! - More information about how each field is handled (bit length, endianness, ..), as well as structure
 //! wide effects (bit position, default field endianness, ..), can be found on the
 //! [`Bitfields Derive`](Bitfields) page.
@@ -112,6 +131,31 @@
 //! }
 //! ```
 //!
+//! # Views
+//! Every `Bitfields` type also gets a `View` and `ViewMut` type which do not copy the bytes out of
+//! the container they wrap, so a packet can be handed between stages of a pipeline and only the
+//! fields that are needed get read or written.
+//! ```
+//! use bondrewd::*;
+//! #[derive(Bitfields)]
+//! #[bondrewd(default_endianness = "be")]
+//! struct Simple {
+//!     #[bondrewd(bit_length = 3)]
+//!     one: u8,
+//!     two: bool,
+//!     #[bondrewd(bit_length = 4)]
+//!     three: u8,
+//! }
+//!
+//! let mut view = SimpleViewMut::new(vec![0u8; Simple::BYTE_SIZE]).unwrap();
+//! view.write_three(9);
+//! assert_eq!(view.read_three(), 9);
+//! let bytes: Vec<u8> = view.into_inner();
+//! let view = SimpleView::new(bytes).unwrap();
+//! assert_eq!(view.read_one(), 0);
+//! assert_eq!(view.read_three(), 9);
+//! ```
+//!
 //! # Crate Features
 //! ### dyn_fns
 //! Slice functions are convenience functions for reading/wring single or multiple fields without reading
@@ -137,6 +181,10 @@
 //!       `write_{field}` functions.
 //!         * `fn read_{field}(&self) -> {field_type} { .. }`
 //!         * `fn write_{field}(&mut self) -> {field_type} { .. }`
+//! * Enum variant access. The Checked structure of an enum has `read_variant_id` and `variant`, which
+//!   reads the id once and returns a `{enum_name}CheckedVariant` with one variant per enum variant, each
+//!   holding a `{enum_name}{variant}Checked` which only has getters for that variant's fields.
+//!     * `fn variant(&self) -> {enum_name}CheckedVariant { .. }`
 //!   
 //! Example Cargo.toml Bondrewd dependency  
 //! `bondrewd = { version = "^0.1", features = ["derive", "dyn_fns"] }`  
@@ -187,13 +235,51 @@
 //!     pub fn write_three(&mut self, three: u8) { .. }
 //! }
 //! ```
+//! ### setters
+//! Adds a `set_{field}` function for each field which keeps the value within the field's bits, numbers
+//! and chars are clamped to the closest value that fits, arrays are clamped per element, and nested
+//! structures and enums are stored as is. `#[bondrewd(checked_setters)]` makes the setters return a
+//! `BitfieldTryError` without changing the field instead, which also checks `enum_primitive` fields and
+//! nested structures (using their `check_fields`). The derived `Bitfields::check_fields(&self)`
+//! returns the same error for the first field which does not fit, hand written implementations get a
+//! default which never fails. Bitfields enums get `set_{variant}_{field}` functions, returning
+//! whether `self` was that variant, and `set_{field}` for `common` fields.
+//! ```
+//! use bondrewd::Bitfields;
+//!
+//! #[derive(Bitfields)]
+//! #[bondrewd(default_endianness = "be")]
+//! struct Clamped {
+//!     #[bondrewd(bit_length = 3)]
+//!     one: u8,
+//! }
+//!
+//! #[derive(Bitfields)]
+//! #[bondrewd(default_endianness = "be", checked_setters)]
+//! struct Checked {
+//!     #[bondrewd(bit_length = 3)]
+//!     one: u8,
+//! }
+//!
+//! let mut clamped = Clamped { one: 0 };
+//! clamped.set_one(9);
+//! assert_eq!(clamped.one, 7);
+//! let mut checked = Checked { one: 0 };
+//! assert!(checked.set_one(9).is_err());
+//! assert_eq!(checked.one, 0);
+//! ```
 //! ### part_eq_enums
 //! Implements [`PartialEq`] for the type which fits the bits of the a [`Bitfields`] enum's id on the enum.
+//! Enums with signed ids use the signed type, `i8` for a 3 bit id holding `-1`.
 //! ### hex_fns
 //! `hex_fns` provided from/into hex functions like from/into bytes. The hex inputs/outputs are \[u8;N\]
 //! where N is double the calculated bondrewd STRUCT_SIZE. Hex encoding and decoding is based off the
 //! [hex](https://crates.io/crates/hex) crate's from/into slice functions but with statically sized
 //! arrays so we could eliminate sizing errors.
+//! `from_hex_str`, `from_binary_str` and `from_base64_str` decode text as it shows up in logs
+//! (`0x` or `0b` prefixes, whitespace, `:` and `_` separators are accepted) and return a
+//! `BitfieldTextError` with the index of the offending character. `write_base64` and `write_binary`
+//! encode into any [`core::fmt::Write`].
 //!
 //! ### Full Example Generated Code
 //! ```
@@ -227,6 +313,26 @@
 //!         output_byte_buffer[6usize] |= four_bytes[0] & 248u8;
 //!         output_byte_buffer
 //!     }
+//!     fn write_to(&self, output_byte_buffer: &mut [u8; 7usize]) {
+//!         *output_byte_buffer = [0u8; 7usize];
+//!         let one = self.one;
+//!         output_byte_buffer[0usize] |= ((one as u8) << 7usize) & 128u8;
+//!         let two = self.two;
+//!         let two_bytes = (two.to_bits().rotate_right(1u32)).to_be_bytes();
+//!         output_byte_buffer[0usize] |= two_bytes[0usize] & 127u8;
+//!         output_byte_buffer[1usize] |= two_bytes[1usize];
+//!         output_byte_buffer[2usize] |= two_bytes[2usize];
+//!         output_byte_buffer[3usize] |= two_bytes[3usize];
+//!         output_byte_buffer[4usize] |= two_bytes[0] & 128u8;
+//!         let three = self.three;
+//!         let three_bytes = (three.rotate_right(7u32)).to_be_bytes();
+//!         output_byte_buffer[4usize] |= three_bytes[1usize] & 127u8;
+//!         output_byte_buffer[5usize] |= three_bytes[0] & 254u8;
+//!         let four = self.four;
+//!         let four_bytes = (four.rotate_right(5u32)).to_be_bytes();
+//!         output_byte_buffer[5usize] |= four_bytes[0usize] & 1u8;
+//!         output_byte_buffer[6usize] |= four_bytes[0] & 248u8;
+//!     }
 //!     fn from_bytes(mut input_byte_buffer: [u8; 7usize]) -> Self {
 //!         let one = Self::read_one(&input_byte_buffer);
 //!         let two = Self::read_two(&input_byte_buffer);
@@ -239,6 +345,12 @@
 //!             four,
 //!         }
 //!     }
+//!     fn update_from(&mut self, input_byte_buffer: &[u8; 7usize]) {
+//!         self.one = Self::read_one(input_byte_buffer);
+//!         self.two = Self::read_two(input_byte_buffer);
+//!         self.three = Self::read_three(input_byte_buffer);
+//!         self.four = Self::read_four(input_byte_buffer);
+//!     }
 //! }
 //! impl SimpleExample {
 //!     #[inline]
@@ -362,6 +474,15 @@ use crate::structs::from_bytes::create_from_bytes_field_quotes_enum;
 /// [example](#bit-positioning-examples)
 /// - `reverse` Defines that the entire byte array should be read backward (first byte index becomes last
 /// byte index). This has no runtime cost. [example](#reverse-example)
+/// - `serde` Implements `Serialize` and `Deserialize` (requires the `serde` feature of bondrewd) using the
+///   fields instead of the bitfield form. Deserializing fails if a number, char, `enum_primitive` value or
+///   array element does not fit in the bits it uses, or `check_fields` of a nested structure fails. Nested
+///   structures and enums must also implement `Serialize` and `Deserialize`.
+/// - `checked_setters` Makes the setters of the `setters` feature return a `Result` instead of clamping
+///   values which do not fit in their field.
+/// - `fill_pattern = {BYTE}` The byte pattern `reserve` fields, `fill_bytes` and enum fill bits are
+///   written with instead of zeros, ex. `0xFF` or `0x55`. Each bit takes the value of the pattern's bit at
+///   the same position in its byte. `try_from_bytes` expects `reserve` fields to match the pattern.
 ///
 /// #### Struct/Variant Attributes
 /// - `enforce_bytes = {BYTES}` Adds a check that requires total bytes defined by fields to equal provided
@@ -376,12 +497,26 @@ use crate::structs::from_bytes::create_from_bytes_field_quotes_enum;
 /// #### Enum Attributes
 /// - `id_bit_length = {BITS}` Describes the amount of bits bondrewd will use to identify which variant is being stored.
 /// [example](#enum-example)
+///     - When no id size is defined the size of the enum's `#[repr(..)]` type is used, otherwise the
+///       smallest amount of bits which fits the largest variant id. A defined size which is too small for
+///       the largest variant id is an error.
+///     - Negative ids make the id signed, it is stored in two's complement using the id bits and `id()`,
+///       `read_variant_id` and `PartialEq` use the matching `iN` type. A signed `#[repr(iN)]` alone
+///       does not, so `#[repr(i8)]` with ids `0..=3` fits in 2 unsigned bits.
 /// - `id_byte_length = {BYTES}` Describes the amount of bytes bondrewd will use to identify which variant is being stored.
+/// - `id_tail` Places the id in the last bits of the enum instead of the first bits (`id_head`, default).
+/// - `id_bits = "{START}..{END}"` Places the id at a specific bit range of the enum, the fields of each variant
+///   are placed around it and none may overlap the id.
+/// - `fill_position = {"trailing" or "after_id"}` Where the fill bits of variants smaller than the largest
+///   variant go, after the variant's fields (default) or between the id and the variant's fields.
 ///
 /// #### Variant Attributes
 /// - `variant_id = {ID}` Tell bondrewd the id value tot use for the variant.
 /// [example](#enum-example).
 /// The id can also be defined by a using discriminates [discriminate-example](#enum-with-discriminates).
+/// - `variant_id = "{IDS}"` Uses the variant for every id in a list of ids and ranges of ids, ex.
+///   `variant_id = "3, 7, 0x10..=0x1F"` or `variant_id = "-3..=-1"`. The variant is written with its first id unless it has a
+///   `capture_id` field, which stores the id that was read and is written as is, see `capture_id`.
 ///
 /// # Field Attributes
 /// - `bit_length = {BITS}` Define the total amount of bits to use when condensed. [example](#simple-example)
@@ -395,19 +530,71 @@ use crate::structs::from_bytes::create_from_bytes_field_quotes_enum;
 /// type). [example](#bitfield-array-examples)
 /// - `element_byte_length = {BYTES}` Describes a byte length for each element of an array. (default array
 /// type). [example](#bitfield-array-examples)
-/// - `enum_primitive = "u8"` Defines the size of the enum. the BitfieldEnum currently only supports u8.
-/// [example](#enum-examples)
+/// - `enum_primitive = "u8"` Defines the size of the enum, which must be `Copy` as `write_to` copies
+/// it. the BitfieldEnum currently only supports u8. [example](#enum-examples)
 /// - `struct_size = {SIZE}` Defines the field as a struct which implements the Bitfield trait and the
 /// BYTE_SIZE const defined in said trait. [example](#bitfield-struct-as-field-examples)
+///     - Fields of a nested struct or enum (including a `Bitfields` enum held by a variant, which
+///       dispatches on its own id after the outer id) get a `read_{field}_bytes(&[u8; N])` function returning
+///       the nested value in its bitfield form, so its `read_` functions can reach the inner fields. The
+///       `bit_length` of the field is checked at compile time to be at least the `BIT_SIZE` of its type.
+///     - `read_through = "{READ}: {TYPE}, .."` also generates `read_{field}_{READ}(&[u8; N]) -> TYPE` for
+///       each listed `read_{READ}` function of the nested type, the field name is left out for the tuple
+///       field of a variant (`read_{variant}_{READ}`). A nested enum can list its own `read_through`
+///       functions so the outermost type reaches any level, ex. `read_telemetry_housekeeping_temperature_value`.
+///       `from_bytes` still decodes one level at a time by passing the nested bits to the nested `from_bytes`.
 /// - `reserve` Defines that this field should be ignored in from and into bytes functions.
 /// [example](#reserve-examples)
 ///     - Reserve requires the fields type to impl ['Default'](https://doc.rust-lang.org/std/default/trait.Default.html).
 /// due to from_bytes needed to provided a value.
+///     - `fill_pattern = {BYTE}` can be used with `reserve` to override the structure's `fill_pattern`.
+/// - `count_from = "{FIELD}"` Defines a `Vec` or `heapless::Vec` field whose amount of elements is read
+///   from the provided number FIELD. Must be the last field and also requires `max = {ELEMENTS}`.
+///   [example](#variable-length-array-example)
+///     - Structures using `count_from` implement `BitfieldsVariable` instead of `Bitfields`.
+/// - `crc = "{ALGORITHM}", covers = "{RANGE}"` Defines an unsigned number field holding a CRC which
+///   `into_bytes` computes, `ALGORITHM` is a constant in `bondrewd::checksum` (`crc8`, `crc16_ccitt_false`,
+///   `crc16_xmodem`, `crc16_arc`, `crc32`, `crc32c`) and the bit length of the field must match its width.
+/// - `parity = "{even/odd}", covers = "{RANGE}"` Defines a `bool` field holding the parity bit of the
+///   covered bits, which `into_bytes` computes.
+///     - `RANGE` is either a range of byte indices like `"0..6"` or `"2.."`, or a range of fields
+///       like `"field_a..field_c"` which covers both fields and everything between them. CRCs can only cover
+///       whole bytes.
+///     - A `verify_{field}(&[u8; N]) -> bool` function is generated for each checksum field.
+///     - A checksum covering another checksum field is filled in after it, checksums covering each
+///       other are an error.
+///     - `write_{field}` functions and views do not update checksums, `update_checksums(&mut [u8; N])`
+///       fills them in again after writing fields one at a time.
+/// - `length_of = "{FIELD}"` Defines an unsigned number field holding the size in bytes of FIELD, which
+///   `into_bytes` (or `into_slice` when FIELD is a `count_from` array) computes. `try_from_bytes` and
+///   `from_slice` return an error if the value does not match the size.
+///     - `adjust = {AMOUNT}` is added to the size before it is stored, ex. `adjust = -1` for CCSDS
+///       packet data lengths.
+/// - `constant = {VALUE}` Defines a field which `into_bytes` always writes as VALUE. The field must use
+///   the zero-sized `bondrewd::Constant<T>` type, where `T` is the unsigned number or `bool` the value
+///   is stored as, so the structure holds no value. `write_{field}` takes no value and writes VALUE,
+///   `read_{field}` returns the `T` the bytes contain. A `check_constants(&[u8; N])` function is
+///   generated which returns an error naming the first constant field that does not contain its value,
+///   and `try_from_bytes` and `from_slice` perform the same check.
+/// - `tag_from = "{FIELD}"` Stores a `Bitfields` enum field without its id, the variant is chosen by
+///   the number or `enum_primitive` FIELD instead. `into_bytes` writes the id of the enum into FIELD, so
+///   the value of FIELD in the structure is ignored. The `bit_length` of the enum field must be the size
+///   of the enum without its id, the enum's `PAYLOAD_BYTE_SIZE`, `into_payload_bytes` and
+///   `from_payload_bytes` are used to store it. The `write_` functions of the enum field (including
+///   views) and its setter also write the id into FIELD.
 ///
 /// # Enum Attributes
-/// - `capture_id` Tells Bondrewd to put the value for id in the field on reads, fields
-/// with this attribute do NOT get written to the bytes to prevent users from creating improper
-/// byte values. [example](#capture-id)
+/// - `capture_id` Tells Bondrewd to put the value for id in the field on reads, and to write the value
+///   of the field as the id. Any variant can capture its id, which is useful for variants that use a range
+///   of ids. `TryBitfields::try_into_bytes` returns an error instead of writing a captured id that does not
+///   belong to its variant (for the last variant, an id used by another variant). `into_bytes`, `to_bytes`
+///   and `write_to` do not validate the captured id and write it as is, debug builds only check it with
+///   a `debug_assert!`. [example](#capture-id)
+/// - `common` Marks a field every variant has, such as a sequence count. The field and its attributes
+///   are declared once with `common`, the other variants only declare a field with the same name and
+///   type. Common fields are placed right after the id in every variant (so `fill_position = "after_id"`
+///   can not be used with them), and the enum gets `read_{field}`/`write_{field}` functions along with a
+///   `{field}(&self)` function which works for any variant.
 ///
 /// # Experimental Field Attributes
 /// if you decide to use these remember that they have not been exhaustively tested. when using
@@ -1338,6 +1525,37 @@ use crate::structs::from_bytes::create_from_bytes_field_quotes_enum;
 /// assert_eq!(false, reconstructed.flag_five);
 /// assert_eq!(false, reconstructed.flag_six);
 /// ```
+/// # Variable Length Array Example
+/// The element count is read from `count` before the elements are read, and `count` is always
+/// written as the length of `samples`. A `count_from` array must be the last field, declaring another
+/// field after it is a compile error.
+/// ```
+/// use bondrewd::*;
+/// #[derive(Bitfields)]
+/// #[bondrewd(default_endianness = "be")]
+/// struct Samples {
+///     #[bondrewd(bit_length = 4)]
+///     version: u8,
+///     #[bondrewd(bit_length = 4)]
+///     count: u8,
+///     #[bondrewd(count_from = "count", max = 15)]
+///     samples: Vec<u16>,
+/// }
+///
+/// assert_eq!(Samples::HEADER_BYTE_SIZE, 1);
+/// assert_eq!(Samples::MAX_BYTE_SIZE, 31);
+/// let bytes = Samples {
+///     version: 1,
+///     count: 0,
+///     samples: vec![2, 3],
+/// }
+/// .into_vec()
+/// .unwrap();
+/// assert_eq!(bytes, vec![0b0001_0010, 0, 2, 0, 3]);
+/// let samples = Samples::from_slice(&bytes).unwrap();
+/// assert_eq!(samples.count, 2);
+/// assert_eq!(samples.samples, vec![2, 3]);
+/// ```
 /// # Enum Example
 /// Because enums can provide a lot of ambiguity there is a requirement that The last variant is
 /// always considered the "Invalid Variant", which simply means that it will be a
@@ -1418,6 +1636,28 @@ use crate::structs::from_bytes::create_from_bytes_field_quotes_enum;
 ///         }
 ///         output_byte_buffer
 ///     }
+///     fn write_to(&self, output_byte_buffer: &mut [u8; 3usize]) {
+///         *output_byte_buffer = [0u8; 3usize];
+///         match self {
+///             Self::One { a } => {
+///                 Self::write_id(output_byte_buffer, 1);
+///                 Self::write_one_a(output_byte_buffer, *a);
+///             }
+///             Self::Two { a, b } => {
+///                 Self::write_id(output_byte_buffer, 2);
+///                 Self::write_two_a(output_byte_buffer, *a);
+///                 Self::write_two_b(output_byte_buffer, *b);
+///             }
+///             Self::Three { d, e } => {
+///                 Self::write_id(output_byte_buffer, 3);
+///                 Self::write_three_d(output_byte_buffer, *d);
+///                 Self::write_three_e(output_byte_buffer, *e);
+///             }
+///             Self::Idk {} => {
+///                 Self::write_id(output_byte_buffer, 0);
+///             }
+///         }
+///     }
 ///     fn from_bytes(mut input_byte_buffer: [u8; 3usize]) -> Self {
 ///         let id = Self::read_id(&input_byte_buffer);
 ///         match id {
@@ -1644,14 +1884,15 @@ use crate::structs::from_bytes::create_from_bytes_field_quotes_enum;
 /// // fields with capture_id will use the id_bit_length so defining the bit_length is unnecessary.
 /// assert_eq!(Thing::BYTE_SIZE, 3);
 /// assert_eq!(Thing::BIT_SIZE, 18);
-/// // fields that are capturing the id do not write.
+/// // fields that are capturing the id are written as the id.
 /// let mut bytes = Thing::Idk { id: 3, a: 0 }.into_bytes();
-/// // despite setting the id to 3 it will be 0 on output, this is to prevent
-/// // users from providing a valid id when it should not be.
 /// assert_eq!(bytes[0], 0b11000000);
 /// assert_eq!(bytes[1], 0b00000000);
 /// assert_eq!(bytes[2], 0b00000000);
-/// // but the id can be set to anything using the write_variant_id function.
+/// // try_into_bytes refuses ids that would be read back as another variant.
+/// assert!(Thing::Idk { id: 1, a: 0 }.try_into_bytes().is_err());
+/// assert_eq!(Thing::Idk { id: 3, a: 0 }.try_into_bytes().unwrap(), bytes);
+/// // the id can also be set to anything using the write_variant_id function.
 /// Thing::write_variant_id(&mut bytes, 3);
 /// // the id is now 3
 /// assert_eq!(bytes[0], 0b11000000);
@@ -1713,6 +1954,32 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
     }
     match struct_info {
         ObjectInfo::Struct(struct_info) => {
+            // structures with a variable length array can not implement Bitfields because their size
+            // is only known at runtime.
+            if let Some(ref variable) = struct_info.variable_array {
+                if !struct_info.checksums.is_empty() {
+                    return TokenStream::from(
+                        syn::Error::new(
+                            variable.ident.span(),
+                            "crc and parity fields are not supported on structures with a count_from array",
+                        )
+                        .to_compile_error(),
+                    );
+                }
+                if struct_info.attrs.serde {
+                    return TokenStream::from(
+                        syn::Error::new(
+                            variable.ident.span(),
+                            "serde is not supported on structures with a count_from array",
+                        )
+                        .to_compile_error(),
+                    );
+                }
+                return match structs::variable::create_variable_quotes(&struct_info, variable) {
+                    Ok(q) => TokenStream::from(q),
+                    Err(err) => TokenStream::from(err.to_compile_error()),
+                };
+            }
             // get a list of all fields into_bytes logic which puts there bytes into an array called
             // output_byte_buffer.
             let fields_into_bytes =
@@ -1736,6 +2003,9 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
             }
 
             let from_bytes_quote = fields_from_bytes.from_bytes_fn;
+            let write_to_quote = fields_into_bytes.write_to_fn;
+            let update_from_quote = fields_from_bytes.update_from_fn;
+            let stream_quote = fields_from_bytes.stream_fns;
             let mut peek_quotes = fields_from_bytes.peek_field_fns;
 
             if let Some(peek_slice_quote) = fields_from_bytes.peek_slice_field_fns {
@@ -1745,7 +2015,10 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
                 }
             }
             let setters_quote = if setters {
-                match structs::struct_fns::create_setters_quotes(&struct_info) {
+                match structs::struct_fns::create_setters_quotes(
+                    &struct_info,
+                    struct_info.attrs.checked_setters,
+                ) {
                     Ok(parsed_struct) => parsed_struct,
                     Err(err) => {
                         return TokenStream::from(err.to_compile_error());
@@ -1755,11 +2028,19 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
                 quote! {}
             };
 
+            let verify_quotes = structs::checksum::create_verify_quotes(&struct_info);
+            let update_checksums_quote = structs::checksum::create_update_quote(&struct_info);
+            let check_constants_quote =
+                structs::constant::create_check_constants_quote(&struct_info);
+            let nested_checks_quote = structs::nested::create_size_checks_quote(&struct_info);
             let getter_setters_quotes = quote! {
                 impl #struct_name {
                     #peek_quotes
                     #set_quotes
                     #setters_quote
+                    #verify_quotes
+                    #update_checksums_quote
+                    #check_constants_quote
                 }
             };
             let hex_size = struct_size * 2;
@@ -1786,14 +2067,38 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
             // that buffer.
             // from_bytes is essentially the same minus a variable because input_byte_buffer is the input.
             // slap peek quotes inside a impl block at the end and we good to go
+            let view_quote = structs::view::create_view_quotes(
+                &struct_name,
+                &struct_info.vis,
+                struct_size,
+                &fields_from_bytes.peek_view_field_fns,
+                &fields_into_bytes.set_view_field_fns,
+            );
+            let serde_quote = if struct_info.attrs.serde {
+                structs::serde::create_struct_serde_quotes(&struct_info)
+            } else {
+                quote! {}
+            };
+            let try_from_bytes_quote = fields_from_bytes.try_from_bytes_fn;
+            let check_fields_quote = structs::struct_fns::create_check_fields_quote(&struct_info);
             let to_bytes_quote = quote! {
                 impl bondrewd::Bitfields<#struct_size> for #struct_name {
                     const BIT_SIZE: usize = #bit_size;
                     #into_bytes_quote
+                    #write_to_quote
                     #from_bytes_quote
+                    #update_from_quote
+                    #stream_quote
+                    #check_fields_quote
+                }
+                impl bondrewd::TryBitfields<#struct_size> for #struct_name {
+                    #try_from_bytes_quote
                 }
                 #getter_setters_quotes
                 #hex_fns_quote
+                #view_quote
+                #serde_quote
+                #nested_checks_quote
             };
 
             if dyn_fns {
@@ -1871,6 +2176,9 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
             }
 
             let from_bytes_quote = fields_from_bytes.from_bytes_fn;
+            let write_to_quote = fields_into_bytes.write_to_fn;
+            let update_from_quote = fields_from_bytes.update_from_fn;
+            let stream_quote = fields_from_bytes.stream_fns;
             let mut peek_quotes = fields_from_bytes.peek_field_fns;
 
             if let Some(peek_slice_quote) = fields_from_bytes.peek_slice_field_fns {
@@ -1880,11 +2188,35 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
                 }
             }
 
+            let payload_quote = match structs::tag::create_payload_quotes(&enum_info) {
+                Ok(pq) => pq,
+                Err(err) => return TokenStream::from(err.to_compile_error()),
+            };
+            let kind_quote = match structs::kind::create_kind_quotes(&enum_info) {
+                Ok(kq) => kq,
+                Err(err) => return TokenStream::from(err.to_compile_error()),
+            };
+            let compact_quote = structs::compact::create_compact_quotes(&enum_info);
+            let setters_quote = if setters {
+                match structs::struct_fns::create_enum_setters_quotes(
+                    &enum_info,
+                    enum_info.attrs.attrs.checked_setters,
+                ) {
+                    Ok(sq) => sq,
+                    Err(err) => return TokenStream::from(err.to_compile_error()),
+                }
+            } else {
+                quote! {}
+            };
             let getter_setters_quotes = quote! {
                 impl #struct_name {
                     #peek_quotes
                     #set_quotes
+                    #setters_quote
+                    #payload_quote
+                    #compact_quote
                 }
+                #kind_quote
             };
             let struct_size = enum_info.total_bytes();
             let hex_size = struct_size * 2;
@@ -1911,14 +2243,48 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
             // that buffer.
             // from_bytes is essentially the same minus a variable because input_byte_buffer is the input.
             // slap peek quotes inside a impl block at the end and we good to go
+            let view_quote = structs::view::create_view_quotes(
+                &struct_name,
+                &enum_info.vis,
+                struct_size,
+                &fields_from_bytes.peek_view_field_fns,
+                &fields_into_bytes.set_view_field_fns,
+            );
+            let serde_quote = if enum_info.attrs.attrs.serde {
+                structs::serde::create_enum_serde_quotes(&enum_info)
+            } else {
+                quote! {}
+            };
+            let nested_checks_quote = enum_info
+                .variants
+                .iter()
+                .map(structs::nested::create_size_checks_quote);
+            let try_from_bytes_quote = fields_from_bytes.try_from_bytes_fn;
+            let try_into_bytes_quote =
+                match structs::into_bytes::create_try_into_bytes_quote(&enum_info) {
+                    Ok(quote) => quote,
+                    Err(err) => return TokenStream::from(err.to_compile_error()),
+                };
+            let check_fields_quote = structs::struct_fns::create_enum_check_fields_quote(&enum_info);
             let to_bytes_quote = quote! {
                 impl bondrewd::Bitfields<#struct_size> for #struct_name {
                     const BIT_SIZE: usize = #bit_size;
                     #into_bytes_quote
+                    #write_to_quote
                     #from_bytes_quote
+                    #update_from_quote
+                    #stream_quote
+                    #check_fields_quote
+                }
+                impl bondrewd::TryBitfields<#struct_size> for #struct_name {
+                    #try_from_bytes_quote
+                    #try_into_bytes_quote
                 }
                 #getter_setters_quotes
                 #hex_fns_quote
+                #view_quote
+                #serde_quote
+                #(#nested_checks_quote)*
             };
             if dyn_fns {
                 let from_vec_quote = fields_from_bytes.from_slice_field_fns;
@@ -1927,6 +2293,11 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
                 let checked_mut_ident = format_ident!("{}CheckedMut", &struct_name);
                 let unchecked_functions = fields_from_bytes.peek_slice_field_unchecked_fns;
                 let unchecked_mut_functions = fields_into_bytes.set_slice_field_unchecked_fns;
+                let checked_variant_quote =
+                    match structs::from_bytes::create_checked_variant_quotes(&enum_info) {
+                        Ok(cv) => cv,
+                        Err(err) => return TokenStream::from(err.to_compile_error()),
+                    };
                 let comment = format!("A Structure which provides functions for getting the fields of a [{struct_name}] in its bitfield form.");
                 let comment_mut = format!("A Structure which provides functions for getting and setting the fields of a [{struct_name}] in its bitfield form.");
                 let unchecked_comment = format!("Panics if resulting `{checked_ident}` does not contain enough bytes to read a field that is attempted to be read.");
@@ -1963,6 +2334,7 @@ pub fn derive_bitfields(input: TokenStream) -> TokenStream {
                     impl bondrewd::BitfieldsDyn<#struct_size> for #struct_name {
                         #from_vec_quote
                     }
+                    #checked_variant_quote
                 };
                 #[cfg(feature = "part_eq_enums")]
                 let id_ident = match enum_info.id_ident() {
@@ -0,0 +1,95 @@
+use crate::structs::common::{ChecksumInfo, ChecksumKind, StructInfo};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// Returns an expression computing the value of `checksum` from the bytes in `buffer`.
+fn checksum_value_quote(info: &StructInfo, checksum: &ChecksumInfo, buffer: &Ident) -> TokenStream {
+    let start = checksum.bytes.start;
+    let end = checksum.bytes.end;
+    match checksum.kind {
+        ChecksumKind::Crc(ref name) => {
+            let type_ident = info
+                .fields
+                .iter()
+                .find(|f| f.ident().ident() == checksum.ident)
+                .map(|f| f.ty.type_quote())
+                .unwrap_or_else(|| quote! {u64});
+            quote! {
+                bondrewd::checksum::#name.checksum(&#buffer[#start..#end]) as #type_ident
+            }
+        }
+        ChecksumKind::Parity(odd) => {
+            let first_mask = checksum.first_mask;
+            let last_mask = checksum.last_mask;
+            // the parity bit is set when it is needed to make the total amount of set bits even/odd.
+            let remainder: u32 = if odd { 0 } else { 1 };
+            quote! {
+                (bondrewd::checksum::count_ones(&#buffer[#start..#end], #first_mask, #last_mask) % 2 == #remainder)
+            }
+        }
+    }
+}
+
+/// Generates the code `into_bytes` and `write_to` run after every other field has been written,
+/// which fills in each checksum field, checksums covering other checksum fields are filled in last.
+/// `buffer` is how the `output_byte_buffer` is passed to the field's write function.
+pub fn create_fill_quotes(info: &StructInfo, buffer: &TokenStream) -> TokenStream {
+    let output_byte_buffer = format_ident!("output_byte_buffer");
+    let mut fill_quote = quote! {};
+    for checksum in info.checksums.iter() {
+        let value = checksum_value_quote(info, checksum, &output_byte_buffer);
+        let write_fn = format_ident!("write_{}", checksum.ident);
+        fill_quote = quote! {
+            #fill_quote
+            let checksum = #value;
+            Self::#write_fn(#buffer, checksum);
+        };
+    }
+    fill_quote
+}
+
+/// Generates an `update_checksums` function which fills in every checksum field of a buffer, for
+/// buffers changed by `write_` functions which leave the checksums as they were.
+pub fn create_update_quote(info: &StructInfo) -> TokenStream {
+    if info.checksums.is_empty() {
+        return quote! {};
+    }
+    let struct_size = info.total_bytes();
+    let struct_name = &info.name;
+    let fill_quote = create_fill_quotes(info, &quote! {output_byte_buffer});
+    let comment = format!("Fills in every checksum field of a [{struct_name}] in bitfield form, the `write_` functions do not update them.");
+    quote! {
+        #[doc = #comment]
+        pub fn update_checksums(output_byte_buffer: &mut [u8;#struct_size]) {
+            #fill_quote
+        }
+    }
+}
+
+/// Generates a `verify_{field}` function for each checksum field, which recomputes the checksum and
+/// compares it to the value stored in the field.
+pub fn create_verify_quotes(info: &StructInfo) -> TokenStream {
+    let struct_size = info.total_bytes();
+    let struct_name = &info.name;
+    let input_byte_buffer = format_ident!("input_byte_buffer");
+    let mut verify_quote = quote! {};
+    for checksum in info.checksums.iter() {
+        let field_name = &checksum.ident;
+        let value = checksum_value_quote(info, checksum, &input_byte_buffer);
+        let read_fn = format_ident!("read_{field_name}");
+        let verify_fn = format_ident!("verify_{field_name}");
+        let comment = format!(
+            "Returns true if the `{field_name}` field of a [{struct_name}] in bitfield form matches the checksum of bytes {} through {} in `input_byte_buffer`.",
+            checksum.bytes.start,
+            checksum.bytes.end - 1
+        );
+        verify_quote = quote! {
+            #verify_quote
+            #[doc = #comment]
+            pub fn #verify_fn(input_byte_buffer: &[u8;#struct_size]) -> bool {
+                Self::#read_fn(input_byte_buffer) == #value
+            }
+        };
+    }
+    verify_quote
+}
@@ -3,8 +3,7 @@ use crate::structs::parse::{
 };
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote};
-use std::ops::Range;
-use std::str::FromStr;
+use std::ops::{Range, RangeInclusive};
 use syn::parse::Error;
 use syn::spanned::Spanned;
 use syn::{Attribute, DeriveInput, Expr, Fields, Ident, Lit, Meta, NestedMeta, Type};
@@ -633,8 +632,15 @@ pub struct FieldAttrs {
     pub bit_range: Range<usize>,
     pub reserve: ReserveFieldOption,
     pub overlap: OverlapOptions,
-    /// This should only ever be true on the Invalid case for enums that what to capture the invalid Id.
+    /// The field holds the id of its enum variant, only allowed on the first field of a variant.
     pub capture_id: bool,
+    /// byte pattern a reserve field is written with, overrides the structure's `fill_pattern`.
+    pub fill_pattern: Option<u8>,
+    /// the field a `tag_from` enum field takes its variant id from, the enum is stored without its id.
+    pub tag_from: Option<Box<FieldInfo>>,
+    /// `read_` functions of a nested bitfields field which are also generated for the outer
+    /// structure, and the type they return.
+    pub read_through: Vec<(Ident, TokenStream)>,
 }
 
 impl FieldAttrs {
@@ -671,6 +677,9 @@ impl Iterator for ElementSubFieldIter {
                 reserve: self.reserve.clone(),
                 overlap: self.overlap.clone(),
                 capture_id: false,
+                fill_pattern: None,
+                tag_from: None,
+                read_through: Vec::new(),
             };
             let outer_ident = self.outer_ident.ident().clone();
             let name = quote::format_ident!("{}_{}", outer_ident, index);
@@ -716,6 +725,9 @@ impl Iterator for BlockSubFieldIter {
                 reserve: self.reserve.clone(),
                 overlap: self.overlap.clone(),
                 capture_id: false,
+                fill_pattern: None,
+                tag_from: None,
+                read_through: Vec::new(),
             };
             self.bit_length -= ty_size;
             let index = self.total_bytes - self.length;
@@ -915,14 +927,33 @@ impl FieldInfo {
             .filter(|x| !x.attrs.overlap.is_redundant())
             .last();
         let mut attrs_builder = FieldAttrBuilder::parse(field, last_relevant_field, ident.span())?;
+        if attrs_builder.count_from.is_some() || attrs_builder.max.is_some() {
+            return Err(Error::new(
+                field.span(),
+                "count_from and max can only be used on a `Vec` or `heapless::Vec` field",
+            ));
+        }
+        // constant fields are parsed as the type their value is stored as.
+        let ty = if attrs_builder.constant.is_some() {
+            super::constant::constant_stored_type(&field.ty, field.ty.span())?
+        } else {
+            &field.ty
+        };
         // check the field for supported types.
         let data_type = FieldDataType::parse(
-            &field.ty,
+            ty,
             &mut attrs_builder,
             ident.span(),
             &attrs.default_endianess,
         )?;
 
+        if !attrs_builder.read_through.is_empty() && !matches!(data_type, FieldDataType::Struct(..)) {
+            return Err(Error::new(
+                field.span(),
+                "read_through can only be used on a nested Bitfields struct or enum field",
+            ));
+        }
+
         let attr_result: std::result::Result<FieldAttrs, TryFromAttrBuilderError> =
             attrs_builder.try_into();
 
@@ -957,6 +988,892 @@ impl FieldInfo {
     }
 }
 
+/// The type of the elements in a variable length array.
+#[derive(Clone, Debug)]
+pub enum VariableElementType {
+    /// A primitive number. contains the amount of bytes, endianness and the type.
+    Number(usize, Endianness, TokenStream),
+    /// A Struct or Enum which implements the Bitfields trait. contains the amount of bytes and the type.
+    Struct(usize, TokenStream),
+}
+
+impl VariableElementType {
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Number(size, _, _) | Self::Struct(size, _) => *size,
+        }
+    }
+}
+
+/// A `Vec` field which gets the amount of elements it contains from another field. Variable length
+/// arrays are always placed after all of the fixed size fields.
+#[derive(Clone, Debug)]
+pub struct VariableArrayInfo {
+    pub ident: Ident,
+    /// name of the field the amount of elements is read from and written to.
+    pub count_from: Ident,
+    /// maximum amount of elements.
+    pub max: usize,
+    pub element: VariableElementType,
+}
+
+impl VariableArrayInfo {
+    /// Returns `None` if the field does not have the `count_from` attribute.
+    pub fn parse(field: &syn::Field, attrs: &AttrInfo) -> syn::Result<Option<Self>> {
+        let vec_args = if let Type::Path(ref path) = field.ty {
+            if let Some(last) = path.path.segments.last() {
+                if last.ident == "Vec" {
+                    if let syn::PathArguments::AngleBracketed(ref args) = last.arguments {
+                        args.args.iter().cloned().collect::<Vec<syn::GenericArgument>>()
+                    } else {
+                        return Ok(None);
+                    }
+                } else {
+                    return Ok(None);
+                }
+            } else {
+                return Ok(None);
+            }
+        } else {
+            return Ok(None);
+        };
+        let mut builder = FieldAttrBuilder::parse(field, None, field.span())?;
+        let count_from = if let Some(count_from) = builder.count_from.take() {
+            count_from
+        } else {
+            return Ok(None);
+        };
+        let ident = if let Some(ref ident) = field.ident {
+            ident.clone()
+        } else {
+            return Err(Error::new(
+                field.span(),
+                "count_from is not supported for tuple structs",
+            ));
+        };
+        let max = if let Some(max) = builder.max {
+            max
+        } else {
+            return Err(Error::new(
+                field.span(),
+                "count_from fields must also define the maximum amount of elements ex. `max = 32`",
+            ));
+        };
+        if let FieldBuilderRange::Range(_) = builder.bit_range {
+            return Err(Error::new(
+                field.span(),
+                "count_from fields get their size from the count field, bit ranges can not be defined",
+            ));
+        }
+        let element_ty = if let Some(syn::GenericArgument::Type(ref ty)) = vec_args.first() {
+            ty.clone()
+        } else {
+            return Err(Error::new(
+                field.span(),
+                "could not determine the element type of the Vec",
+            ));
+        };
+        // heapless::Vec has its capacity as the second generic argument.
+        if let Some(syn::GenericArgument::Const(Expr::Lit(ref lit))) = vec_args.get(1) {
+            if let Lit::Int(ref capacity) = lit.lit {
+                if capacity.base10_parse::<usize>()? < max {
+                    return Err(Error::new(
+                        field.span(),
+                        "max is larger than the capacity of the Vec",
+                    ));
+                }
+            }
+        }
+        let primitive_size = if let Type::Path(ref path) = element_ty {
+            if let Some(ident) = path.path.get_ident() {
+                match ident.to_string().as_str() {
+                    "u8" | "i8" => Some(1),
+                    "u16" | "i16" => Some(2),
+                    "u32" | "i32" | "f32" => Some(4),
+                    "u64" | "i64" | "f64" => Some(8),
+                    "u128" | "i128" => Some(16),
+                    _ => None,
+                }
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        let element = if let Some(size) = primitive_size {
+            let mut endianness = if builder.endianness.has_endianness() {
+                *builder.endianness
+            } else {
+                attrs.default_endianess.clone()
+            };
+            if !endianness.perhaps_endianness(size) {
+                return Err(Error::new(
+                    field.span(),
+                    "elements of count_from fields larger than 1 byte require an endianness",
+                ));
+            }
+            VariableElementType::Number(size, endianness, quote! {#element_ty})
+        } else {
+            let size = match builder.ty {
+                FieldAttrBuilderType::Struct(size) => size,
+                FieldAttrBuilderType::ElementArray(bits, _) => {
+                    if bits % 8 != 0 {
+                        return Err(Error::new(
+                            field.span(),
+                            "elements of count_from fields must use whole bytes",
+                        ));
+                    }
+                    bits / 8
+                }
+                _ => {
+                    return Err(Error::new(
+                        field.span(),
+                        "count_from fields containing structures or enums must define `element_byte_length`",
+                    ));
+                }
+            };
+            VariableElementType::Struct(size, quote! {#element_ty})
+        };
+        Ok(Some(Self {
+            ident,
+            count_from,
+            max,
+            element,
+        }))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ChecksumKind {
+    /// name of the `bondrewd::checksum` constant used.
+    Crc(Ident),
+    /// true if the total amount of set bits, including the parity bit, should be odd.
+    Parity(bool),
+}
+
+/// A field which `into_bytes` fills with a checksum of other bytes in the bitfield form.
+#[derive(Clone, Debug)]
+pub struct ChecksumInfo {
+    /// name of the field holding the checksum.
+    pub ident: Ident,
+    pub kind: ChecksumKind,
+    /// bytes of the bitfield form the checksum covers.
+    pub bytes: Range<usize>,
+    /// bits the checksum covers, using the same positions as the bit ranges of fields.
+    pub bits: Range<usize>,
+    /// mask applied to the first covered byte, only parity can cover partial bytes.
+    pub first_mask: u8,
+    /// mask applied to the last covered byte, only parity can cover partial bytes.
+    pub last_mask: u8,
+}
+
+impl ChecksumInfo {
+    /// Returns an error if the field has the `crc` or `parity` attribute, for places checksums are not supported.
+    pub fn reject(field: &syn::Field, place: &str) -> syn::Result<()> {
+        let builder = FieldAttrBuilder::parse(field, None, field.span())?;
+        if builder.checksum.is_some() {
+            return Err(Error::new(
+                field.span(),
+                format!("crc and parity fields are not currently supported in {place}."),
+            ));
+        }
+        Ok(())
+    }
+    /// Returns `None` if the field does not have the `crc` or `parity` attribute. `fields` must be
+    /// the fully parsed fields of the structure.
+    pub fn parse(
+        field: &syn::Field,
+        fields: &[FieldInfo],
+        attrs: &AttrInfo,
+    ) -> syn::Result<Option<Self>> {
+        let builder = FieldAttrBuilder::parse(field, None, field.span())?;
+        let (attr, value) = if let Some(checksum) = builder.checksum {
+            checksum
+        } else {
+            if let Some((_, span)) = builder.covers {
+                return Err(Error::new(
+                    span,
+                    "covers is only used by crc and parity fields",
+                ));
+            }
+            return Ok(None);
+        };
+        let ident = if let Some(ref ident) = field.ident {
+            ident.clone()
+        } else {
+            return Err(Error::new(
+                field.span(),
+                "crc and parity are not supported for tuple structs",
+            ));
+        };
+        let info = if let Some(info) = fields.iter().find(|f| f.ident().ident() == ident) {
+            info
+        } else {
+            return Err(Error::new(
+                field.span(),
+                "an error with bondrewd has occurred, the checksum field was not parsed",
+            ));
+        };
+        if !info.attrs.reserve.write_field() {
+            return Err(Error::new(
+                field.span(),
+                "crc and parity fields must be writable",
+            ));
+        }
+        let kind = if attr == "crc" {
+            let (name, width) = match value.as_str() {
+                "crc8" => ("CRC8", 8),
+                "crc16_ccitt_false" => ("CRC16_CCITT_FALSE", 16),
+                "crc16_xmodem" => ("CRC16_XMODEM", 16),
+                "crc16_arc" => ("CRC16_ARC", 16),
+                "crc32" => ("CRC32", 32),
+                "crc32c" => ("CRC32C", 32),
+                _ => {
+                    return Err(Error::new(
+                        attr.span(),
+                        format!("\"{value}\" is not a supported crc, use crc8, crc16_ccitt_false, crc16_xmodem, crc16_arc, crc32 or crc32c"),
+                    ));
+                }
+            };
+            if !matches!(
+                info.ty,
+                FieldDataType::Number(_, NumberSignage::Unsigned, _)
+            ) || info.attrs.bit_length() != width
+            {
+                return Err(Error::new(
+                    field.span(),
+                    format!(
+                        "{value} fields must be an unsigned number with a bit_length of {width}"
+                    ),
+                ));
+            }
+            ChecksumKind::Crc(format_ident!("{name}"))
+        } else {
+            let odd = match value.as_str() {
+                "even" => false,
+                "odd" => true,
+                _ => {
+                    return Err(Error::new(
+                        attr.span(),
+                        "parity must be \"even\" or \"odd\"",
+                    ));
+                }
+            };
+            if !matches!(info.ty, FieldDataType::Boolean) {
+                return Err(Error::new(field.span(), "parity fields must be a bool"));
+            }
+            ChecksumKind::Parity(odd)
+        };
+        let (covers, covers_span) = if let Some(covers) = builder.covers {
+            covers
+        } else {
+            return Err(Error::new(
+                field.span(),
+                "crc and parity fields must define what they cover ex. `covers = \"0..6\"` or `covers = \"first_field..last_field\"`",
+            ));
+        };
+        let (start, end, inclusive) = if let Some((start, end)) = covers.split_once("..=") {
+            (start.trim(), end.trim(), true)
+        } else if let Some((start, end)) = covers.split_once("..") {
+            (start.trim(), end.trim(), false)
+        } else {
+            return Err(Error::new(
+                covers_span,
+                "covers must be a range of byte indices or field names ex. \"0..6\" or \"first_field..last_field\"",
+            ));
+        };
+        let total_bits: usize = fields.iter().map(|f| f.bit_size()).sum();
+        let total_bytes = (total_bits as f64 / 8.0f64).ceil() as usize;
+        let is_index = |side: &str| side.is_empty() || side.parse::<usize>().is_ok();
+        // the range of bits covered, using bondrewd's internal bit positions.
+        let bits = if is_index(start) && is_index(end) {
+            let start = start.parse::<usize>().unwrap_or(0);
+            let end = match end.parse::<usize>() {
+                Ok(end) if inclusive => end + 1,
+                Ok(end) => end,
+                Err(_) => total_bytes,
+            };
+            if start >= end || end > total_bytes {
+                return Err(Error::new(
+                    covers_span,
+                    format!("covers must be a non-empty range of bytes within the {total_bytes} bytes of the structure"),
+                ));
+            }
+            if attrs.flip {
+                ((total_bytes - end) * 8)..((total_bytes - start) * 8)
+            } else {
+                (start * 8)..(end * 8)
+            }
+        } else {
+            let find = |name: &str| {
+                fields
+                    .iter()
+                    .find(|f| !f.attrs.reserve.is_fake_field() && f.ident().ident() == name)
+                    .ok_or_else(|| {
+                        Error::new(
+                            covers_span,
+                            format!("covers field \"{name}\" was not found"),
+                        )
+                    })
+            };
+            let first = find(start)?;
+            let last = find(end)?;
+            // field ranges always include the last field, with lsb0 the first field may have the
+            // higher bit positions.
+            first.attrs.bit_range.start.min(last.attrs.bit_range.start)
+                ..first.attrs.bit_range.end.max(last.attrs.bit_range.end)
+        };
+        if info.attrs.bit_range.start < bits.end && bits.start < info.attrs.bit_range.end {
+            return Err(Error::new(
+                covers_span,
+                "a checksum can not cover the field it is stored in",
+            ));
+        }
+        if matches!(kind, ChecksumKind::Crc(_)) && (bits.start % 8 != 0 || bits.end % 8 != 0) {
+            return Err(Error::new(covers_span, "crc fields must cover whole bytes"));
+        }
+        let first_byte = bits.start / 8;
+        let end_byte = (bits.end as f64 / 8.0f64).ceil() as usize;
+        let first_mask = get_right_and_mask(8 - (bits.start % 8));
+        let last_mask = if bits.end % 8 == 0 {
+            u8::MAX
+        } else {
+            get_left_and_mask(bits.end % 8)
+        };
+        let (bytes, first_mask, last_mask) = if attrs.flip {
+            (
+                (total_bytes - end_byte)..(total_bytes - first_byte),
+                last_mask,
+                first_mask,
+            )
+        } else {
+            (first_byte..end_byte, first_mask, last_mask)
+        };
+        Ok(Some(Self {
+            ident,
+            kind,
+            bytes,
+            bits,
+            first_mask,
+            last_mask,
+        }))
+    }
+    /// Orders `checksums` so a checksum covering another checksum field is filled in after it,
+    /// otherwise keeping the order they were defined in. Checksums covering each other are an error.
+    pub fn fill_order(
+        mut checksums: Vec<ChecksumInfo>,
+        fields: &[FieldInfo],
+    ) -> syn::Result<Vec<ChecksumInfo>> {
+        let field_bits = |checksum: &ChecksumInfo| {
+            fields
+                .iter()
+                .find(|f| f.ident().ident() == checksum.ident)
+                .map(|f| f.attrs.bit_range.clone())
+                .unwrap_or_default()
+        };
+        let mut ordered = Vec::with_capacity(checksums.len());
+        while !checksums.is_empty() {
+            let ready = checksums.iter().position(|checksum| {
+                checksums.iter().all(|other| {
+                    let bits = field_bits(other);
+                    other.ident == checksum.ident
+                        || !(checksum.bits.start < bits.end && bits.start < checksum.bits.end)
+                })
+            });
+            if let Some(ready) = ready {
+                ordered.push(checksums.remove(ready));
+            } else {
+                let names: Vec<String> = checksums.iter().map(|c| c.ident.to_string()).collect();
+                return Err(Error::new(
+                    checksums[0].ident.span(),
+                    format!("checksum fields {} cover each other", names.join(", ")),
+                ));
+            }
+        }
+        Ok(ordered)
+    }
+}
+
+/// What a `length_of` field measures.
+#[derive(Clone, Debug)]
+pub enum LengthPayload {
+    /// a fixed size field taking this amount of bytes.
+    Fixed(usize),
+    /// the `count_from` array, whose size is its amount of elements times the element size.
+    Variable,
+}
+
+/// A field which `into_bytes` fills with the size in bytes of another field.
+#[derive(Clone, Debug)]
+pub struct LengthInfo {
+    /// name of the field holding the length.
+    pub ident: Ident,
+    pub payload: LengthPayload,
+    /// amount added to the size in bytes before it is stored.
+    pub adjust: i128,
+    /// the largest value the length field can hold.
+    pub max: u128,
+}
+
+impl LengthInfo {
+    /// Returns an error if the field has the `length_of` attribute, for places lengths are not supported.
+    pub fn reject(field: &syn::Field, place: &str) -> syn::Result<()> {
+        let builder = FieldAttrBuilder::parse(field, None, field.span())?;
+        if builder.length_of.is_some() {
+            return Err(Error::new(
+                field.span(),
+                format!("length_of fields are not currently supported in {place}."),
+            ));
+        }
+        Ok(())
+    }
+    /// Returns `None` if the field does not have the `length_of` attribute. `fields` must be
+    /// the fully parsed fields of the structure.
+    pub fn parse(
+        field: &syn::Field,
+        fields: &[FieldInfo],
+        variable_array: Option<&VariableArrayInfo>,
+    ) -> syn::Result<Option<Self>> {
+        let builder = FieldAttrBuilder::parse(field, None, field.span())?;
+        let length_of = if let Some(length_of) = builder.length_of {
+            length_of
+        } else {
+            if let Some((_, span)) = builder.adjust {
+                return Err(Error::new(span, "adjust is only used by length_of fields"));
+            }
+            return Ok(None);
+        };
+        let ident = if let Some(ref ident) = field.ident {
+            ident.clone()
+        } else {
+            return Err(Error::new(
+                field.span(),
+                "length_of is not supported for tuple structs",
+            ));
+        };
+        let info = if let Some(info) = fields.iter().find(|f| f.ident().ident() == ident) {
+            info
+        } else {
+            return Err(Error::new(
+                field.span(),
+                "an error with bondrewd has occurred, the length_of field was not parsed",
+            ));
+        };
+        if !info.attrs.reserve.write_field() {
+            return Err(Error::new(
+                field.span(),
+                "length_of fields must be writable",
+            ));
+        }
+        let bit_length = info.attrs.bit_length();
+        if !matches!(info.ty, FieldDataType::Number(_, NumberSignage::Unsigned, _)) {
+            return Err(Error::new(
+                field.span(),
+                "length_of fields must be an unsigned number",
+            ));
+        }
+        let max = if bit_length >= 128 {
+            u128::MAX
+        } else {
+            2_u128.pow(bit_length as u32) - 1
+        };
+        let adjust = builder.adjust.map(|(adjust, _)| adjust).unwrap_or(0);
+        let payload = if length_of == ident {
+            return Err(Error::new(
+                length_of.span(),
+                "a length_of field can not measure itself",
+            ));
+        } else if variable_array.is_some_and(|variable| variable.ident == length_of) {
+            LengthPayload::Variable
+        } else if let Some(payload) = fields
+            .iter()
+            .find(|f| !f.attrs.reserve.is_fake_field() && f.ident().ident() == length_of)
+        {
+            let bytes = (payload.bit_size() as f64 / 8.0f64).ceil() as usize;
+            let value = bytes as i128 + adjust;
+            if value < 0 || value as u128 > max {
+                return Err(Error::new(
+                    length_of.span(),
+                    format!("the length of \"{length_of}\" ({bytes} bytes) adjusted by {adjust} does not fit in the {bit_length} bits of \"{ident}\""),
+                ));
+            }
+            LengthPayload::Fixed(bytes)
+        } else {
+            return Err(Error::new(
+                length_of.span(),
+                format!("length_of field \"{length_of}\" was not found"),
+            ));
+        };
+        Ok(Some(Self {
+            ident,
+            payload,
+            adjust,
+            max,
+        }))
+    }
+}
+
+/// A field which is always written with the same value.
+#[derive(Clone, Debug)]
+pub struct ConstantInfo {
+    /// name of the constant field.
+    pub ident: Ident,
+    /// the value of the field, `bool` fields use 1 and 0.
+    pub value: u128,
+}
+
+impl ConstantInfo {
+    /// Returns an error if the field has the `constant` attribute, for places constants are not supported.
+    pub fn reject(field: &syn::Field, place: &str) -> syn::Result<()> {
+        let builder = FieldAttrBuilder::parse(field, None, field.span())?;
+        if builder.constant.is_some() {
+            return Err(Error::new(
+                field.span(),
+                format!("constant fields are not currently supported in {place}."),
+            ));
+        }
+        Ok(())
+    }
+    /// Returns `None` if the field does not have the `constant` attribute. `fields` must be
+    /// the fully parsed fields of the structure.
+    pub fn parse(field: &syn::Field, fields: &[FieldInfo]) -> syn::Result<Option<Self>> {
+        let builder = FieldAttrBuilder::parse(field, None, field.span())?;
+        let (value, value_span) = if let Some(constant) = builder.constant {
+            constant
+        } else {
+            return Ok(None);
+        };
+        let ident = if let Some(ref ident) = field.ident {
+            ident.clone()
+        } else {
+            return Err(Error::new(
+                field.span(),
+                "constant is not supported for tuple structs",
+            ));
+        };
+        let info = if let Some(info) = fields.iter().find(|f| f.ident().ident() == ident) {
+            info
+        } else {
+            return Err(Error::new(
+                field.span(),
+                "an error with bondrewd has occurred, the constant field was not parsed",
+            ));
+        };
+        if !info.attrs.reserve.write_field() || !info.attrs.reserve.read_field() {
+            return Err(Error::new(
+                field.span(),
+                "constant fields can not be reserve or read_only",
+            ));
+        }
+        if builder.checksum.is_some() || builder.length_of.is_some() {
+            return Err(Error::new(
+                field.span(),
+                "constant fields can not also be crc, parity or length_of fields",
+            ));
+        }
+        let bit_length = info.attrs.bit_length();
+        match info.ty {
+            FieldDataType::Number(_, NumberSignage::Unsigned, _) | FieldDataType::Boolean => {}
+            _ => {
+                return Err(Error::new(
+                    field.span(),
+                    "constant fields must be an unsigned number or a bool",
+                ));
+            }
+        }
+        if bit_length < 128 && value >= 2_u128.pow(bit_length as u32) {
+            return Err(Error::new(
+                value_span,
+                format!("constant {value} does not fit in the {bit_length} bits of \"{ident}\""),
+            ));
+        }
+        Ok(Some(Self { ident, value }))
+    }
+}
+
+/// Parses a `variant_id` list like `"3, 7, 0x10..=0x1F"`, `None` if it is not a valid list.
+fn parse_id_set(value: &str) -> Option<(Vec<RangeInclusive<u128>>, bool)> {
+    /// returns the id and whether it was negative, negative ids are sign extended to 128 bits.
+    fn parse_id(value: &str) -> Option<(u128, bool)> {
+        let value = value.trim().replace('_', "");
+        let (value, negative) = match value.strip_prefix('-') {
+            Some(value) => (value.trim(), true),
+            None => (value.as_str(), false),
+        };
+        let magnitude = if let Some(hex) = value.strip_prefix("0x") {
+            u128::from_str_radix(hex, 16).ok()
+        } else if let Some(binary) = value.strip_prefix("0b") {
+            u128::from_str_radix(binary, 2).ok()
+        } else if let Some(octal) = value.strip_prefix("0o") {
+            u128::from_str_radix(octal, 8).ok()
+        } else {
+            value.parse().ok()
+        }?;
+        if negative && magnitude != 0 {
+            if magnitude > 1 << 127 {
+                return None;
+            }
+            Some((0_u128.wrapping_sub(magnitude), true))
+        } else {
+            Some((magnitude, false))
+        }
+    }
+    let mut id_set = Vec::new();
+    let mut any_negative = false;
+    for item in value.split(',') {
+        let ((start, start_negative), (end, end_negative)) =
+            if let Some((start, end)) = item.split_once("..=") {
+                (parse_id(start)?, parse_id(end)?)
+            } else if let Some((start, end)) = item.split_once("..") {
+                let (end, end_negative) = parse_id(end)?;
+                let end = if end_negative || end == 0 {
+                    (end.wrapping_sub(1), true)
+                } else {
+                    (end - 1, false)
+                };
+                (parse_id(start)?, end)
+            } else {
+                let id = parse_id(item)?;
+                (id, id)
+            };
+        any_negative |= start_negative;
+        match (start_negative, end_negative) {
+            // negative ids are sign extended, so ranges crossing zero are split in two.
+            (true, false) => {
+                id_set.push(start..=u128::MAX);
+                id_set.push(0..=end);
+            }
+            (false, true) => return None,
+            _ => {
+                if start > end {
+                    return None;
+                }
+                id_set.push(start..=end);
+            }
+        }
+    }
+    Some((id_set, any_negative))
+}
+
+/// Returns an error if the field has the `tag_from` attribute, for places `tag_from` is not supported.
+pub fn reject_tag_from(field: &syn::Field, place: &str) -> syn::Result<()> {
+    let builder = FieldAttrBuilder::parse(field, None, field.span())?;
+    if builder.tag_from.is_some() {
+        return Err(Error::new(
+            field.span(),
+            format!("tag_from fields are not currently supported in {place}."),
+        ));
+    }
+    Ok(())
+}
+
+/// Stores the field a `tag_from` field takes its variant id from in the attributes of the
+/// `tag_from` field. `fields` must be the fully parsed fields of the structure.
+pub fn parse_tag_from(field: &syn::Field, fields: &mut [FieldInfo]) -> syn::Result<()> {
+    let builder = FieldAttrBuilder::parse(field, None, field.span())?;
+    let tag_from = if let Some(tag_from) = builder.tag_from {
+        tag_from
+    } else {
+        return Ok(());
+    };
+    let ident = if let Some(ref ident) = field.ident {
+        ident.clone()
+    } else {
+        return Err(Error::new(
+            field.span(),
+            "tag_from is not supported for tuple structs",
+        ));
+    };
+    if tag_from == ident {
+        return Err(Error::new(
+            tag_from.span(),
+            "a tag_from field can not hold its own variant id",
+        ));
+    }
+    let tag = if let Some(tag) = fields
+        .iter()
+        .find(|f| !f.attrs.reserve.is_fake_field() && f.ident().ident() == tag_from)
+    {
+        tag.clone()
+    } else {
+        return Err(Error::new(
+            tag_from.span(),
+            format!("tag_from field \"{tag_from}\" was not found"),
+        ));
+    };
+    if !matches!(tag.ty, FieldDataType::Number(..) | FieldDataType::Enum(..)) {
+        return Err(Error::new(
+            tag_from.span(),
+            "the field a tag_from field takes its variant id from must be a number or an enum_primitive",
+        ));
+    }
+    if !tag.attrs.reserve.write_field() || !tag.attrs.reserve.read_field() {
+        return Err(Error::new(
+            tag_from.span(),
+            "the field a tag_from field takes its variant id from can not be a reserve field",
+        ));
+    }
+    let info = if let Some(info) = fields.iter_mut().find(|f| f.ident().ident() == ident) {
+        info
+    } else {
+        return Err(Error::new(
+            field.span(),
+            "an error with bondrewd has occurred, the tag_from field was not parsed",
+        ));
+    };
+    if !matches!(info.ty, FieldDataType::Struct(..)) {
+        return Err(Error::new(
+            field.span(),
+            "tag_from fields must be a Bitfields enum with a bit_length",
+        ));
+    }
+    if !info.attrs.read_through.is_empty() {
+        return Err(Error::new(
+            field.span(),
+            "read_through can not be used on tag_from fields, they are stored without their id",
+        ));
+    }
+    info.attrs.tag_from = Some(Box::new(tag));
+    Ok(())
+}
+
+/// Returns the amount of bits needed to store `id`, `signed` ids are sign extended to 128 bits and
+/// need a sign bit.
+fn id_bits_for(id: u128, signed: bool) -> usize {
+    if !signed {
+        return (u128::BITS - id.leading_zeros()) as usize;
+    }
+    let id = id as i128;
+    if id < 0 {
+        (i128::BITS + 1 - id.leading_ones()) as usize
+    } else {
+        (i128::BITS + 1 - id.leading_zeros()) as usize
+    }
+}
+
+/// Returns the amount of bits the `#[repr(..)]` attribute of an enum gives its discriminants, which
+/// is used as the size of the id when no size is defined. a signed repr does not make the id signed.
+fn repr_id_bits(attrs: &[Attribute]) -> Option<usize> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        if let Ok(Meta::List(list)) = attr.parse_meta() {
+            for nested in list.nested.iter() {
+                if let NestedMeta::Meta(Meta::Path(ref path)) = nested {
+                    let repr = path.get_ident().map(|ident| ident.to_string());
+                    return Some(match repr.as_deref() {
+                        Some("u8" | "i8") => 8,
+                        Some("u16" | "i16") => 16,
+                        Some("u32" | "i32") => 32,
+                        Some("u64" | "i64") => 64,
+                        Some("u128" | "i128") => 128,
+                        _ => continue,
+                    });
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Returns an error if the field has the `common` attribute, which only enum variants support.
+pub fn reject_common(field: &syn::Field) -> syn::Result<()> {
+    let builder = FieldAttrBuilder::parse(field, None, field.span())?;
+    if builder.common {
+        return Err(Error::new(
+            field.span(),
+            "common fields are only supported in enum variants.",
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the fields marked `common` in the variants of an enum, in the order they are declared.
+fn collect_common_fields(data: &syn::DataEnum) -> syn::Result<Vec<syn::Field>> {
+    let mut common: Vec<syn::Field> = Vec::new();
+    for variant in data.variants.iter() {
+        for field in variant.fields.iter() {
+            if !FieldAttrBuilder::parse(field, None, field.span())?.common {
+                continue;
+            }
+            let ident = if let Some(ref ident) = field.ident {
+                ident
+            } else {
+                return Err(Error::new(
+                    field.span(),
+                    "common fields must be named, tuple variants can not have common fields.",
+                ));
+            };
+            if common.iter().any(|c| c.ident.as_ref() == Some(ident)) {
+                return Err(Error::new(
+                    field.span(),
+                    format!("`{ident}` is already marked common, the other variants only need to declare the field's name and type."),
+                ));
+            }
+            common.push(field.clone());
+        }
+    }
+    Ok(common)
+}
+
+/// Returns the fields of `variant` with the `common` fields moved to the front (after a `capture_id`
+/// field), each using the attributes of the field marked `common` so every variant stores them
+/// in the same bits.
+fn with_common_fields(variant: &syn::Variant, common: &[syn::Field]) -> syn::Result<Fields> {
+    if common.is_empty() {
+        return Ok(variant.fields.clone());
+    }
+    let mut named = if let Fields::Named(ref named) = variant.fields {
+        named.clone()
+    } else {
+        return Err(Error::new(
+            variant.ident.span(),
+            "variants of an enum with common fields must have named fields, including the common fields.",
+        ));
+    };
+    let mut fields: Vec<syn::Field> = Vec::new();
+    for field in named.named.iter() {
+        if FieldAttrBuilder::parse(field, None, field.span())?.capture_id {
+            fields.push(field.clone());
+        }
+    }
+    for common_field in common {
+        let ident = common_field.ident.as_ref();
+        let field = if let Some(field) = named.named.iter().find(|f| f.ident.as_ref() == ident) {
+            field
+        } else {
+            return Err(Error::new(
+                variant.ident.span(),
+                format!(
+                    "variant is missing the common field `{}`, every variant must have it.",
+                    quote! {#ident}
+                ),
+            ));
+        };
+        let (ty, common_ty) = (&field.ty, &common_field.ty);
+        if quote! {#ty}.to_string() != quote! {#common_ty}.to_string() {
+            return Err(Error::new(
+                field.ty.span(),
+                "common fields must have the same type in every variant.",
+            ));
+        }
+        if !FieldAttrBuilder::parse(field, None, field.span())?.common
+            && field.attrs.iter().any(|a| a.path.is_ident("bondrewd"))
+        {
+            return Err(Error::new(
+                field.span(),
+                "attributes of a common field are defined where it is marked common.",
+            ));
+        }
+        fields.push(common_field.clone());
+    }
+    for field in named.named.iter() {
+        if !fields.iter().any(|f| f.ident == field.ident) {
+            fields.push(field.clone());
+        }
+    }
+    named.named = fields.into_iter().collect();
+    Ok(Fields::Named(named))
+}
+
 #[derive(Debug, Clone)]
 pub enum StructEnforcement {
     /// there is no enforcement so if bits are unused then it will act like they are a reserve field
@@ -967,10 +1884,24 @@ pub enum StructEnforcement {
     EnforceBitAmount(usize),
 }
 
+/// Where the variant id is placed within the enum.
 #[derive(Clone)]
 pub enum IdPosition {
+    /// the first bits of the enum.
     Leading,
+    /// the last bits of the enum.
     Trailing,
+    /// a specific bit range, variant fields are placed around it.
+    Bits(Range<usize>),
+}
+
+/// Where the fill bits of variants smaller than the largest variant are placed.
+#[derive(Clone, Copy)]
+pub enum FillPosition {
+    /// after the variant's fields.
+    Trailing,
+    /// between the id and the variant's fields.
+    AfterId,
 }
 
 #[derive(Clone)]
@@ -984,8 +1915,40 @@ pub struct AttrInfo {
     pub enforcement: StructEnforcement,
     pub default_endianess: Endianness,
     pub fill_bits: Option<usize>,
+    /// byte pattern reserve fields and fill bits are written with, zero when `None`.
+    pub fill_pattern: Option<u8>,
     pub id: Option<u128>,
+    /// every id a variant is used for when `variant_id` is a list or range of ids, `id` is the first.
+    pub id_set: Vec<RangeInclusive<u128>>,
     pub invalid: bool,
+    /// generate `Serialize` and `Deserialize` implementations.
+    pub serde: bool,
+    /// setters return an error instead of clamping values that do not fit, needs the `setters` feature.
+    pub checked_setters: bool,
+    /// a variant id was given as a negative number.
+    pub negative_id: bool,
+    /// the amount of bits a signed enum id uses, `None` when the id is unsigned. ids are always kept as
+    /// the bits they are stored with, this is used to turn them back into signed numbers.
+    pub signed_id_bits: Option<usize>,
+}
+
+impl AttrInfo {
+    /// Returns `id`, which is the bits the id is stored with, as a literal of the enum's id type.
+    pub fn id_literal(&self, id: u128) -> TokenStream {
+        let literal = match self.signed_id_bits {
+            Some(bits) => {
+                // sign extend the id.
+                let id = if bits < 128 && id >> (bits - 1) & 1 == 1 {
+                    id | !((1_u128 << bits) - 1)
+                } else {
+                    id
+                };
+                proc_macro2::Literal::i128_unsuffixed(id as i128)
+            }
+            None => proc_macro2::Literal::u128_unsuffixed(id),
+        };
+        quote! {#literal}
+    }
 }
 
 impl Default for AttrInfo {
@@ -996,8 +1959,14 @@ impl Default for AttrInfo {
             enforcement: StructEnforcement::NoRules,
             default_endianess: Endianness::None,
             fill_bits: None,
+            fill_pattern: None,
             id: None,
+            id_set: Vec::new(),
             invalid: false,
+            serde: false,
+            checked_setters: false,
+            negative_id: false,
+            signed_id_bits: None,
         }
     }
 }
@@ -1009,6 +1978,14 @@ pub struct StructInfo {
     pub fields: Vec<FieldInfo>,
     pub vis: syn::Visibility,
     pub tuple: bool,
+    /// a `Vec` field placed after all other fields, its length is determined at runtime.
+    pub variable_array: Option<VariableArrayInfo>,
+    /// fields filled in with a checksum of other bytes, in the order they are computed.
+    pub checksums: Vec<ChecksumInfo>,
+    /// fields filled in with the size of another field.
+    pub lengths: Vec<LengthInfo>,
+    /// fields always written with the same value.
+    pub constants: Vec<ConstantInfo>,
 }
 
 impl StructInfo {
@@ -1020,15 +1997,7 @@ impl StructInfo {
             }
         }
         if let Some(id) = self.attrs.id {
-            match TokenStream::from_str(format!("{id}").as_str()) {
-                Ok(id) => Ok(id),
-                Err(err) => Err(syn::Error::new(
-                    self.name.span(),
-                    format!(
-                        "variant id was not able to be formatted for of code generation. [{err}]"
-                    ),
-                )),
-            }
+            Ok(self.attrs.id_literal(id))
         } else {
             Err(syn::Error::new(
                 self.name.span(),
@@ -1036,6 +2005,50 @@ impl StructInfo {
             ))
         }
     }
+    /// Returns every id the variant is used for.
+    pub fn id_ranges(&self) -> Vec<RangeInclusive<u128>> {
+        if !self.attrs.id_set.is_empty() {
+            self.attrs.id_set.clone()
+        } else if let Some(id) = self.attrs.id {
+            vec![id..=id]
+        } else {
+            Vec::new()
+        }
+    }
+    /// Returns a pattern matching every id the variant is used for.
+    pub fn id_pattern(&self) -> syn::Result<TokenStream> {
+        let mut ranges = self.id_ranges();
+        if ranges.is_empty() {
+            return Err(syn::Error::new(
+                self.name.span(),
+                "failed to find id for variant, this is a bug in bondrewd.",
+            ));
+        }
+        // the ids with the sign bit set are negative, so ranges across it are split in two.
+        if let Some(bits) = self.attrs.signed_id_bits {
+            let sign = 1_u128 << (bits - 1);
+            ranges = ranges
+                .into_iter()
+                .flat_map(|ids| {
+                    if *ids.start() < sign && *ids.end() >= sign {
+                        vec![*ids.start()..=sign - 1, sign..=*ids.end()]
+                    } else {
+                        vec![ids]
+                    }
+                })
+                .collect();
+        }
+        let patterns = ranges.iter().map(|ids| {
+            let start = self.attrs.id_literal(*ids.start());
+            if ids.start() == ids.end() {
+                quote! {#start}
+            } else {
+                let end = self.attrs.id_literal(*ids.end());
+                quote! {#start..=#end}
+            }
+        });
+        Ok(quote! {#(#patterns)|*})
+    }
     pub fn total_bits(&self) -> usize {
         let mut total: usize = 0;
         for field in self.fields.iter() {
@@ -1055,6 +2068,8 @@ pub struct EnumInfo {
     pub variants: Vec<StructInfo>,
     pub attrs: EnumAttrInfo,
     pub vis: syn::Visibility,
+    /// fields marked `common`, which every variant has in the same bits.
+    pub common_fields: Vec<FieldInfo>,
 }
 
 impl EnumInfo {
@@ -1073,20 +2088,37 @@ impl EnumInfo {
         (self.total_bits() as f64 / 8.0f64).ceil() as usize
     }
     pub fn id_ident(&self) -> syn::Result<TokenStream> {
-        match self.attrs.id_bits {
-            0..=8 => Ok(quote! {u8}),
-            9..=16 => Ok(quote! {u16}),
-            17..=32 => Ok(quote! {u32}),
-            33..=64 => Ok(quote! {u64}),
-            65..=128 => Ok(quote! {u128}),
-            _ => {
-                return Err(syn::Error::new(
-                    self.name.span(),
-                    "variant id size is invalid",
-                ));
+        get_id_type(
+            self.attrs.id_bits,
+            self.id_signage(),
+            self.name.span(),
+        )
+    }
+    pub fn id_signage(&self) -> NumberSignage {
+        if self.attrs.attrs.signed_id_bits.is_some() {
+            NumberSignage::Signed
+        } else {
+            NumberSignage::Unsigned
+        }
+    }
+    /// The bits the variant id occupies.
+    pub fn id_range(&self) -> Range<usize> {
+        match self.attrs.id_position {
+            IdPosition::Leading => 0..self.attrs.id_bits,
+            IdPosition::Trailing => {
+                let total_bits = self.total_bits();
+                total_bits - self.attrs.id_bits..total_bits
             }
+            IdPosition::Bits(ref range) => range.clone(),
         }
     }
+    /// Returns the layout the `common` fields are read and written with, which is the same in every
+    /// variant, named after the enum.
+    pub fn common_struct_info(&self) -> StructInfo {
+        let mut info = self.variants[0].clone();
+        info.name = self.name.clone();
+        info
+    }
     pub fn generate_id_field(&self) -> syn::Result<FieldInfo> {
         let e = match &self.attrs.attrs.default_endianess {
             Endianness::Little => Endianness::Little,
@@ -1097,15 +2129,18 @@ impl EnumInfo {
             ident: Box::new(format_ident!("{}", EnumInfo::VARIANT_ID_NAME).into()),
             ty: FieldDataType::Number(
                 (self.attrs.id_bits as f64 / 8.0f64).ceil() as usize,
-                NumberSignage::Unsigned,
+                self.id_signage(),
                 self.id_ident()?,
             ),
             attrs: FieldAttrs {
                 endianness: Box::new(e),
-                bit_range: 0..self.attrs.id_bits,
+                bit_range: self.id_range(),
                 reserve: ReserveFieldOption::NotReserve,
                 overlap: OverlapOptions::None,
                 capture_id: false,
+                fill_pattern: None,
+                tag_from: None,
+                read_through: Vec::new(),
             },
         })
     }
@@ -1115,6 +2150,7 @@ impl EnumInfo {
 pub struct EnumAttrInfoBuilder {
     pub id_bits: Option<usize>,
     pub id_position: IdPosition,
+    pub fill_position: FillPosition,
     pub total_bit_size: Option<usize>,
     pub payload_bit_size: Option<usize>,
 }
@@ -1123,8 +2159,7 @@ pub struct EnumAttrInfoBuilder {
 pub struct EnumAttrInfo {
     pub id_bits: usize,
     pub id_position: IdPosition,
-    // TODO we should add an option of where to but the fill bytes. currently the generative code will always
-    // have the "useful" data proceeding each other then filler. maybe someone will want id -> fill -> variant_data
+    pub fill_position: FillPosition,
     /// The Full size of the enum. while we allow variants to be take differing sizes, the
     /// enum will always use the full size, filling unused space with a pattern
     /// of bytes. `payload_bit_size` is simply the largest variant's size and
@@ -1138,6 +2173,7 @@ impl Default for EnumAttrInfoBuilder {
         Self {
             id_bits: None,
             id_position: IdPosition::Leading,
+            fill_position: FillPosition::Trailing,
             total_bit_size: None,
             payload_bit_size: None,
         }
@@ -1150,13 +2186,18 @@ pub enum ObjectInfo {
 }
 
 /// `id_bits` is the amount of bits the enum's id takes.
-fn get_id_type(id_bits: usize, span: Span) -> syn::Result<TokenStream> {
-    match id_bits {
-        0..=8 => Ok(quote! {u8}),
-        9..=16 => Ok(quote! {u16}),
-        17..=32 => Ok(quote! {u32}),
-        33..=64 => Ok(quote! {u64}),
-        65..=128 => Ok(quote! {u128}),
+fn get_id_type(id_bits: usize, sign: NumberSignage, span: Span) -> syn::Result<TokenStream> {
+    match (id_bits, sign) {
+        (0..=8, NumberSignage::Unsigned) => Ok(quote! {u8}),
+        (9..=16, NumberSignage::Unsigned) => Ok(quote! {u16}),
+        (17..=32, NumberSignage::Unsigned) => Ok(quote! {u32}),
+        (33..=64, NumberSignage::Unsigned) => Ok(quote! {u64}),
+        (65..=128, NumberSignage::Unsigned) => Ok(quote! {u128}),
+        (0..=8, NumberSignage::Signed) => Ok(quote! {i8}),
+        (9..=16, NumberSignage::Signed) => Ok(quote! {i16}),
+        (17..=32, NumberSignage::Signed) => Ok(quote! {i32}),
+        (33..=64, NumberSignage::Signed) => Ok(quote! {i64}),
+        (65..=128, NumberSignage::Signed) => Ok(quote! {i128}),
         _ => {
             return Err(syn::Error::new(span, "id size is invalid"));
         }
@@ -1195,16 +2236,74 @@ impl ObjectInfo {
         }
         Ok(())
     }
-    // Parses the Expression, looking for a literal number expression
-    fn parse_lit_discriminant_expr(input: &Expr) -> syn::Result<u128> {
+    /// Returns the ids the variants of an enum use, variants without an id are given one the same
+    /// way `parse` does, and whether any id was negative.
+    fn scan_ids(
+        data: &syn::DataEnum,
+        attrs: &AttrInfo,
+    ) -> syn::Result<(Vec<RangeInclusive<u128>>, bool)> {
+        let mut used_ids: Vec<RangeInclusive<u128>> = Vec::default();
+        let mut unassigned = 0;
+        let mut negative = false;
+        for variant in data.variants.iter() {
+            let mut attrs = attrs.clone();
+            if let Some((_, ref expr)) = variant.discriminant {
+                let (id, negative_id) = Self::parse_lit_discriminant_expr(expr)?;
+                attrs.id = Some(id);
+                attrs.negative_id = negative_id;
+            }
+            Self::parse_struct_attrs(&variant.attrs, &mut attrs, true)?;
+            negative |= attrs.negative_id;
+            if !attrs.id_set.is_empty() {
+                used_ids.extend(attrs.id_set);
+            } else if let Some(id) = attrs.id {
+                used_ids.push(id..=id);
+            } else {
+                unassigned += 1;
+            }
+        }
+        let mut current_guess: u128 = 0;
+        for _ in 0..unassigned {
+            while used_ids.iter().any(|used| used.contains(&current_guess)) {
+                current_guess += 1;
+            }
+            used_ids.push(current_guess..=current_guess);
+            current_guess += 1;
+        }
+        Ok((used_ids, negative))
+    }
+    /// Returns the smallest amount of bits which can hold every id in `used_ids`.
+    fn infer_id_bits(used_ids: &[RangeInclusive<u128>], signed: bool) -> usize {
+        let bits = used_ids
+            .iter()
+            .flat_map(|ids| [*ids.start(), *ids.end()])
+            .map(|id| id_bits_for(id, signed))
+            .max()
+            .unwrap_or(0);
+        // an id needs at least 1 bit even if every variant uses 0.
+        bits.max(1)
+    }
+    // Parses the Expression, looking for a literal number expression. Returns the id and whether it
+    // was negative, negative ids are sign extended to 128 bits.
+    fn parse_lit_discriminant_expr(input: &Expr) -> syn::Result<(u128, bool)> {
         match input {
             Expr::Lit(ref lit) => match lit.lit {
-                Lit::Int(ref i) => Ok(i.base10_parse()?),
+                Lit::Int(ref i) => Ok((i.base10_parse()?, false)),
                 _ => Err(syn::Error::new(
                     input.span(),
                     "non-integer literals for custom discriminant are illegal.",
                 )),
             },
+            Expr::Unary(ref unary) if matches!(unary.op, syn::UnOp::Neg(_)) => {
+                match Self::parse_lit_discriminant_expr(&unary.expr)? {
+                    (0, false) => Ok((0, false)),
+                    (id, false) if id <= 1 << 127 => Ok((0_u128.wrapping_sub(id), true)),
+                    _ => Err(syn::Error::new(
+                        input.span(),
+                        "negative discriminant does not fit in an i128.",
+                    )),
+                }
+            }
             _ => Err(syn::Error::new(
                 input.span(),
                 "non-literal expressions for custom discriminant are illegal.",
@@ -1219,19 +2318,103 @@ impl ObjectInfo {
             syn::Data::Struct(ref data) => {
                 let tuple = matches!(data.fields, syn::Fields::Unnamed(_));
                 Self::parse_struct_attrs(&input.attrs, &mut attrs, false)?;
-                let fields = Self::parse_fields(&name, &data.fields, &attrs, None, tuple)?;
-                Ok(Self::Struct(StructInfo {
+                let (mut fields, variable_array) =
+                    Self::parse_fields(&name, &data.fields, &attrs, None, tuple)?;
+                for field in data.fields.iter() {
+                    reject_common(field)?;
+                    parse_tag_from(field, &mut fields)?;
+                }
+                let mut checksums = Vec::new();
+                let mut lengths = Vec::new();
+                let mut constants = Vec::new();
+                for field in data.fields.iter() {
+                    if let Some(checksum) = ChecksumInfo::parse(field, &fields, &attrs)? {
+                        checksums.push(checksum);
+                    }
+                    if let Some(length) = LengthInfo::parse(field, &fields, variable_array.as_ref())? {
+                        lengths.push(length);
+                    }
+                    if let Some(constant) = ConstantInfo::parse(field, &fields)? {
+                        constants.push(constant);
+                    }
+                }
+                let checksums = ChecksumInfo::fill_order(checksums, &fields)?;
+                let info = StructInfo {
                     name,
                     attrs,
                     fields,
                     vis: input.vis.clone(),
                     tuple,
-                }))
+                    variable_array,
+                    checksums,
+                    lengths,
+                    constants,
+                };
+                if let Some(ref variable) = info.variable_array {
+                    let count_field = if let Some(count_field) = info
+                        .fields
+                        .iter()
+                        .find(|f| f.ident().ident() == variable.count_from)
+                    {
+                        count_field
+                    } else {
+                        return Err(Error::new(
+                            variable.count_from.span(),
+                            format!("count_from field \"{}\" was not found", variable.count_from),
+                        ));
+                    };
+                    if !count_field.ty.is_number() {
+                        return Err(Error::new(
+                            count_field.span(),
+                            "the field a count_from array reads its length from must be a number",
+                        ));
+                    }
+                    if !count_field.attrs.reserve.write_field() {
+                        return Err(Error::new(
+                            count_field.span(),
+                            "the field a count_from array reads its length from must be writable",
+                        ));
+                    }
+                    let count_bits = count_field.attrs.bit_length();
+                    if count_bits < 128 && variable.max as u128 > (1_u128 << count_bits) - 1 {
+                        return Err(Error::new(
+                            variable.ident.span(),
+                            format!("max is larger than the count field \"{}\" can represent", variable.count_from),
+                        ));
+                    }
+                    if !info.total_bits().is_multiple_of(8) {
+                        return Err(Error::new(
+                            variable.ident.span(),
+                            "the fields before a count_from array must use a multiple of 8 bits",
+                        ));
+                    }
+                }
+                Ok(Self::Struct(info))
             }
             syn::Data::Enum(ref data) => {
                 let mut enum_attrs = EnumAttrInfoBuilder::default();
                 Self::parse_enum_attrs(&input.attrs, &mut attrs, &mut enum_attrs)?;
+                if let IdPosition::Bits(ref range) = enum_attrs.id_position {
+                    match enum_attrs.id_bits {
+                        Some(id_bits) if id_bits != range.end - range.start => {
+                            return Err(syn::Error::new(
+                                data.enum_token.span(),
+                                format!("id_bits range {}..{} does not match the id_bit_length of {id_bits}", range.start, range.end),
+                            ));
+                        }
+                        _ => enum_attrs.id_bits = Some(range.end - range.start),
+                    }
+                }
                 let mut variants: Vec<StructInfo> = Vec::default();
+                let (scanned_ids, negative) = Self::scan_ids(data, &attrs)?;
+                let repr = repr_id_bits(&input.attrs);
+                // only negative ids make the id signed, the repr only gives its size.
+                let signed = negative;
+                let id_sign = if signed {
+                    NumberSignage::Signed
+                } else {
+                    NumberSignage::Unsigned
+                };
                 let (id_field_type, id_bits) = {
                     let id_bits = if let Some(id_bits) = enum_attrs.id_bits {
                         id_bits
@@ -1239,75 +2422,96 @@ impl ObjectInfo {
                         (enum_attrs.payload_bit_size, enum_attrs.total_bit_size)
                     {
                         total_size - payload_size
+                    } else if let Some(repr_bits) = repr {
+                        repr_bits
                     } else {
-                        return Err(syn::Error::new(
-                            data.enum_token.span(),
-                            "Must define the length of the id use #[bondrewd(id_bit_length = AMOUNT_OF_BITS)]",
-                        ));
+                        Self::infer_id_bits(&scanned_ids, signed)
                     };
+                    enum_attrs.id_bits = Some(id_bits);
                     (
                         FieldDataType::Number(
                             (id_bits as f64 / 8.0f64).ceil() as usize,
-                            NumberSignage::Unsigned,
-                            get_id_type(id_bits, name.span())?,
+                            id_sign.clone(),
+                            get_id_type(id_bits, id_sign, name.span())?,
                         ),
                         id_bits,
                     )
                 };
+                if signed {
+                    attrs.signed_id_bits = Some(id_bits);
+                }
                 let id_field = FieldInfo {
                     ident: Box::new(format_ident!("{}", EnumInfo::VARIANT_ID_NAME).into()),
                     ty: id_field_type,
                     attrs: FieldAttrs {
                         endianness: Box::new(attrs.default_endianess.clone()),
-                        // this need to accommodate tailing ids, currently this locks the
-                        // id field to the first field read from the starting point of reading.
-                        // TODO make sure this gets corrected if the id size is unknown.
+                        // the id is parsed as the first field, it gets moved to where
+                        // `id_position` puts it once the size of every variant is known.
                         bit_range: 0..id_bits,
                         reserve: ReserveFieldOption::FakeReserveField,
                         overlap: OverlapOptions::None,
                         capture_id: false,
+                        fill_pattern: None,
+                        tag_from: None,
+                        read_through: Vec::new(),
                     },
                 };
+                let common = collect_common_fields(data)?;
                 for variant in data.variants.iter() {
                     let tuple = matches!(variant.fields, syn::Fields::Unnamed(_));
                     let mut attrs = attrs.clone();
                     if let Some((_, ref expr)) = variant.discriminant {
-                        let parsed = Self::parse_lit_discriminant_expr(expr)?;
+                        let (parsed, negative_id) = Self::parse_lit_discriminant_expr(expr)?;
                         attrs.id = Some(parsed);
+                        attrs.negative_id = negative_id;
                     }
                     Self::parse_struct_attrs(&variant.attrs, &mut attrs, true)?;
                     let variant_name = variant.ident.clone();
                     // TODO currently we always add the id field, but some people might want the id to be a
                     // field in the variant. this would no longer need to insert the id as a "fake-field".
-                    let fields = Self::parse_fields(
+                    let (fields, _) = Self::parse_fields(
                         &variant_name,
-                        &variant.fields,
+                        &with_common_fields(variant, &common)?,
                         &attrs,
                         Some(id_field.clone()),
                         tuple,
                     )?;
+                    for field in variant.fields.iter() {
+                        ChecksumInfo::reject(field, "enum variants")?;
+                        LengthInfo::reject(field, "enum variants")?;
+                        ConstantInfo::reject(field, "enum variants")?;
+                        reject_tag_from(field, "enum variants")?;
+                    }
                     variants.push(StructInfo {
                         name: variant_name,
                         attrs,
                         fields,
                         vis: input.vis.clone(),
                         tuple,
+                        variable_array: None,
+                        checksums: Vec::new(),
+                        lengths: Vec::new(),
+                        constants: Vec::new(),
                     });
                 }
                 // detect and fix variants without ids and verify non conflict.
-                let mut used_ids: Vec<u128> = Vec::default();
+                let mut used_ids: Vec<RangeInclusive<u128>> = Vec::default();
                 let mut unassigned_indices: Vec<usize> = Vec::default();
                 let mut invalid_index: Option<usize> = None;
                 let mut largest = 0;
                 for (i, variant) in variants.iter().enumerate() {
-                    if let Some(ref value) = variant.attrs.id {
-                        if used_ids.contains(value) {
-                            return Err(Error::new(
-                                variant.name.span(),
-                                "variant identifier used twice.",
-                            ));
-                        } else {
-                            used_ids.push(*value);
+                    if variant.attrs.id.is_some() {
+                        for ids in variant.id_ranges() {
+                            if used_ids
+                                .iter()
+                                .any(|used| used.start() <= ids.end() && ids.start() <= used.end())
+                            {
+                                return Err(Error::new(
+                                    variant.name.span(),
+                                    "variant identifier used twice.",
+                                ));
+                            }
+                            used_ids.push(ids);
                         }
                     } else {
                         unassigned_indices.push(i);
@@ -1348,11 +2552,11 @@ impl ObjectInfo {
                 if !unassigned_indices.is_empty() {
                     let mut current_guess: u128 = 0;
                     for i in unassigned_indices {
-                        while used_ids.contains(&current_guess) {
+                        while used_ids.iter().any(|used| used.contains(&current_guess)) {
                             current_guess += 1;
                         }
                         variants[i].attrs.id = Some(current_guess);
-                        used_ids.push(current_guess);
+                        used_ids.push(current_guess..=current_guess);
                         current_guess += 1;
                     }
                 }
@@ -1360,17 +2564,13 @@ impl ObjectInfo {
                     let var = variants.remove(ii);
                     variants.push(var);
                 }
-                // find minimal id size from largest id value
-                used_ids.sort();
-                let min_id_size = if let Some(last_id) = used_ids.last() {
-                    let mut x = last_id.clone();
-                    // find minimal id size from largest id value
-                    let mut n = 0;
-                    while x != 0 {
-                        x >>= 1;
-                        n += 1;
-                    }
-                    n
+                // find minimal id size from the id which needs the most bits.
+                let last_id = used_ids
+                    .iter()
+                    .flat_map(|ids| [*ids.start(), *ids.end()])
+                    .max_by_key(|id| id_bits_for(*id, signed));
+                let min_id_size = if let Some(last_id) = last_id {
+                    id_bits_for(last_id, signed)
                 } else {
                     return Err(Error::new(
                         data.enum_token.span(),
@@ -1384,6 +2584,7 @@ impl ObjectInfo {
                                 payload_bit_size: payload,
                                 id_bits: id,
                                 id_position: enum_attrs.id_position,
+                                fill_position: enum_attrs.fill_position,
                                 attrs: attrs.clone(),
                             }
                         } else {
@@ -1391,6 +2592,7 @@ impl ObjectInfo {
                                 payload_bit_size: payload,
                                 id_bits: min_id_size,
                                 id_position: enum_attrs.id_position,
+                                fill_position: enum_attrs.fill_position,
                                 attrs: attrs.clone(),
                             }
                         }
@@ -1401,6 +2603,7 @@ impl ObjectInfo {
                                 payload_bit_size: total - id,
                                 id_bits: id,
                                 id_position: enum_attrs.id_position,
+                                fill_position: enum_attrs.fill_position,
                                 attrs: attrs.clone(),
                             }
                         } else {
@@ -1410,6 +2613,7 @@ impl ObjectInfo {
                                     payload_bit_size: largest,
                                     id_bits: id,
                                     id_position: enum_attrs.id_position,
+                                    fill_position: enum_attrs.fill_position,
                                     attrs: attrs.clone(),
                                 }
                             } else {
@@ -1437,6 +2641,7 @@ impl ObjectInfo {
                             EnumAttrInfo {
                                 id_bits: id,
                                 id_position: enum_attrs.id_position,
+                                fill_position: enum_attrs.fill_position,
                                 payload_bit_size: payload,
                                 attrs: attrs.clone(),
                             }
@@ -1445,6 +2650,7 @@ impl ObjectInfo {
                                 payload_bit_size: largest,
                                 id_bits: min_id_size,
                                 id_position: enum_attrs.id_position,
+                                fill_position: enum_attrs.fill_position,
                                 attrs: attrs.clone(),
                             }
                         }
@@ -1454,6 +2660,7 @@ impl ObjectInfo {
                             EnumAttrInfo {
                                 id_bits: id,
                                 id_position: enum_attrs.id_position,
+                                fill_position: enum_attrs.fill_position,
                                 payload_bit_size: largest,
                                 attrs: attrs.clone(),
                             }
@@ -1462,6 +2669,7 @@ impl ObjectInfo {
                                 payload_bit_size: largest,
                                 id_bits: min_id_size,
                                 id_position: enum_attrs.id_position,
+                                fill_position: enum_attrs.fill_position,
                                 attrs: attrs.clone(),
                             }
                         }
@@ -1470,9 +2678,27 @@ impl ObjectInfo {
                 if enum_attrs.id_bits < min_id_size {
                     return Err(Error::new(
                         data.enum_token.span(),
-                        format!("the bit size being used is less than required to describe each variant"),
+                        format!(
+                            "the id uses {} bits but variant id {} needs {min_id_size} bits, increase id_bit_length or leave it out to use the smallest size that fits",
+                            enum_attrs.id_bits,
+                            if signed {
+                                format!("{}", last_id.unwrap_or_default() as i128)
+                            } else {
+                                format!("{}", last_id.unwrap_or_default())
+                            },
+                        ),
                     ));
                 }
+                // negative ids are sign extended, keep only the bits the id is stored with.
+                if signed && enum_attrs.id_bits < 128 {
+                    let mask = (1_u128 << enum_attrs.id_bits) - 1;
+                    for variant in variants.iter_mut() {
+                        variant.attrs.id = variant.attrs.id.map(|id| id & mask);
+                        for ids in variant.attrs.id_set.iter_mut() {
+                            *ids = (*ids.start() & mask)..=(*ids.end() & mask);
+                        }
+                    }
+                }
                 if enum_attrs.payload_bit_size + enum_attrs.id_bits < largest {
                     return Err(Error::new(
                         data.enum_token.span(),
@@ -1484,21 +2710,100 @@ impl ObjectInfo {
                 //     NumberSignage::Unsigned,
                 //     get_id_type(enum_attrs.id_bits, name.span())?,
                 // );
-                // add fill_bits if needed.
+                // place the id and add fill_bits if needed. the fields of each variant are laid
+                // out as if the id was not there, then the fields after the start of the id are
+                // moved past it.
+                let id_bits = enum_attrs.id_bits;
+                let footprint = match enum_attrs.id_position {
+                    IdPosition::Bits(ref range) => largest.max(range.end),
+                    _ => largest,
+                };
+                let id_range = match enum_attrs.id_position {
+                    IdPosition::Leading => 0..id_bits,
+                    IdPosition::Trailing => footprint - id_bits..footprint,
+                    IdPosition::Bits(ref range) => range.clone(),
+                };
+                let is_id = |f: &FieldInfo| {
+                    f.attrs.capture_id || f.ident().ident() == EnumInfo::VARIANT_ID_NAME
+                };
+                // moves a range without the id bits to where it is with the id placed.
+                let place = |range: Range<usize>| {
+                    if range.start >= id_range.start {
+                        (range.start + id_bits)..(range.end + id_bits)
+                    } else {
+                        range
+                    }
+                };
                 for v in variants.iter_mut() {
-                    let first_bit = v.total_bits();
-                    if first_bit < largest {
+                    let data_bits = v.total_bits() - id_bits;
+                    let fill_bits = footprint - id_bits - data_bits;
+                    let fill_start = match enum_attrs.fill_position {
+                        FillPosition::Trailing => data_bits,
+                        // right after the id, unless every field comes before the id.
+                        FillPosition::AfterId => id_range.start.min(data_bits),
+                    };
+                    let parsed_id_range = v
+                        .fields
+                        .iter()
+                        .find(|f| is_id(f))
+                        .map(|f| f.attrs.bit_range.clone())
+                        .unwrap_or(0..0);
+                    for field in v.fields.iter_mut() {
+                        if is_id(field) {
+                            field.attrs.bit_range = id_range.clone();
+                            continue;
+                        }
+                        let mut range = field.attrs.bit_range.clone();
+                        if range.start >= parsed_id_range.end {
+                            range = (range.start - id_bits)..(range.end - id_bits);
+                        }
+                        if range.start >= fill_start {
+                            range = (range.start + fill_bits)..(range.end + fill_bits);
+                        }
+                        if range.start < id_range.start && range.end > id_range.start {
+                            return Err(Error::new(
+                                field.span(),
+                                format!(
+                                    "field overlaps the variant id at bits {}..{}, fields must end before the id or start after it",
+                                    id_range.start, id_range.end
+                                ),
+                            ));
+                        }
+                        field.attrs.bit_range = place(range);
+                    }
+                    if fill_bits == 0 {
+                        continue;
+                    }
+                    let fill_range = fill_start..fill_start + fill_bits;
+                    let fill_ranges = if fill_range.start < id_range.start
+                        && fill_range.end > id_range.start
+                    {
+                        vec![
+                            fill_range.start..id_range.start,
+                            id_range.end..fill_range.end + id_bits,
+                        ]
+                    } else {
+                        vec![place(fill_range)]
+                    };
+                    for (i, fill_range) in fill_ranges.into_iter().enumerate() {
                         let fill_bytes_size =
-                            ((largest - first_bit) as f64 / 8.0_f64).ceil() as usize;
-                        let ident = quote::format_ident!("fill_bits");
+                            ((fill_range.end - fill_range.start) as f64 / 8.0_f64).ceil() as usize;
+                        let ident = if i == 0 {
+                            quote::format_ident!("fill_bits")
+                        } else {
+                            quote::format_ident!("fill_bits_after_id")
+                        };
                         v.fields.push(FieldInfo {
                             ident: Box::new(ident.into()),
                             attrs: FieldAttrs {
-                                bit_range: first_bit..largest,
+                                bit_range: fill_range,
                                 endianness: Box::new(Endianness::Big),
                                 reserve: ReserveFieldOption::FakeReserveField,
                                 overlap: OverlapOptions::None,
                                 capture_id: false,
+                                fill_pattern: None,
+                                tag_from: None,
+                                read_through: Vec::new(),
                             },
                             ty: FieldDataType::BlockArray(
                                 Box::new(SubFieldInfo {
@@ -1514,11 +2819,44 @@ impl ObjectInfo {
                         });
                     }
                 }
+                // common fields must end up in the same bits of every variant, fill placed after
+                // the id moves them.
+                let mut common_fields = Vec::new();
+                for common_field in common.iter() {
+                    let ident = common_field.ident.clone().unwrap_or_else(|| name.clone());
+                    let mut found: Option<(&Ident, &FieldInfo)> = None;
+                    for v in variants.iter() {
+                        let field = v.fields.iter().find(|f| f.ident().ident() == ident);
+                        match (field, found) {
+                            (Some(field), None) => found = Some((&v.name, field)),
+                            (Some(field), Some((first_name, first)))
+                                if field.attrs.bit_range != first.attrs.bit_range =>
+                            {
+                                return Err(Error::new(
+                                    common_field.span(),
+                                    format!(
+                                        "common field `{ident}` uses bits {}..{} in variant `{first_name}` but bits {}..{} in variant `{}`, it must use the same bits in every variant.",
+                                        first.attrs.bit_range.start,
+                                        first.attrs.bit_range.end,
+                                        field.attrs.bit_range.start,
+                                        field.attrs.bit_range.end,
+                                        v.name,
+                                    ),
+                                ));
+                            }
+                            _ => {}
+                        }
+                    }
+                    if let Some((_, field)) = found {
+                        common_fields.push(field.clone());
+                    }
+                }
                 Ok(Self::Enum(EnumInfo {
                     name,
                     variants,
                     attrs: enum_attrs,
                     vis: input.vis.clone(),
+                    common_fields,
                 }))
             }
             _ => Err(Error::new(Span::call_site(), "input can not be a union")),
@@ -1582,6 +2920,57 @@ impl ObjectInfo {
                             }
                         }
                     }
+                } else if value.path.is_ident("id_bits") {
+                    if let Lit::Str(ref val) = value.lit {
+                        let value = val.value();
+                        let range = if let Some((start, end)) = value.split_once("..=") {
+                            match (start.trim().parse::<usize>(), end.trim().parse::<usize>()) {
+                                (Ok(start), Ok(end)) => Some(start..end + 1),
+                                _ => None,
+                            }
+                        } else if let Some((start, end)) = value.split_once("..") {
+                            match (start.trim().parse::<usize>(), end.trim().parse::<usize>()) {
+                                (Ok(start), Ok(end)) => Some(start..end),
+                                _ => None,
+                            }
+                        } else {
+                            None
+                        };
+                        match range {
+                            Some(range) if range.start < range.end && range.end - range.start <= 128 => {
+                                enum_info.id_position = IdPosition::Bits(range);
+                            }
+                            _ => {
+                                return Err(syn::Error::new(
+                                    span,
+                                    "id_bits must be a range of at most 128 bits ex. `id_bits = \"12..16\"`",
+                                ));
+                            }
+                        }
+                    } else {
+                        return Err(syn::Error::new(
+                            span,
+                            "improper usage of id_bits, must use literal str ex. `id_bits = \"12..16\"`",
+                        ));
+                    }
+                } else if value.path.is_ident("fill_position") {
+                    if let Lit::Str(ref val) = value.lit {
+                        enum_info.fill_position = match val.value().as_str() {
+                            "trailing" => FillPosition::Trailing,
+                            "after_id" => FillPosition::AfterId,
+                            _ => {
+                                return Err(syn::Error::new(
+                                    span,
+                                    "fill_position must be \"trailing\" or \"after_id\"",
+                                ))
+                            }
+                        };
+                    } else {
+                        return Err(syn::Error::new(
+                            span,
+                            "improper usage of fill_position, must use literal str ex. `fill_position = \"after_id\"`",
+                        ));
+                    }
                 } else if value.path.is_ident("payload_bit_length") {
                     if let Lit::Int(ref val) = value.lit {
                         match val.base10_parse::<usize>() {
@@ -1654,7 +3043,32 @@ impl ObjectInfo {
         match meta {
             Meta::NameValue(ref value) => {
                 if is_variant && value.path.is_ident(EnumInfo::VARIANT_ID_NAME) {
-                    if let Lit::Int(ref val) = value.lit {
+                    if let Lit::Str(ref val) = value.lit {
+                        let (id_set, negative) = if let Some(id_set) = parse_id_set(&val.value()) {
+                            id_set
+                        } else {
+                            return Err(syn::Error::new(
+                                val.span(),
+                                format!(
+                                    "{} must be a list of ids or ranges of ids ex. `{} = \"3, 7, 0x10..=0x1F\"`",
+                                    EnumInfo::VARIANT_ID_NAME,
+                                    EnumInfo::VARIANT_ID_NAME,
+                                ),
+                            ));
+                        };
+                        if info.id.is_some() {
+                            return Err(syn::Error::new(span, "must not have 2 ids defined."));
+                        }
+                        // negative ids are sign extended, so they are compared as signed numbers.
+                        let starts = id_set.iter().map(|ids| *ids.start());
+                        info.id = if negative {
+                            starts.min_by_key(|id| *id as i128)
+                        } else {
+                            starts.min()
+                        };
+                        info.negative_id |= negative;
+                        info.id_set = id_set;
+                    } else if let Lit::Int(ref val) = value.lit {
                         match val.base10_parse::<u128>() {
                             Ok(value) => {
                                 if info.id.is_none() {
@@ -1753,6 +3167,25 @@ impl ObjectInfo {
                             format!("improper usage of enforce_bits, must use literal integer ex. `enforce_bits = 5`"),
                         ));
                     }
+                } else if value.path.is_ident("fill_pattern") {
+                    if let Lit::Int(ref val) = value.lit {
+                        match val.base10_parse::<u8>() {
+                            Ok(value) => {
+                                info.fill_pattern = Some(value);
+                            }
+                            Err(err) => {
+                                return Err(syn::Error::new(
+                                    span,
+                                    format!("failed parsing fill_pattern value [{}]", err),
+                                ))
+                            }
+                        }
+                    } else {
+                        return Err(syn::Error::new(
+                            span,
+                            "improper usage of fill_pattern, must use literal u8 ex. `fill_pattern = 0xFF`",
+                        ));
+                    }
                 } else if value.path.is_ident("fill_bytes") {
                     if let Lit::Int(ref val) = value.lit {
                         match val.base10_parse::<usize>() {
@@ -1793,6 +3226,12 @@ impl ObjectInfo {
                         "invalid" => {
                             info.invalid = true;
                         }
+                        "serde" => {
+                            info.serde = true;
+                        }
+                        "checked_setters" => {
+                            info.checked_setters = true;
+                        }
                         _ => {}
                     }
                 }
@@ -1818,7 +3257,7 @@ impl ObjectInfo {
         attrs: &AttrInfo,
         first_field: Option<FieldInfo>,
         tuple: bool,
-    ) -> syn::Result<Vec<FieldInfo>> {
+    ) -> syn::Result<(Vec<FieldInfo>, Option<VariableArrayInfo>)> {
         let (mut parsed_fields, is_enum) = if let Some(f) = first_field {
             (vec![f], true)
         } else {
@@ -1852,9 +3291,34 @@ impl ObjectInfo {
         } else {
             0
         };
+        let mut variable_array = None;
         if let Some(fields) = fields {
             for (i, ref field) in fields.iter().enumerate() {
+                if let Some(variable) = VariableArrayInfo::parse(field, attrs)? {
+                    if is_enum {
+                        return Err(Error::new(
+                            field.span(),
+                            "count_from is not currently supported in enum variants.",
+                        ));
+                    }
+                    if i != fields.len() - 1 {
+                        return Err(Error::new(
+                            field.span(),
+                            "count_from field must be the last field.",
+                        ));
+                    }
+                    variable_array = Some(variable);
+                    continue;
+                }
                 let mut parsed_field = FieldInfo::from_syn_field(field, &parsed_fields, attrs)?;
+                if parsed_field.attrs.fill_pattern.is_some()
+                    && !matches!(parsed_field.attrs.reserve, ReserveFieldOption::ReserveField)
+                {
+                    return Err(Error::new(
+                        field.span(),
+                        "fill_pattern can only be used on reserve fields",
+                    ));
+                }
                 if parsed_field.attrs.capture_id {
                     if is_enum {
                         if i == 0 {
@@ -1864,7 +3328,7 @@ impl ObjectInfo {
                                         parsed_field.attrs.bit_range = parsed_fields[0].attrs.bit_range.clone();
                                     }
                                     if bon_sign != user_sign {
-                                        return Err(Error::new(field.span(), format!("capture_id field must be unsigned. bondrewd will enforce the type as {bon_ty}")));
+                                        return Err(Error::new(field.span(), format!("capture_id field must have the same sign as the id. bondrewd will enforce the type as {bon_ty}")));
                                     }else if bon_ty.to_string() != user_ty.to_string() {
                                         return Err(Error::new(field.span(), format!("capture_id field currently must be {bon_ty} in this instance, because bondrewd makes an assumption about the id type. changing this would be difficult")));
                                     }
@@ -1873,7 +3337,7 @@ impl ObjectInfo {
                                         parsed_field.ident = old_id.ident;
                                     }
                                 }
-                                (FieldDataType::Number(_bon_bits, _bon_sign, bon_ty), _) => return Err(Error::new(field.span(), format!("capture_id field must be an unsigned number. detected type is {bon_ty}."))),
+                                (FieldDataType::Number(_bon_bits, _bon_sign, bon_ty), _) => return Err(Error::new(field.span(), format!("capture_id field must be a number. the id type is {bon_ty}."))),
                                 _ => return Err(Error::new(field.span(), "an error with bondrewd has occurred, the id field should be a number but bondrewd did not use a number for the id.")),
                             }
                         } else {
@@ -1935,6 +3399,9 @@ impl ObjectInfo {
                     reserve: ReserveFieldOption::FakeReserveField,
                     overlap: OverlapOptions::None,
                     capture_id: false,
+                    fill_pattern: None,
+                    tag_from: None,
+                    read_through: Vec::new(),
                 },
                 ty: FieldDataType::BlockArray(
                     Box::new(SubFieldInfo {
@@ -1954,6 +3421,6 @@ impl ObjectInfo {
             parsed_fields.reverse();
         }
 
-        Ok(parsed_fields)
+        Ok((parsed_fields, variable_array))
     }
 }
@@ -0,0 +1,42 @@
+use crate::structs::common::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `into_bytes_compact` and `from_slice_compact`, which leave out the fill bits smaller
+/// variants are padded with. The sizes come from the `Bitfields` streaming hooks, so compact bytes
+/// are the same bytes `bondrewd::io::write_to` writes.
+pub fn create_compact_quotes(info: &EnumInfo) -> TokenStream {
+    let enum_name = &info.name;
+    let enum_size = info.total_bytes();
+    let into_comment = format!(
+        "Returns the bitfield form of a [{enum_name}] along with the amount of Bytes at the start of it the variant uses, the fill after the variant's fields is not needed to decode it with [{enum_name}::from_slice_compact]."
+    );
+    let from_comment = format!(
+        "Extracts a [{enum_name}] from the Bytes [{enum_name}::into_bytes_compact] uses, only as many Bytes as the variant id says the variant uses are required.\n # Errors\n If `input_byte_buffer` does not have enough Bytes for the id or the variant an error will be returned."
+    );
+    quote! {
+        #[doc = #into_comment]
+        pub fn into_bytes_compact(self) -> (usize, [u8;#enum_size]) {
+            let output_byte_buffer = <Self as bondrewd::Bitfields<#enum_size>>::into_bytes(self);
+            let size = <Self as bondrewd::Bitfields<#enum_size>>::stream_byte_size(&output_byte_buffer);
+            (size.min(#enum_size), output_byte_buffer)
+        }
+        #[doc = #from_comment]
+        pub fn from_slice_compact(input_byte_buffer: &[u8]) -> Result<Self, bondrewd::BitfieldLengthError> {
+            let buf_len = input_byte_buffer.len();
+            let header_size = <Self as bondrewd::Bitfields<#enum_size>>::STREAM_HEADER_BYTE_SIZE.min(#enum_size);
+            if buf_len < header_size {
+                return Err(bondrewd::BitfieldLengthError(buf_len, header_size));
+            }
+            let mut output_byte_buffer = [0u8;#enum_size];
+            output_byte_buffer[..header_size].copy_from_slice(&input_byte_buffer[..header_size]);
+            let size = <Self as bondrewd::Bitfields<#enum_size>>::stream_byte_size(&output_byte_buffer)
+                .clamp(header_size, #enum_size);
+            if buf_len < size {
+                return Err(bondrewd::BitfieldLengthError(buf_len, size));
+            }
+            output_byte_buffer[header_size..size].copy_from_slice(&input_byte_buffer[header_size..size]);
+            Ok(<Self as bondrewd::Bitfields<#enum_size>>::from_bytes(output_byte_buffer))
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::structs::common::{FieldDataType, StructInfo};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{GenericArgument, PathArguments, Type};
+
+/// Returns the `T` of a `constant` field's `Constant<T>` type, which is the type the value is stored
+/// as. Constant fields must use the zero-sized `bondrewd::Constant` so no value has to be provided.
+pub fn constant_stored_type(ty: &Type, span: Span) -> syn::Result<&Type> {
+    if let Type::Path(ref path) = ty {
+        if let Some(last_segment) = path.path.segments.last() {
+            if last_segment.ident == "Constant" {
+                if let PathArguments::AngleBracketed(ref args) = last_segment.arguments {
+                    if let (1, Some(GenericArgument::Type(ref stored))) =
+                        (args.args.len(), args.args.first())
+                    {
+                        return Ok(stored);
+                    }
+                }
+            }
+        }
+    }
+    Err(syn::Error::new(
+        span,
+        "constant fields must use the zero-sized `bondrewd::Constant` type, ex. `sync: Constant<u32>`",
+    ))
+}
+
+/// Returns the value a `constant` field is always written with, `None` if the field named `ident`
+/// is not a constant.
+pub fn constant_value_quote(info: &StructInfo, ident: &Ident) -> Option<TokenStream> {
+    let constant = info.constants.iter().find(|c| &c.ident == ident)?;
+    let field = info.fields.iter().find(|f| &f.ident().ident() == ident)?;
+    let value = constant.value;
+    Some(match field.ty {
+        FieldDataType::Boolean => {
+            let value = value != 0;
+            quote! {#value}
+        }
+        _ => {
+            let ty = field.ty.type_quote();
+            quote! {(#value as #ty)}
+        }
+    })
+}
+
+/// Returns true if `ident` names a `constant` field.
+pub fn is_constant(info: &StructInfo, ident: &Ident) -> bool {
+    info.constants.iter().any(|c| &c.ident == ident)
+}
+
+/// Generates a `check_constants` function which returns an error naming the first `constant`
+/// field that does not contain its value. Nothing is generated if there are no constant fields.
+pub fn create_check_constants_quote(info: &StructInfo) -> TokenStream {
+    if info.constants.is_empty() {
+        return quote! {};
+    }
+    let struct_size = info.total_bytes();
+    let struct_name = &info.name;
+    let mut checks = quote! {};
+    for constant in info.constants.iter() {
+        let field = if let Some(field) = info
+            .fields
+            .iter()
+            .find(|f| f.ident().ident() == constant.ident)
+        {
+            field
+        } else {
+            continue;
+        };
+        let read_fn = format_ident!("read_{}", constant.ident);
+        let name = constant.ident.to_string();
+        let value = constant.value;
+        let (start, end) = (field.attrs.bit_range.start, field.attrs.bit_range.end);
+        checks = quote! {
+            #checks
+            {
+                let found = Self::#read_fn(input_byte_buffer) as u128;
+                if found != #value {
+                    return Err(bondrewd::BitfieldTryError {
+                        field: #name,
+                        bit_range: #start..#end,
+                        kind: bondrewd::BitfieldTryErrorKind::ConstantMismatch(found, #value),
+                    });
+                }
+            }
+        };
+    }
+    let comment = format!("Returns an error if a `constant` field of a [{struct_name}] in bitfield form does not contain its value.");
+    quote! {
+        #[doc = #comment]
+        pub fn check_constants(input_byte_buffer: &[u8;#struct_size]) -> Result<(), bondrewd::BitfieldTryError> {
+            #checks
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::structs::common::{EnumInfo, FieldInfo, ReserveFieldOption, StructInfo};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Returns the index of each byte in bitfield form the field touches along with a mask of the
+/// bits it uses in that byte.
+pub fn field_byte_masks(info: &StructInfo, field: &FieldInfo) -> Vec<(usize, u8)> {
+    let flip = if info.attrs.flip {
+        Some(info.total_bytes() - 1)
+    } else {
+        None
+    };
+    let mut masks: Vec<(usize, u8)> = Vec::default();
+    for bit in field.attrs.bit_range.clone() {
+        let byte = if let Some(last) = flip {
+            last - (bit / 8)
+        } else {
+            bit / 8
+        };
+        let mask = 0b1000_0000_u8 >> (bit % 8);
+        if let Some(last_mask) = masks.last_mut() {
+            if last_mask.0 == byte {
+                last_mask.1 |= mask;
+                continue;
+            }
+        }
+        masks.push((byte, mask));
+    }
+    masks
+}
+
+/// Returns the byte pattern the field is written with, `None` if the field is not a reserve field
+/// or fill bits.
+pub fn field_fill_pattern(info: &StructInfo, field: &FieldInfo) -> Option<u8> {
+    let is_fill = match field.attrs.reserve {
+        ReserveFieldOption::ReserveField => true,
+        // the only fake field which is not fill is the enum variant id.
+        ReserveFieldOption::FakeReserveField => field.ident().ident() != EnumInfo::VARIANT_ID_NAME,
+        _ => false,
+    };
+    if is_fill {
+        Some(
+            field
+                .attrs
+                .fill_pattern
+                .or(info.attrs.fill_pattern)
+                .unwrap_or(0),
+        )
+    } else {
+        None
+    }
+}
+
+/// Generates the code which writes the `fill_pattern` into the bits of reserve fields and fill
+/// bits. Reserve fields are never written so their bits are still zero when this runs.
+pub fn create_fill_pattern_quote(info: &StructInfo, buffer: &TokenStream) -> TokenStream {
+    let mut fill_quote = quote! {};
+    for field in info.fields.iter() {
+        let pattern = match field_fill_pattern(info, field) {
+            Some(pattern) if pattern != 0 => pattern,
+            _ => continue,
+        };
+        for (byte, mask) in field_byte_masks(info, field) {
+            let value = pattern & mask;
+            if value != 0 {
+                fill_quote = quote! {
+                    #fill_quote
+                    #buffer[#byte] |= #value;
+                };
+            }
+        }
+    }
+    fill_quote
+}
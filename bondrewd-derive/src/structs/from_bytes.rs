@@ -1,8 +1,8 @@
-use std::{cmp::Ordering, str::FromStr};
+use std::cmp::Ordering;
 
 use crate::structs::common::{
     get_be_starting_index, get_left_and_mask, get_right_and_mask, BitMath, Endianness,
-    FieldDataType, FieldInfo, StructInfo,
+    FieldDataType, FieldIdent, FieldInfo, StructInfo,
 };
 
 use convert_case::{Case, Casing};
@@ -10,11 +10,19 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use syn::{token::Pub, VisPublic};
 
-use super::common::{EnumInfo, NumberSignage};
+use super::common::{EnumInfo, NumberSignage, ReserveFieldOption};
 
 pub struct FromBytesOptions {
     pub from_bytes_fn: TokenStream,
+    pub try_from_bytes_fn: TokenStream,
+    /// Empty for enums because the variant can change, so they use the default which replaces `self`.
+    pub update_from_fn: TokenStream,
+    /// Overrides the streaming hooks of `Bitfields` for enums so only the id and the bytes the
+    /// variant uses are read. Empty for structs.
+    pub stream_fns: TokenStream,
     pub peek_field_fns: TokenStream,
+    /// `read_` functions for the generated view types, which read from `self.buffer`.
+    pub peek_view_field_fns: TokenStream,
     pub from_slice_field_fns: Option<TokenStream>,
     pub peek_slice_field_fns: Option<TokenStream>,
     pub peek_slice_field_unchecked_fns: Option<TokenStream>,
@@ -23,8 +31,12 @@ pub struct FromBytesOptions {
 struct FieldQuotes {
     field_name_list: TokenStream,
     from_bytes_quote: TokenStream,
+    /// same as `from_bytes_quote` but nested bitfields are decoded with `checked_from_bytes`.
+    try_from_bytes_quote: TokenStream,
+    update_from_quote: TokenStream,
     from_vec_quote: TokenStream,
     peek_fns_quote: TokenStream,
+    peek_view_fns_quote: TokenStream,
     peek_slice_fns_option: Option<(TokenStream, TokenStream)>,
 }
 fn make_read_fns(
@@ -32,6 +44,7 @@ fn make_read_fns(
     info: &StructInfo,
     enum_name: &Option<Ident>,
     peek_fns_quote: &mut TokenStream,
+    peek_view_fns_quote: &mut TokenStream,
     peek_slice_fns_option: &mut Option<(TokenStream, TokenStream)>,
 ) -> syn::Result<TokenStream> {
     // let peek_name = if let Some((prefix, _, _)) = enum_name {
@@ -53,6 +66,22 @@ fn make_read_fns(
         #peek_fns_quote
         #peek_quote
     };
+    if let FieldDataType::Struct(ref size, ref ty) = field.ty {
+        if field.attrs.tag_from.is_none() {
+            let peek_bytes_quote = make_peek_bytes_fn(field, *size, ty, info, enum_name)?;
+            let read_through_quote = make_read_through_fns(field, ty, info, enum_name);
+            *peek_fns_quote = quote! {
+                #peek_fns_quote
+                #peek_bytes_quote
+                #read_through_quote
+            };
+        }
+    }
+    let peek_view_quote = make_peek_view_fn(&field_extractor, field, info, enum_name)?;
+    *peek_view_fns_quote = quote! {
+        #peek_view_fns_quote
+        #peek_view_quote
+    };
     // make the slice functions if applicable.
     if let Some((ref mut the_peek_slice_fns_quote, ref mut unchecked_quote)) = peek_slice_fns_option
     {
@@ -73,6 +102,16 @@ fn make_read_fns(
     Ok(field_extractor)
 }
 
+/// Overrides `Bitfields::checked_from_bytes` so nested fields of this type are checked by the
+/// `try_from_bytes` of the structure holding them.
+fn get_checked_from_bytes_fn(struct_size: usize) -> TokenStream {
+    quote! {
+        fn checked_from_bytes(input_byte_buffer: [u8;#struct_size]) -> Result<Self, bondrewd::BitfieldTryError> {
+            <Self as bondrewd::TryBitfields<#struct_size>>::try_from_bytes(input_byte_buffer)
+        }
+    }
+}
+
 fn get_check_slice_fn(
     name: &Ident,
     // total_bytes
@@ -113,6 +152,9 @@ fn create_fields_quotes(
     let mut field_name_list = quote! {};
     // all of the fields extraction will be appended to this
     let mut from_bytes_quote = quote! {};
+    let mut try_from_bytes_quote = quote! {};
+    // all of the fields that get read are assigned directly into self here.
+    let mut update_from_quote = quote! {};
     let mut from_vec_quote = quote! {};
     // all quote with all of the peek slice functions appended to it. the second tokenstream is an unchecked
     // version for the checked_struct.
@@ -130,6 +172,13 @@ fn create_fields_quotes(
     };
     // all quote with all of the peek functions appended to it.
     let mut peek_fns_quote = quote! {};
+    // same as peek_fns_quote but for the view types.
+    let mut peek_view_fns_quote = quote! {};
+    let flip = if info.attrs.flip {
+        Some(info.total_bytes() - 1)
+    } else {
+        None
+    };
     // TODO make each variant decide if the id field needs to be accounted for.
     // currently the id fields is added the each enum variant as the first field so we
     // skip it assuming there will be one made that is common across all variants.
@@ -146,6 +195,7 @@ fn create_fields_quotes(
             info,
             &lower_name,
             &mut peek_fns_quote,
+            &mut peek_view_fns_quote,
             &mut peek_slice_fns_option,
         )?;
         // fake fields do not exist in the actual structure and should only have functions
@@ -169,7 +219,10 @@ fn create_fields_quotes(
                         "fields with attribute 'capture_id' are automatically considered 'read_only', meaning it can not have the 'reserve' attribute.",
                     ));
                 }
-            }else {
+            } else if super::constant::is_constant(info, field_name) {
+                // the value of a constant only exists in bitfield form.
+                quote! { let #field_name = bondrewd::Constant::new(); }
+            } else {
                 // put the field extraction in the actual from bytes.
                 if field.attrs.reserve.read_field() {
                     let fn_field_name = if let Some(ref p) = lower_name {
@@ -177,6 +230,10 @@ fn create_fields_quotes(
                     } else {
                         format_ident!("read_{field_name}")
                     };
+                    update_from_quote = quote! {
+                        #update_from_quote
+                        self.#field_name = Self::#fn_field_name(input_byte_buffer);
+                    };
                     quote! {
                         let #field_name = Self::#fn_field_name(&input_byte_buffer);
                     }
@@ -188,6 +245,35 @@ fn create_fields_quotes(
                 #from_bytes_quote
                 #peek_call;
             };
+            let checked_extractor = if field.attrs.capture_id
+                || super::constant::is_constant(info, field_name)
+                || !field.attrs.reserve.read_field()
+            {
+                None
+            } else {
+                let name = if enum_name.is_some() {
+                    format!("{}::{}", info.name, field_name)
+                } else {
+                    field_name.to_string()
+                };
+                get_checked_field_quote(field, flip, &name)?
+            };
+            try_from_bytes_quote = if let Some(checked_extractor) = checked_extractor {
+                quote! {
+                    #try_from_bytes_quote
+                    let #field_name = #checked_extractor;
+                }
+            } else {
+                quote! {
+                    #try_from_bytes_quote
+                    #peek_call;
+                }
+            };
+            let field_extractor = if super::constant::is_constant(info, field_name) {
+                quote! {bondrewd::Constant::new()}
+            } else {
+                field_extractor
+            };
             from_vec_quote = quote! {
                 #from_vec_quote
                 let #field_name = #field_extractor;
@@ -197,8 +283,11 @@ fn create_fields_quotes(
     Ok(FieldQuotes {
         field_name_list,
         from_bytes_quote,
+        try_from_bytes_quote,
+        update_from_quote,
         from_vec_quote,
         peek_fns_quote,
+        peek_view_fns_quote,
         peek_slice_fns_option,
     })
 }
@@ -209,7 +298,7 @@ pub fn create_from_bytes_field_quotes_enum(
 ) -> Result<FromBytesOptions, syn::Error> {
     let mut from_bytes_fn: TokenStream = quote! {};
     let mut from_vec_fn: TokenStream = quote! {};
-    let (mut peek_fns_quote, mut peek_slice_fns_option) = {
+    let ((mut peek_fns_quote, mut peek_view_fns_quote), mut peek_slice_fns_option) = {
         (
             {
                 let field = info.generate_id_field()?;
@@ -235,6 +324,10 @@ pub fn create_from_bytes_field_quotes_enum(
                         pub_token: Pub::default(),
                     }),
                     tuple: false,
+                    variable_array: None,
+                    checksums: Vec::new(),
+                    lengths: Vec::new(),
+                    constants: Vec::new(),
                 };
                 let id_field = make_peek_fn(
                     &field_extractor,
@@ -242,7 +335,13 @@ pub fn create_from_bytes_field_quotes_enum(
                     &temp_struct_info,
                     &None,
                 )?;
-                if peek_slice {
+                let id_view_field = make_peek_view_fn(
+                    &field_extractor,
+                    &field,
+                    &temp_struct_info,
+                    &None,
+                )?;
+                let id_field = if peek_slice {
                     let id_slice_peek = make_peek_slice_fn(
                         &field_extractor,
                         &field,
@@ -257,7 +356,8 @@ pub fn create_from_bytes_field_quotes_enum(
                     quote! {
                         #id_field
                     }
-                }
+                };
+                (id_field, id_view_field)
             },
             if peek_slice {
                 Some((
@@ -269,8 +369,22 @@ pub fn create_from_bytes_field_quotes_enum(
             },
         )
     };
+    // common fields use the same bits in every variant, so they can be read without knowing the variant.
+    let common_info = info.common_struct_info();
+    for field in info.common_fields.iter() {
+        make_read_fns(
+            field,
+            &common_info,
+            &None,
+            &mut peek_fns_quote,
+            &mut peek_view_fns_quote,
+            &mut peek_slice_fns_option,
+        )?;
+    }
     let struct_size = info.total_bytes();
     let last_variant = info.variants.len() - 1;
+    let mut try_from_bytes_fn: TokenStream = quote! {};
+    let mut stream_size_fn: TokenStream = quote! {};
     for (i, variant) in info.variants.iter().enumerate() {
         // this is the slice indexing that will fool the set function code into thinking
         // it is looking at a smaller array.
@@ -281,12 +395,14 @@ pub fn create_from_bytes_field_quotes_enum(
         let v_byte_size = variant.total_bytes();
         let v_bit_size = variant.total_bits();
         let variant_name = quote! {#v_name};
-        let (field_name_list, peek_fns_quote_temp, from_bytes_quote, peek_slice_fns_option_temp, from_vec_quote) = {
+        let (field_name_list, peek_fns_quote_temp, peek_view_fns_quote_temp, from_bytes_quote, try_from_bytes_quote, peek_slice_fns_option_temp, from_vec_quote) = {
             let thing = create_fields_quotes(variant, Some(info.name.clone()), peek_slice)?;
             (
                 thing.field_name_list,
                 thing.peek_fns_quote,
+                thing.peek_view_fns_quote,
                 thing.from_bytes_quote,
+                thing.try_from_bytes_quote,
                 thing.peek_slice_fns_option,
                 thing.from_vec_quote,
             )
@@ -313,6 +429,10 @@ pub fn create_from_bytes_field_quotes_enum(
             #peek_fns_quote
             #peek_fns_quote_temp
         };
+        peek_view_fns_quote = quote! {
+            #peek_view_fns_quote
+            #peek_view_fns_quote_temp
+        };
         // make setter for each field.
         // construct from bytes function. use input_byte_buffer as input name because,
         // that is what the field quotes expect to extract from.
@@ -323,21 +443,21 @@ pub fn create_from_bytes_field_quotes_enum(
         let variant_id = if i == last_variant {
             quote! {_}
         } else {
-            if let Some(id) = variant.attrs.id {
-                if let Ok(yes) = TokenStream::from_str(&format!("{id}")) {
-                    yes
-                } else {
-                    return Err(syn::Error::new(
-                        variant.name.span(),
-                        "failed to construct id, this is a bug in bondrewd.",
-                    ));
-                }
-            } else {
-                return Err(syn::Error::new(
-                    variant.name.span(),
-                    "failed to find id for variant, this is a bug in bondrewd.",
-                ));
-            }
+            variant.id_pattern()?
+        };
+        // the filler at the end of smaller variants does not need to be streamed.
+        let v_used_bits = variant
+            .fields
+            .iter()
+            .filter(|f| !f.attrs.reserve.is_fake_field())
+            .map(|f| f.attrs.bit_range.end)
+            .max()
+            .unwrap_or(0)
+            .max(info.id_range().end);
+        let v_used_bytes = (v_used_bits as f64 / 8.0f64).ceil() as usize;
+        stream_size_fn = quote! {
+            #stream_size_fn
+            #variant_id => #v_used_bytes,
         };
         let variant_constructor = if field_name_list.is_empty() {
             quote! {Self::#variant_name}
@@ -348,6 +468,39 @@ pub fn create_from_bytes_field_quotes_enum(
                 quote! {Self::#variant_name { #field_name_list }}
             }
         };
+        let try_checks = create_try_checks_quote(variant, Some(&info.name))?;
+        if i == last_variant && !variant.attrs.invalid {
+            // without a catch invalid variant, unknown ids are an error when decoding strictly.
+            let id = variant.id_pattern()?;
+            let v_id = format_ident!("{}", EnumInfo::VARIANT_ID_NAME);
+            let id_field = info.generate_id_field()?;
+            let id_range = id_field.attrs.bit_range;
+            let (id_start, id_end) = (id_range.start, id_range.end);
+            try_from_bytes_fn = quote! {
+                #try_from_bytes_fn
+                #id => {
+                    #try_checks
+                    #try_from_bytes_quote
+                    Ok(#variant_constructor)
+                }
+                _ => {
+                    return Err(bondrewd::BitfieldTryError {
+                        field: stringify!(#v_id),
+                        bit_range: #id_start..#id_end,
+                        kind: bondrewd::BitfieldTryErrorKind::InvalidVariant(#v_id as u128),
+                    });
+                }
+            };
+        } else {
+            try_from_bytes_fn = quote! {
+                #try_from_bytes_fn
+                #variant_id => {
+                    #try_checks
+                    #try_from_bytes_quote
+                    Ok(#variant_constructor)
+                }
+            };
+        }
         from_bytes_fn = quote! {
             #from_bytes_fn
             #variant_id => {
@@ -368,6 +521,31 @@ pub fn create_from_bytes_field_quotes_enum(
     let v_id = format_ident!("{}", EnumInfo::VARIANT_ID_NAME);
     let v_id_call = format_ident!("read_{v_id}");
     let v_id_slice_call = format_ident!("read_slice_{v_id}");
+    let update_from_fn = quote! {};
+    // reversed enums have the id in the last byte so the entire enum must be read to find it.
+    let stream_fns = if info.attrs.attrs.flip {
+        quote! {}
+    } else {
+        // the id is not always in the first bits, every byte up to the end of it is needed.
+        let id_byte_size = (info.id_range().end as f64 / 8.0f64).ceil() as usize;
+        quote! {
+            const STREAM_HEADER_BYTE_SIZE: usize = #id_byte_size;
+            fn stream_byte_size(input_byte_buffer: &[u8;#struct_size]) -> usize {
+                match Self::#v_id_call(input_byte_buffer) {
+                    #stream_size_fn
+                }
+            }
+        }
+    };
+    let try_from_bytes_fn = quote! {
+        fn try_from_bytes(input_byte_buffer: [u8;#struct_size]) -> Result<Self, bondrewd::BitfieldTryError> {
+            let #v_id = Self::#v_id_call(&input_byte_buffer);
+            match #v_id {
+                #try_from_bytes_fn
+            }
+        }
+    };
+    let checked_from_bytes_fn = get_checked_from_bytes_fn(struct_size);
     let from_bytes_fn = quote! {
         fn from_bytes(mut input_byte_buffer: [u8;#struct_size]) -> Self {
             let #v_id = Self::#v_id_call(&input_byte_buffer);
@@ -375,6 +553,7 @@ pub fn create_from_bytes_field_quotes_enum(
                 #from_bytes_fn
             }
         }
+        #checked_from_bytes_fn
     };
     if let Some((peek_slice_field_fns, peek_slice_field_unchecked_fns)) = peek_slice_fns_option {
         let comment_take = format!("Creates a new instance of `Self` by copying field from the bitfields, removing bytes that where used. \n # Errors\n If the provided `Vec<u8>` does not have enough bytes an error will be returned.");
@@ -406,7 +585,11 @@ pub fn create_from_bytes_field_quotes_enum(
         };
         Ok(FromBytesOptions {
             from_bytes_fn,
+            try_from_bytes_fn,
+            update_from_fn,
+            stream_fns,
             peek_field_fns: peek_fns_quote,
+            peek_view_field_fns: peek_view_fns_quote,
             from_slice_field_fns: Some(from_slice_field_fns),
             peek_slice_field_fns: Some(peek_slice_field_fns),
             peek_slice_field_unchecked_fns: Some(peek_slice_field_unchecked_fns),
@@ -414,7 +597,11 @@ pub fn create_from_bytes_field_quotes_enum(
     } else {
         Ok(FromBytesOptions {
             from_bytes_fn,
+            try_from_bytes_fn,
+            update_from_fn,
+            stream_fns,
             peek_field_fns: peek_fns_quote,
+            peek_view_field_fns: peek_view_fns_quote,
             from_slice_field_fns: None,
             peek_slice_field_fns: None,
             peek_slice_field_unchecked_fns: None,
@@ -422,16 +609,109 @@ pub fn create_from_bytes_field_quotes_enum(
     }
 }
 
+/// Generates `{name}Checked::variant`, which reads the id once and returns a `{name}CheckedVariant`
+/// holding a `{name}{variant}Checked` for the variant the slice holds. Each variant view only has
+/// `read_` functions for its own fields, so fields of other variants can not be read by mistake.
+pub fn create_checked_variant_quotes(info: &EnumInfo) -> syn::Result<TokenStream> {
+    let enum_name = &info.name;
+    let vis = &info.vis;
+    let checked_ident = format_ident!("{enum_name}Checked");
+    let checked_variant_ident = format_ident!("{enum_name}CheckedVariant");
+    let id_field = info.generate_id_field()?;
+    let id_extractor = get_field_quote(&id_field, None)?;
+    let id_type = id_field.ty.type_quote();
+    let v_id_call = format_ident!("read_{}", EnumInfo::VARIANT_ID_NAME);
+    let last_variant = info.variants.len() - 1;
+    let mut views_quote = quote! {};
+    let mut view_variants = quote! {};
+    let mut variant_arms = quote! {};
+    for (i, variant) in info.variants.iter().enumerate() {
+        let variant_name = &variant.name;
+        let view_ident = format_ident!("{enum_name}{variant_name}Checked");
+        // the id is read by `variant`, only a captured id gets a function.
+        let fields = if variant.fields[0].attrs.capture_id {
+            &variant.fields[..]
+        } else {
+            &variant.fields[1..]
+        };
+        let flip = if variant.attrs.flip {
+            Some(variant.total_bytes() - 1)
+        } else {
+            None
+        };
+        let mut read_fns = quote! {};
+        for field in fields.iter().filter(|f| !f.attrs.reserve.is_fake_field()) {
+            let field_extractor = get_field_quote(field, flip)?;
+            let read_fn = make_peek_slice_unchecked_fn(&field_extractor, field, variant, &None)?;
+            read_fns = quote! {
+                #read_fns
+                #read_fn
+            };
+        }
+        let comment = format!("Provides functions for reading the fields of a [{enum_name}::{variant_name}] in its bitfield form, returned by [{checked_ident}::variant].");
+        views_quote = quote! {
+            #views_quote
+            #[doc = #comment]
+            #vis struct #view_ident<'a> {
+                buffer: &'a [u8],
+            }
+            impl<'a> #view_ident<'a> {
+                #read_fns
+            }
+        };
+        view_variants = quote! {
+            #view_variants
+            #variant_name(#view_ident<'a>),
+        };
+        let pattern = if i == last_variant {
+            quote! {_}
+        } else {
+            variant.id_pattern()?
+        };
+        variant_arms = quote! {
+            #variant_arms
+            #pattern => #checked_variant_ident::#variant_name(#view_ident { buffer: self.buffer }),
+        };
+    }
+    let enum_comment = format!("The variant a [{checked_ident}] holds, returned by [{checked_ident}::variant].");
+    let id_comment = format!("Reads the variant id in pre-checked slice of a [{enum_name}] in bitfield form.");
+    let variant_comment = format!("Reads the variant id once and returns a view which can only read the fields of the variant of [{enum_name}] the slice holds.");
+    Ok(quote! {
+        #views_quote
+        #[doc = #enum_comment]
+        #vis enum #checked_variant_ident<'a> {
+            #view_variants
+        }
+        impl<'a> #checked_ident<'a> {
+            #[inline]
+            #[doc = #id_comment]
+            pub fn #v_id_call(&self) -> #id_type {
+                let input_byte_buffer: &[u8] = self.buffer;
+                #id_extractor
+            }
+            #[doc = #variant_comment]
+            pub fn variant(&self) -> #checked_variant_ident<'a> {
+                match self.#v_id_call() {
+                    #variant_arms
+                }
+            }
+        }
+    })
+}
+
 pub fn create_from_bytes_field_quotes(
     info: &StructInfo,
     peek_slice: bool,
 ) -> Result<FromBytesOptions, syn::Error> {
-    let (peek_fns_quote, from_bytes_struct_quote, from_bytes_quote, peek_slice_fns_option, from_vec_fn) = {
+    let (peek_fns_quote, peek_view_fns_quote, from_bytes_struct_quote, from_bytes_quote, try_from_bytes_quote, update_from_quote, peek_slice_fns_option, from_vec_fn) = {
         let thing = create_fields_quotes(info, None, peek_slice)?;
         (
             thing.peek_fns_quote,
+            thing.peek_view_fns_quote,
             thing.field_name_list,
             thing.from_bytes_quote,
+            thing.try_from_bytes_quote,
+            thing.update_from_quote,
             thing.peek_slice_fns_option,
             thing.from_vec_quote,
         )
@@ -443,6 +723,7 @@ pub fn create_from_bytes_field_quotes(
     // wrap our list of field names with commas with Self{} so we it instantiate our struct,
     // because all of the from_bytes field quote store there data in a temporary variable with the same
     // name as its destination field the list of field names will be just fine.
+    let checked_from_bytes_fn = get_checked_from_bytes_fn(*struct_size);
     let from_bytes_fn = quote! {
         fn from_bytes(mut input_byte_buffer: [u8;#struct_size]) -> Self {
             #from_bytes_quote
@@ -450,6 +731,31 @@ pub fn create_from_bytes_field_quotes(
                 #from_bytes_struct_quote
             }
         }
+        #checked_from_bytes_fn
+    };
+    let update_from_fn = quote! {
+        fn update_from(&mut self, input_byte_buffer: &[u8;#struct_size]) {
+            #update_from_quote
+        }
+    };
+    let stream_fns = quote! {};
+    let try_checks = create_try_checks_quote(info, None)?;
+    let length_checks = super::length::create_check_quotes(info, 0, &quote! {0usize});
+    let constant_checks = if info.constants.is_empty() {
+        quote! {}
+    } else {
+        quote! {Self::check_constants(&input_byte_buffer)?;}
+    };
+    let try_from_bytes_fn = quote! {
+        fn try_from_bytes(input_byte_buffer: [u8;#struct_size]) -> Result<Self, bondrewd::BitfieldTryError> {
+            #try_checks
+            #length_checks
+            #constant_checks
+            #try_from_bytes_quote
+            Ok(Self{
+                #from_bytes_struct_quote
+            })
+        }
     };
     if let Some((peek_slice_field_fns, peek_slice_field_unchecked_fns)) = peek_slice_fns_option {
         let comment_take = format!("Creates a new instance of `Self` by copying field from the bitfields, removing bytes that where used. \n # Errors\n If the provided `Vec<u8>` does not have enough bytes an error will be returned.");
@@ -485,7 +791,11 @@ pub fn create_from_bytes_field_quotes(
         };
         Ok(FromBytesOptions {
             from_bytes_fn,
+            try_from_bytes_fn,
+            update_from_fn,
+            stream_fns,
             peek_field_fns: peek_fns_quote,
+            peek_view_field_fns: peek_view_fns_quote,
             from_slice_field_fns: Some(from_slice_field_fns),
             peek_slice_field_fns: Some(peek_slice_field_fns),
             peek_slice_field_unchecked_fns: Some(peek_slice_field_unchecked_fns),
@@ -493,7 +803,11 @@ pub fn create_from_bytes_field_quotes(
     } else {
         Ok(FromBytesOptions {
             from_bytes_fn,
+            try_from_bytes_fn,
+            update_from_fn,
+            stream_fns,
             peek_field_fns: peek_fns_quote,
+            peek_view_field_fns: peek_view_fns_quote,
             from_slice_field_fns: None,
             peek_slice_field_fns: None,
             peek_slice_field_unchecked_fns: None,
@@ -566,6 +880,34 @@ fn make_peek_slice_unchecked_fn(
     })
 }
 
+fn make_peek_view_fn(
+    field_quote: &TokenStream,
+    field: &FieldInfo,
+    info: &StructInfo,
+    prefix: &Option<Ident>,
+) -> syn::Result<TokenStream> {
+    let field_name = if let Some(p) = prefix {
+        format_ident!("{p}_{}", field.ident().ident())
+    } else {
+        field.ident().ident()
+    };
+    let fn_field_name = format_ident!("read_{field_name}");
+    let bit_range = &field.attrs.bit_range;
+    let type_ident = field.ty.type_quote();
+    let struct_name = &info.name;
+    let comment = format!(
+        "Reads bits {} through {} within the viewed buffer, getting the `{field_name}` field of a [{struct_name}] in bitfield form.", bit_range.start, bit_range.end - 1
+    );
+    Ok(quote! {
+        #[inline]
+        #[doc = #comment]
+        pub fn #fn_field_name(&self) -> #type_ident {
+            let input_byte_buffer: &[u8] = self.buffer.as_ref();
+            #field_quote
+        }
+    })
+}
+
 fn make_peek_fn(
     field_quote: &TokenStream,
     field: &FieldInfo,
@@ -592,6 +934,207 @@ fn make_peek_fn(
     })
 }
 
+/// Generates a `read_{field}_bytes` function for a nested bitfields field, which returns the nested
+/// struct or enum in its own bitfield form so its `read_` functions can be used without decoding it.
+fn make_peek_bytes_fn(
+    field: &FieldInfo,
+    size: usize,
+    ty: &TokenStream,
+    info: &StructInfo,
+    prefix: &Option<Ident>,
+) -> syn::Result<TokenStream> {
+    let field_name = if let Some(p) = prefix {
+        format_ident!("{p}_{}", field.ident().ident())
+    } else {
+        field.ident().ident()
+    };
+    let fn_field_name = format_ident!("read_{field_name}_bytes");
+    let bit_range = &field.attrs.bit_range;
+    let struct_name = &info.name;
+    let struct_size = &info.total_bytes();
+    let value_retrieval = apply_ne_math_to_field_access_quote(
+        field,
+        if info.attrs.flip {
+            Some(info.total_bytes() - 1)
+        } else {
+            None
+        },
+    )?;
+    let comment = format!("Reads bits {} through {} within `input_byte_buffer`, getting the `{field_name}` field of a `{struct_name}` as a `{}` in bitfield form, which can be passed to its `read_` functions.", bit_range.start, bit_range.end - 1, ty);
+    Ok(quote! {
+        #[inline]
+        #[doc = #comment]
+        pub fn #fn_field_name(input_byte_buffer: &[u8;#struct_size]) -> [u8;#size] {
+            #value_retrieval
+        }
+    })
+}
+
+/// Generates a `read_{field}_{read}` function for each `read_through` function of a nested bitfields
+/// field, which reads the nested value's bytes then calls its `read_{read}`. The field name is left
+/// out for the tuple field of an enum variant, ex. `read_{variant}_{read}`.
+fn make_read_through_fns(
+    field: &FieldInfo,
+    ty: &TokenStream,
+    info: &StructInfo,
+    prefix: &Option<Ident>,
+) -> TokenStream {
+    let field_ident = field.ident().ident();
+    let field_name = if let Some(p) = prefix {
+        format_ident!("{p}_{field_ident}")
+    } else {
+        field_ident.clone()
+    };
+    let bytes_fn = format_ident!("read_{field_name}_bytes");
+    let fn_prefix = match (prefix, field.ident().as_ref()) {
+        (Some(p), FieldIdent::Index { .. }) => p.clone(),
+        _ => field_name.clone(),
+    };
+    let struct_name = &info.name;
+    let struct_size = &info.total_bytes();
+    let mut read_fns = quote! {};
+    for (read, read_ty) in field.attrs.read_through.iter() {
+        let fn_name = format_ident!("read_{fn_prefix}_{read}");
+        let read_fn = format_ident!("read_{read}");
+        let comment = format!("Reads `{read}` from the `{field_ident}` field of a `{struct_name}` in bitfield form, using `{ty}::{read_fn}`.");
+        read_fns = quote! {
+            #read_fns
+            #[inline]
+            #[doc = #comment]
+            pub fn #fn_name(input_byte_buffer: &[u8;#struct_size]) -> #read_ty {
+                <#ty>::#read_fn(&Self::#bytes_fn(input_byte_buffer))
+            }
+        };
+    }
+    read_fns
+}
+
+/// Generates the checks `try_from_bytes` does before decoding `input_byte_buffer`. Reserve fields
+/// must contain their `fill_pattern` (zero without one) and char fields must contain a valid code
+/// point. Nested bitfields are checked while they are decoded, see [get_checked_field_quote].
+fn create_try_checks_quote(info: &StructInfo, enum_name: Option<&Ident>) -> syn::Result<TokenStream> {
+    let flip = if info.attrs.flip {
+        Some(info.total_bytes() - 1)
+    } else {
+        None
+    };
+    let mut checks = quote! {};
+    for field in info.fields.iter() {
+        if field.attrs.reserve.is_fake_field() {
+            continue;
+        }
+        let name = if enum_name.is_some() {
+            format!("{}::{}", info.name, field.ident().ident())
+        } else {
+            field.ident().ident().to_string()
+        };
+        let (start, end) = (field.attrs.bit_range.start, field.attrs.bit_range.end);
+        if let ReserveFieldOption::ReserveField = field.attrs.reserve {
+            // check every byte the reserve field touches using masks, so any type can be reserved.
+            let pattern = super::fill::field_fill_pattern(info, field).unwrap_or(0);
+            let mut condition = quote! {false};
+            for (byte, mask) in super::fill::field_byte_masks(info, field) {
+                let expected = pattern & mask;
+                condition = quote! {#condition || input_byte_buffer[#byte] & #mask != #expected};
+            }
+            checks = quote! {
+                #checks
+                if #condition {
+                    return Err(bondrewd::BitfieldTryError {
+                        field: #name,
+                        bit_range: #start..#end,
+                        kind: bondrewd::BitfieldTryErrorKind::NonZeroReserve,
+                    });
+                }
+            };
+        } else if field.attrs.reserve.read_field() {
+            let char_fields = match field.ty {
+                FieldDataType::Char(_, _) => vec![field.clone()],
+                FieldDataType::ElementArray(ref sub, _, _) => {
+                    if let FieldDataType::Char(_, _) = sub.ty {
+                        field.get_element_iter()?.collect()
+                    } else {
+                        Vec::default()
+                    }
+                }
+                _ => Vec::default(),
+            };
+            for char_field in char_fields {
+                let value_retrieval = match char_field.attrs.endianness.as_ref() {
+                    Endianness::Big => apply_be_math_to_field_access_quote(&char_field, flip)?,
+                    Endianness::Little => apply_le_math_to_field_access_quote(&char_field, flip)?,
+                    Endianness::None => apply_ne_math_to_field_access_quote(&char_field, flip)?,
+                };
+                let (start, end) = (
+                    char_field.attrs.bit_range.start,
+                    char_field.attrs.bit_range.end,
+                );
+                checks = quote! {
+                    #checks
+                    {
+                        let code_point = {#value_retrieval};
+                        if char::from_u32(code_point).is_none() {
+                            return Err(bondrewd::BitfieldTryError {
+                                field: #name,
+                                bit_range: #start..#end,
+                                kind: bondrewd::BitfieldTryErrorKind::InvalidChar(code_point),
+                            });
+                        }
+                    }
+                };
+            }
+        }
+    }
+    Ok(checks)
+}
+
+/// Returns the extraction of a field holding nested bitfields which decodes every nested value with
+/// `Bitfields::checked_from_bytes`. Errors are returned as errors of the outer field `name` with the
+/// bit range moved to where the nested value is. `None` if the field does not hold nested bitfields,
+/// tagged payloads are stored without their id, so only their tag is checked.
+fn get_checked_field_quote(
+    field: &FieldInfo,
+    flip: Option<usize>,
+    name: &str,
+) -> syn::Result<Option<TokenStream>> {
+    let sub_fields: Vec<FieldInfo> = match field.ty {
+        FieldDataType::Struct(_, ref ty) => {
+            if field.attrs.tag_from.is_some() {
+                return Ok(None);
+            }
+            let value_retrieval = apply_ne_math_to_field_access_quote(field, flip)?;
+            let start = field.attrs.bit_range.start;
+            return Ok(Some(quote! {
+                match #ty::checked_from_bytes({#value_retrieval}) {
+                    Ok(value) => value,
+                    Err(err) => {
+                        return Err(bondrewd::BitfieldTryError {
+                            field: #name,
+                            bit_range: (#start + err.bit_range.start)..(#start + err.bit_range.end),
+                            kind: err.kind,
+                        });
+                    }
+                }
+            }));
+        }
+        FieldDataType::ElementArray(_, _, _) => field.get_element_iter()?.collect(),
+        FieldDataType::BlockArray(_, _, _) => field.get_block_iter()?.collect(),
+        _ => return Ok(None),
+    };
+    let mut buffer = quote! {};
+    for sub_field in sub_fields.iter() {
+        if let Some(sub_field_quote) = get_checked_field_quote(sub_field, flip, name)? {
+            buffer = quote! {
+                #buffer
+                {#sub_field_quote},
+            };
+        } else {
+            return Ok(None);
+        }
+    }
+    Ok(Some(quote! { [#buffer] }))
+}
+
 /// if is_inner is false the field will be put into a variable with the fields name, otherwise
 /// it will be returned.
 fn get_field_quote(
@@ -651,7 +1194,18 @@ fn get_field_quote(
             quote! {#ident::from_primitive(#value_retrieval)}
         }
         FieldDataType::Struct(_, ref ident) => {
-            quote! {#ident::from_bytes({#value_retrieval})}
+            if let Some(ref tag) = field.attrs.tag_from {
+                // the enum is stored without its id, which is read from the tag field.
+                let tag_quote = get_field_quote(tag, flip)?;
+                let id = if let FieldDataType::Enum(..) = tag.ty {
+                    quote! {(#tag_quote).into_primitive() as _}
+                } else {
+                    quote! {(#tag_quote) as _}
+                };
+                quote! {#ident::from_payload_bytes(#id, {#value_retrieval})}
+            } else {
+                quote! {#ident::from_bytes({#value_retrieval})}
+            }
         }
         _ => {
             quote! {#value_retrieval}
@@ -1627,7 +2181,7 @@ fn add_sign_fix_quote_single_bit(
             if let NumberSignage::Signed = sign {
                 let bit_to_isolate = field.attrs.bit_range.start % 8;
                 let sign_mask = isolate_bit_index_mask(&bit_to_isolate);
-                let neg_mask = get_left_and_mask(bit_to_isolate + 1);
+                let neg_mask = get_left_and_mask(8 - amount_of_bits);
                 let sign_bit = quote! {
                     (input_byte_buffer[#byte_index] & #sign_mask)
                 };
@@ -13,7 +13,10 @@ use super::common::EnumInfo;
 
 pub struct IntoBytesOptions {
     pub into_bytes_fn: TokenStream,
+    pub write_to_fn: TokenStream,
     pub set_field_fns: TokenStream,
+    /// `write_` functions for the generated mutable view type, which write to `self.buffer`.
+    pub set_view_field_fns: TokenStream,
     pub set_slice_field_fns: Option<TokenStream>,
     pub set_slice_field_unchecked_fns: Option<TokenStream>,
 }
@@ -40,7 +43,10 @@ fn make_checked_mut_func(name: &Ident, struct_size: usize) -> TokenStream {
 struct FieldQuotes {
     field_name_list: TokenStream,
     into_bytes_quote: TokenStream,
+    /// same as `into_bytes_quote` but only borrows the fields.
+    write_to_quote: TokenStream,
     set_fns_quote: TokenStream,
+    set_view_fns_quote: TokenStream,
     set_slice_fns_option: Option<(TokenStream, TokenStream)>,
 }
 
@@ -59,8 +65,14 @@ fn create_fields_quotes(
     };
     let mut field_name_list = quote! {};
     let mut set_fns_quote = quote! {};
+    // same as set_fns_quote but for the mutable view type.
+    let mut set_view_fns_quote = quote! {};
     // all of the fields setting will be appended to this
     let mut into_bytes_quote = quote! {};
+    // values into_bytes_quote needs before the fields are moved out of the structure.
+    let mut into_bytes_prelude = quote! {};
+    // same as into_bytes_quote but only borrows the fields.
+    let mut write_to_quote = quote! {};
     // TODO make sure this gets fixed for enums.
     let mut set_slice_fns_option = if set_slice {
         Some((quote! {}, quote! {}))
@@ -87,12 +99,42 @@ fn create_fields_quotes(
             false,
         )?;
         if field.attrs.reserve.write_field() {
+            // enum variants are matched by reference so the field is already a reference.
+            let access = if lower_name.is_some() {
+                quote! {#field_name}
+            } else {
+                quote! {(&self.#field_name)}
+            };
+            // constant fields ignore the value in the structure, as do the fields holding the
+            // variant id of a `tag_from` enum.
+            let constant = super::constant::constant_value_quote(info, &field_name)
+                .or_else(|| super::tag::tag_value_quote(info, field));
+            let binding = if let Some(ref value) = constant {
+                value.clone()
+            } else {
+                get_borrowed_field_binding(field, access)
+            };
+            write_to_quote = quote! {
+                #write_to_quote
+                let #field_name = #binding;
+                #field_setter
+            };
             if let Some(ref name) = lower_name {
                 let fn_name = format_ident!("write_{name}_{field_name}");
                 into_bytes_quote = quote! {
                     #into_bytes_quote
                     Self::#fn_name(&mut output_byte_buffer, #field_name);
                 };
+            } else if let Some(value) = constant {
+                // taken before any field is moved, tag values are read from the enum they tag.
+                into_bytes_prelude = quote! {
+                    #into_bytes_prelude
+                    let #field_name = #value;
+                };
+                into_bytes_quote = quote! {
+                    #into_bytes_quote
+                    #field_setter
+                };
             } else {
                 into_bytes_quote = quote! {
                     #into_bytes_quote
@@ -106,6 +148,12 @@ fn create_fields_quotes(
             #set_fns_quote
             #set_quote
         };
+        let set_view_quote =
+            make_set_view_fn(&field_setter, field, info, &clear_quote, &lower_name)?;
+        set_view_fns_quote = quote! {
+            #set_view_fns_quote
+            #set_view_quote
+        };
 
         if let Some((ref mut set_slice_fns_quote, ref mut unchecked)) = set_slice_fns_option {
             let set_slice_quote =
@@ -124,23 +172,64 @@ fn create_fields_quotes(
             std::mem::swap(unchecked, &mut unchecked_temp);
         }
     }
+    // reserve fields and fill bits are written last using the fill pattern.
+    let fill_quote = super::fill::create_fill_pattern_quote(info, &quote! {output_byte_buffer});
+    let into_bytes_quote = quote! {
+        #into_bytes_prelude
+        #into_bytes_quote
+        #fill_quote
+    };
+    let write_to_quote = quote! {
+        #write_to_quote
+        #fill_quote
+    };
     Ok(FieldQuotes {
         field_name_list: field_name_list,
         into_bytes_quote,
+        write_to_quote,
         set_fns_quote,
+        set_view_fns_quote,
         set_slice_fns_option,
     })
 }
 
+/// Returns the bytes of the nested structure `access`, which can be the structure or a reference to
+/// it. Nested structures are written by reference with `to_bytes`, which uses their own `write_to`,
+/// enums in `tag_from` fields are stored without their id.
+fn struct_bytes_quote(field: &FieldInfo, access: &TokenStream) -> TokenStream {
+    if field.attrs.tag_from.is_some() {
+        quote! {#access.to_payload_bytes()}
+    } else {
+        quote! {#access.to_bytes()}
+    }
+}
+
+/// Returns a quote that gets a value the field setters can use from a reference to the field
+/// (`access`) without moving it. Nested structures are written through the reference, every other
+/// type is copied, `enum_primitive` types must be `Copy` because `into_primitive` consumes the value.
+fn get_borrowed_field_binding(field: &FieldInfo, access: TokenStream) -> TokenStream {
+    let ty = match field.ty {
+        FieldDataType::ElementArray(ref sub, _, _) | FieldDataType::BlockArray(ref sub, _, _) => {
+            &sub.ty
+        }
+        ref ty => ty,
+    };
+    match ty {
+        FieldDataType::Struct(_, _) => access,
+        _ => quote! {*#access},
+    }
+}
+
 pub fn create_into_bytes_field_quotes_enum(
     info: &EnumInfo,
     set_slice: bool,
 ) -> Result<IntoBytesOptions, syn::Error> {
     let mut id_fn: TokenStream = quote! {};
     let mut into_bytes_fn: TokenStream = quote! {};
+    let mut write_to_fn: TokenStream = quote! {};
     // all quote with all of the set functions appended to it.
 
-    let (mut set_fns_quote, mut set_slice_fns_option, id_ident) = {
+    let ((mut set_fns_quote, mut set_view_fns_quote), mut set_slice_fns_option, id_ident) = {
         (
             {
                 let field = info.generate_id_field()?;
@@ -159,24 +248,39 @@ pub fn create_into_bytes_field_quotes_enum(
                 let attrs = info.attrs.attrs.clone();
                 let mut fields = vec![field.clone()];
                 fields[0].attrs.bit_range = 0..info.total_bits();
+                let temp_struct_info = StructInfo {
+                    name: info.name.clone(),
+                    attrs,
+                    fields,
+                    vis: syn::Visibility::Public(VisPublic {
+                        pub_token: Pub::default(),
+                    }),
+                    tuple: false,
+                    variable_array: None,
+                    checksums: Vec::new(),
+                    lengths: Vec::new(),
+                    constants: Vec::new(),
+                };
                 let id_field = make_set_fn(
                     &field_quote,
                     &field,
-                    &StructInfo {
-                        name: info.name.clone(),
-                        attrs,
-                        fields,
-                        vis: syn::Visibility::Public(VisPublic {
-                            pub_token: Pub::default(),
-                        }),
-                        tuple: false,
-                    },
+                    &temp_struct_info,
                     &clear_quote,
                     &None,
                 )?;
-                quote! {
-                    #id_field
-                }
+                let id_view_field = make_set_view_fn(
+                    &field_quote,
+                    &field,
+                    &temp_struct_info,
+                    &clear_quote,
+                    &None,
+                )?;
+                (
+                    quote! {
+                        #id_field
+                    },
+                    id_view_field,
+                )
             },
             if set_slice {
                 Some((
@@ -189,19 +293,78 @@ pub fn create_into_bytes_field_quotes_enum(
             info.id_ident()?,
         )
     };
+    // common fields use the same bits in every variant, so they can be written without knowing the
+    // variant.
+    let common_info = info.common_struct_info();
+    let flip = if common_info.attrs.flip {
+        Some(common_info.total_bytes() - 1)
+    } else {
+        None
+    };
+    for field in info.common_fields.iter() {
+        let (field_setter, clear_quote) = get_field_quote(field, flip, false)?;
+        let set_quote = make_set_fn(&field_setter, field, &common_info, &clear_quote, &None)?;
+        let set_view_quote =
+            make_set_view_fn(&field_setter, field, &common_info, &clear_quote, &None)?;
+        let field_name = field.ident().ident();
+        let type_ident = field.ty.type_quote();
+        let variants = info.variants.iter().map(|variant| {
+            let variant_name = &variant.name;
+            quote! {Self::#variant_name { #field_name, .. }}
+        });
+        let comment = format!(
+            "Returns the `{field_name}` field every variant of a [{}] has.",
+            info.name
+        );
+        set_fns_quote = quote! {
+            #set_fns_quote
+            #set_quote
+            #[doc = #comment]
+            pub fn #field_name(&self) -> #type_ident {
+                match self {
+                    #(#variants)|* => #field_name.clone(),
+                }
+            }
+        };
+        set_view_fns_quote = quote! {
+            #set_view_fns_quote
+            #set_view_quote
+        };
+        if let Some((ref mut set_slice_fns_quote, ref mut unchecked)) = set_slice_fns_option {
+            let set_slice_quote =
+                make_set_slice_fn(&field_setter, field, &common_info, &clear_quote, &None)?;
+            let set_slice_unchecked_quote = make_set_slice_unchecked_fn(
+                &field_setter,
+                field,
+                &common_info,
+                &clear_quote,
+                &None,
+            )?;
+            *set_slice_fns_quote = quote! {
+                #set_slice_fns_quote
+                #set_slice_quote
+            };
+            *unchecked = quote! {
+                #unchecked
+                #set_slice_unchecked_quote
+            };
+        }
+    }
     let total_size = info.total_bytes();
-    for variant in info.variants.iter() {
+    for (i, variant) in info.variants.iter().enumerate() {
         // this is the slice indexing that will fool the set function code into thinking
         // it is looking at a smaller array.
         let v_name = &variant.name;
         let variant_name = quote! {#v_name};
         let mut variant_id = variant.id_or_field_name()?;
-        let (field_name_list, into_bytes_quote, set_fns_quote_temp, set_slice_fns_option_temp) = {
+        let (field_name_list, into_bytes_quote, write_to_quote, set_fns_quote_temp, set_view_fns_quote_temp, set_slice_fns_option_temp) = {
             let thing = create_fields_quotes(variant, Some(info.name.clone()), set_slice)?;
             (
                 thing.field_name_list,
                 thing.into_bytes_quote,
+                thing.write_to_quote,
                 thing.set_fns_quote,
+                thing.set_view_fns_quote,
                 thing.set_slice_fns_option,
             )
         };
@@ -225,6 +388,10 @@ pub fn create_into_bytes_field_quotes_enum(
             #set_fns_quote
             #set_fns_quote_temp
         };
+        set_view_fns_quote = quote! {
+            #set_view_fns_quote
+            #set_view_fns_quote_temp
+        };
         // make setter for each field.
         // construct from bytes function. use input_byte_buffer as input name because,
         // that is what the field quotes expect to extract from.
@@ -238,13 +405,45 @@ pub fn create_into_bytes_field_quotes_enum(
         }else{
             quote!{{#field_name_list}}
         };
+        // the infallible functions write a captured id as is, see `create_try_into_bytes_quote`.
+        let (id_check, borrowed_id_check) = if variant.fields[0].attrs.capture_id {
+            let belongs = captured_id_belongs_quote(info, i)?;
+            let message = format!(
+                "captured id of `{}` does not belong to the variant, use `try_into_bytes`",
+                variant.name
+            );
+            (
+                quote! {
+                    debug_assert!({ let id: #id_ident = #variant_id; #belongs }, #message);
+                },
+                quote! {
+                    debug_assert!({ let id: #id_ident = *#variant_id; #belongs }, #message);
+                },
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
         into_bytes_fn = quote! {
             #into_bytes_fn
             Self::#variant_name #fields => {
+                #id_check
                 Self::#v_id_call(&mut output_byte_buffer, #variant_id);
                 #into_bytes_quote
             }
         };
+        let borrowed_variant_id = if variant.fields[0].attrs.capture_id {
+            quote! {*#variant_id}
+        } else {
+            quote! {#variant_id}
+        };
+        write_to_fn = quote! {
+            #write_to_fn
+            Self::#variant_name #fields => {
+                #borrowed_id_check
+                Self::#v_id_call(output_byte_buffer, #borrowed_variant_id);
+                #write_to_quote
+            }
+        };
 
         if !variant.fields.is_empty() && variant.fields[0].attrs.capture_id {
             let id_field_name = &variant.fields[0].ident().name();
@@ -282,6 +481,14 @@ pub fn create_into_bytes_field_quotes_enum(
             output_byte_buffer
         }
     };
+    let write_to_fn = quote! {
+        fn write_to(&self, output_byte_buffer: &mut [u8;#total_size]) {
+            *output_byte_buffer = [0u8;#total_size];
+            match self {
+                #write_to_fn
+            }
+        }
+    };
     id_fn = quote! {
         pub fn id(&self) -> #id_ident {
             match self {
@@ -296,29 +503,143 @@ pub fn create_into_bytes_field_quotes_enum(
     if let Some((set_slice_field_fns, set_slice_field_unchecked_fns)) = set_slice_fns_option {
         Ok(IntoBytesOptions {
             into_bytes_fn,
+            write_to_fn,
             set_field_fns: set_fns_quote,
+            set_view_field_fns: set_view_fns_quote,
             set_slice_field_fns: Some(set_slice_field_fns),
             set_slice_field_unchecked_fns: Some(set_slice_field_unchecked_fns),
         })
     } else {
         Ok(IntoBytesOptions {
             into_bytes_fn,
+            write_to_fn,
             set_field_fns: set_fns_quote,
+            set_view_field_fns: set_view_fns_quote,
             set_slice_field_fns: None,
             set_slice_field_unchecked_fns: None,
         })
     }
 }
 
+/// Returns an expression which is true when the id bound to `id` belongs to the variant at `index`.
+/// The last variant catches every id the other variants do not use, same as `from_bytes`.
+fn captured_id_belongs_quote(info: &EnumInfo, index: usize) -> syn::Result<TokenStream> {
+    let last_variant = info.variants.len() - 1;
+    if index != last_variant {
+        let pattern = info.variants[index].id_pattern()?;
+        return Ok(quote! {matches!(id, #pattern)});
+    }
+    let mut others = Vec::new();
+    for other in info.variants.iter().take(last_variant) {
+        others.push(other.id_pattern()?);
+    }
+    let used = if others.is_empty() {
+        quote! {false}
+    } else {
+        quote! {matches!(id, #(#others)|*)}
+    };
+    let id_bits = info.attrs.id_bits;
+    Ok(if id_bits < id_ident_bits(id_bits) {
+        if info.attrs.attrs.signed_id_bits.is_some() {
+            let max = proc_macro2::Literal::i128_unsuffixed((1 << (id_bits - 1)) - 1);
+            let min = proc_macro2::Literal::i128_unsuffixed(-(1 << (id_bits - 1)));
+            quote! {!#used && id >= #min && id <= #max}
+        } else {
+            let max = proc_macro2::Literal::u128_unsuffixed((1 << id_bits) - 1);
+            quote! {!#used && id <= #max}
+        }
+    } else {
+        quote! {!#used}
+    })
+}
+
+/// Generates the `TryBitfields::try_into_bytes` of enums with a variant that captures its id, which
+/// checks every captured id belongs to its variant before writing it. The last variant catches every
+/// id the other variants do not use, same as `from_bytes`, so its captured id must not belong to
+/// another variant. Other enums use the default, which can not fail.
+pub fn create_try_into_bytes_quote(info: &EnumInfo) -> syn::Result<TokenStream> {
+    if !info
+        .variants
+        .iter()
+        .any(|variant| variant.fields[0].attrs.capture_id)
+    {
+        return Ok(quote! {});
+    }
+    let total_size = info.total_bytes();
+    let id_ident = info.id_ident()?;
+    let id_range = info.id_range();
+    let (id_start, id_end) = (id_range.start, id_range.end);
+    let v_id = EnumInfo::VARIANT_ID_NAME;
+    let mut check_arms = quote! {};
+    for (i, variant) in info.variants.iter().enumerate() {
+        if !variant.fields[0].attrs.capture_id {
+            continue;
+        }
+        let v_name = &variant.name;
+        let id_field_name = variant.fields[0].ident().name();
+        let binding = if variant.fields.len() > 1 {
+            quote! {#id_field_name, ..}
+        } else {
+            quote! {#id_field_name}
+        };
+        let binding = if variant.tuple {
+            quote! {(#binding)}
+        } else {
+            quote! {{#binding}}
+        };
+        let belongs = captured_id_belongs_quote(info, i)?;
+        check_arms = quote! {
+            #check_arms
+            Self::#v_name #binding => {
+                let id: #id_ident = *#id_field_name;
+                if !(#belongs) {
+                    return Err(bondrewd::BitfieldTryError {
+                        field: #v_id,
+                        bit_range: #id_start..#id_end,
+                        kind: bondrewd::BitfieldTryErrorKind::CapturedIdMismatch(id as u128),
+                    });
+                }
+            }
+        };
+    }
+    if info
+        .variants
+        .iter()
+        .any(|variant| !variant.fields[0].attrs.capture_id)
+    {
+        check_arms = quote! {
+            #check_arms
+            _ => {}
+        };
+    }
+    Ok(quote! {
+        /// Same as `into_bytes` but returns an error when a `capture_id` field holds an id which does
+        /// not belong to its variant, instead of writing an id `from_bytes` would read as another variant.
+        fn try_into_bytes(self) -> Result<[u8;#total_size], bondrewd::BitfieldTryError> {
+            match &self {
+                #check_arms
+            }
+            Ok(bondrewd::Bitfields::into_bytes(self))
+        }
+    })
+}
+
+/// The size in bits of the primitive used to hold an id of `id_bits` bits.
+fn id_ident_bits(id_bits: usize) -> usize {
+    id_bits.next_power_of_two().max(8)
+}
+
 pub fn create_into_bytes_field_quotes_struct(
     info: &StructInfo,
     set_slice: bool,
 ) -> Result<IntoBytesOptions, syn::Error> {
-    let (into_bytes_quote, set_fns_quote, set_slice_fns_option) = {
+    let (into_bytes_quote, write_to_quote, set_fns_quote, set_view_fns_quote, set_slice_fns_option) = {
         let thing = create_fields_quotes(info, None, set_slice)?;
         (
             thing.into_bytes_quote,
+            thing.write_to_quote,
             thing.set_fns_quote,
+            thing.set_view_fns_quote,
             thing.set_slice_fns_option,
         )
     };
@@ -328,13 +649,32 @@ pub fn create_into_bytes_field_quotes_struct(
     // wrap our list of field names with commas with Self{} so we it instantiate our struct,
     // because all of the from_bytes field quote store there data in a temporary variable with the same
     // name as its destination field the list of field names will be just fine.
+    // lengths are filled in first so checksums can cover them.
+    let into_bytes_length_quote =
+        super::length::create_fill_quotes(info, &quote! {&mut output_byte_buffer});
+    let write_to_length_quote =
+        super::length::create_fill_quotes(info, &quote! {output_byte_buffer});
+    let into_bytes_checksum_quote =
+        super::checksum::create_fill_quotes(info, &quote! {&mut output_byte_buffer});
+    let write_to_checksum_quote =
+        super::checksum::create_fill_quotes(info, &quote! {output_byte_buffer});
     let into_bytes_fn = quote! {
         fn into_bytes(self) -> [u8;#struct_size] {
             let mut output_byte_buffer: [u8;#struct_size] = [0u8;#struct_size];
             #into_bytes_quote
+            #into_bytes_length_quote
+            #into_bytes_checksum_quote
             output_byte_buffer
         }
     };
+    let write_to_fn = quote! {
+        fn write_to(&self, output_byte_buffer: &mut [u8;#struct_size]) {
+            *output_byte_buffer = [0u8;#struct_size];
+            #write_to_quote
+            #write_to_length_quote
+            #write_to_checksum_quote
+        }
+    };
     if let Some((set_slice_field_fns, set_slice_field_unchecked_fns)) = set_slice_fns_option {
         let checked_struct_fn = make_checked_mut_func(&info.name, info.total_bytes());
         let set_slice_field_fns = quote! {
@@ -343,14 +683,18 @@ pub fn create_into_bytes_field_quotes_struct(
         };
         Ok(IntoBytesOptions {
             into_bytes_fn,
+            write_to_fn,
             set_field_fns: set_fns_quote,
+            set_view_field_fns: set_view_fns_quote,
             set_slice_field_fns: Some(set_slice_field_fns),
             set_slice_field_unchecked_fns: Some(set_slice_field_unchecked_fns),
         })
     } else {
         Ok(IntoBytesOptions {
             into_bytes_fn,
+            write_to_fn,
             set_field_fns: set_fns_quote,
+            set_view_field_fns: set_view_fns_quote,
             set_slice_field_fns: None,
             set_slice_field_unchecked_fns: None,
         })
@@ -371,22 +715,33 @@ fn make_set_slice_fn(
         format_ident!("write_slice_{field_name}")
     };
     let bit_range = &field.attrs.bit_range;
-    let type_ident = field.ty.type_quote();
+    let (value_param, value_binding) = write_value_param(field, info, quote! {#field_name});
+    let tag_quote = tag_write_quote(field, info)?;
     let struct_name = &info.name;
+    // the field the variant id of a `tag_from` field is written to must also fit.
+    let (start, end) = match field.attrs.tag_from {
+        Some(ref tag) => (
+            field.attrs.bit_range.start.min(tag.attrs.bit_range.start),
+            field.attrs.bit_range.end.max(tag.attrs.bit_range.end),
+        ),
+        None => (field.attrs.bit_range.start, field.attrs.bit_range.end),
+    };
     let min_length = if info.attrs.flip {
-        ((info.total_bits() - field.attrs.bit_range.start) as f64 / 8.0f64).ceil() as usize
+        ((info.total_bits() - start) as f64 / 8.0f64).ceil() as usize
     } else {
-        (field.attrs.bit_range.end as f64 / 8.0f64).ceil() as usize
+        (end as f64 / 8.0f64).ceil() as usize
     };
     let comment = format!("Writes to bits {} through {} in `input_byte_buffer` if enough bytes are present in slice, setting the `{field_name}` field of a `{struct_name}` in bitfield form. Otherwise a [BitfieldLengthError](bondrewd::BitfieldLengthError) will be returned", bit_range.start, bit_range.end - 1);
     Ok(quote! {
         #[inline]
         #[doc = #comment]
-        pub fn #fn_field_name(output_byte_buffer: &mut [u8], #field_name: #type_ident) -> Result<(), bondrewd::BitfieldLengthError> {
+        pub fn #fn_field_name(output_byte_buffer: &mut [u8] #value_param) -> Result<(), bondrewd::BitfieldLengthError> {
             let slice_length = output_byte_buffer.len();
             if slice_length < #min_length {
                 Err(bondrewd::BitfieldLengthError(slice_length, #min_length))
             } else {
+                #value_binding
+                #tag_quote
                 #clear_quote
                 #field_quote
                 Ok(())
@@ -410,7 +765,8 @@ fn make_set_slice_unchecked_fn(
         format_ident!("write_{field_name}")
     };
     let bit_range = &field.attrs.bit_range;
-    let type_ident = field.ty.type_quote();
+    let (value_param, value_binding) = write_value_param(field, info, quote! {#field_name});
+    let tag_quote = tag_write_quote(field, info)?;
     let struct_name = &info.name;
     let comment = format!(
         "Writes to bits {} through {} in pre-checked mutable slice, setting the `{field_name}` field of a [{struct_name}] in bitfield form.", bit_range.start, bit_range.end - 1
@@ -418,8 +774,43 @@ fn make_set_slice_unchecked_fn(
     Ok(quote! {
         #[inline]
         #[doc = #comment]
-        pub fn #fn_field_name(&mut self, #field_name: #type_ident) {
+        pub fn #fn_field_name(&mut self #value_param) {
             let output_byte_buffer: &mut [u8] = self.buffer;
+            #value_binding
+            #tag_quote
+            #clear_quote
+            #field_quote
+        }
+    })
+}
+
+fn make_set_view_fn(
+    field_quote: &TokenStream,
+    field: &FieldInfo,
+    info: &StructInfo,
+    clear_quote: &TokenStream,
+    prefix: &Option<Ident>,
+) -> syn::Result<TokenStream> {
+    let field_name = field.ident().ident();
+    let fn_field_name = if let Some(p) = prefix {
+        format_ident!("write_{p}_{field_name}")
+    } else {
+        format_ident!("write_{field_name}")
+    };
+    let bit_range = &field.attrs.bit_range;
+    let (value_param, value_binding) = write_value_param(field, info, quote! {#field_name});
+    let tag_quote = tag_write_quote(field, info)?;
+    let struct_name = &info.name;
+    let comment = format!(
+        "Writes to bits {} through {} within the viewed buffer, setting the `{field_name}` field of a [{struct_name}] in bitfield form.", bit_range.start, bit_range.end - 1
+    );
+    Ok(quote! {
+        #[inline]
+        #[doc = #comment]
+        pub fn #fn_field_name(&mut self #value_param) {
+            let output_byte_buffer: &mut [u8] = self.buffer.as_mut();
+            #value_binding
+            #tag_quote
             #clear_quote
             #field_quote
         }
@@ -442,19 +833,65 @@ fn make_set_fn(
     let struct_size = info.total_bytes();
     let bit_range = &field.attrs.bit_range;
     let fn_field_name = format_ident!("write_{}", field_name);
-    let type_ident = field.ty.type_quote();
+    let (value_param, value_binding) =
+        write_value_param(field, info, quote! {mut #field_name_short});
+    let tag_quote = tag_write_quote(field, info)?;
     let struct_name = &info.name;
     let comment = format!("Writes to bits {} through {} within `output_byte_buffer`, setting the `{field_name}` field of a `{struct_name}` in bitfield form.", bit_range.start, bit_range.end - 1);
     Ok(quote! {
         #[inline]
         #[doc = #comment]
-        pub fn #fn_field_name(output_byte_buffer: &mut [u8;#struct_size], mut #field_name_short: #type_ident) {
+        pub fn #fn_field_name(output_byte_buffer: &mut [u8;#struct_size] #value_param) {
+            #value_binding
+            #tag_quote
             #clear_quote
             #field_quote
         }
     })
 }
 
+/// Returns the code the write functions of a `tag_from` field run before writing it, which writes the
+/// variant id of the enum into the field it is taken from so the two always agree.
+fn tag_write_quote(field: &FieldInfo, info: &StructInfo) -> syn::Result<TokenStream> {
+    let tag = if let Some(ref tag) = field.attrs.tag_from {
+        tag
+    } else {
+        return Ok(quote! {});
+    };
+    let flip = if info.attrs.flip {
+        Some(info.total_bytes() - 1)
+    } else {
+        None
+    };
+    let (tag_setter, tag_clear) = get_field_quote(tag, flip, false)?;
+    let tag_name = tag.ident().ident();
+    let field_name = field.ident().ident();
+    let value = super::tag::tag_from_payload_quote(tag, quote! {#field_name});
+    Ok(quote! {
+        {
+            let #tag_name = #value;
+            #tag_clear
+            #tag_setter
+        }
+    })
+}
+
+/// Returns the value parameter of a field's write functions and the binding the body starts with,
+/// `constant` fields take no value and are always written with their constant.
+fn write_value_param(
+    field: &FieldInfo,
+    info: &StructInfo,
+    param: TokenStream,
+) -> (TokenStream, TokenStream) {
+    let field_name = field.ident().ident();
+    if let Some(value) = super::constant::constant_value_quote(info, &field_name) {
+        (quote! {}, quote! {let #field_name = #value;})
+    } else {
+        let type_ident = field.ty.type_quote();
+        (quote! {, #param: #type_ident}, quote! {})
+    }
+}
+
 /// the flip value must be the total amount of bytes the result of into_bytes should have MINUS ONE,
 /// the number is used to invert indices
 fn get_field_quote(
@@ -894,7 +1331,7 @@ fn apply_ne_math_to_field_access_quote(
             FieldDataType::Boolean => return Err(syn::Error::new(field.span(), "matched a boolean data type in generate code for bits that span multiple bytes in the output")),
             FieldDataType::Enum(_, _, _) => return Err(syn::Error::new(field.span(), "Enum was not given Endianness, please report this.")),
             FieldDataType::Struct(ref size, _) => {
-                let field_call = quote!{#field_access_quote.into_bytes()};
+                let field_call = struct_bytes_quote(field, &field_access_quote);
                 let apply_field_to_buffer = quote! {
                     let mut #field_buffer_name = #field_call
                 };
@@ -1066,7 +1503,8 @@ fn apply_ne_math_to_field_access_quote(
             FieldDataType::Enum(_, _, _) => return Err(syn::Error::new(field.ident.span(), "Enum was given Endianness which should be described by the struct implementing Bitfield")),
             FieldDataType::Struct(_, _) => {
                 let used_bits_in_byte = 8 - available_bits_in_first_byte;
-                quote!{output_byte_buffer[#starting_inject_byte] |= (#field_access_quote.into_bytes()[0]) >> #used_bits_in_byte;}
+                let field_call = struct_bytes_quote(field, &field_access_quote);
+                quote!{output_byte_buffer[#starting_inject_byte] |= (#field_call[0]) >> #used_bits_in_byte;}
             }
             FieldDataType::Float(_, _) => return Err(syn::Error::new(field.ident.span(), "Float not supported for single byte insert logic")),
             FieldDataType::ElementArray(_, _, _) | FieldDataType::BlockArray(_, _, _) => return Err(syn::Error::new(field.ident.span(), "an array got passed into apply_ne_math_to_field_access_quote, which is bad."))
@@ -0,0 +1,85 @@
+use crate::structs::common::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Generates the fieldless `{Name}Kind` enum of a bitfields enum, with one variant per variant of
+/// the enum, along with `read_kind` and `kind` functions so routing code can find out which variant
+/// a buffer holds without decoding it.
+pub fn create_kind_quotes(info: &EnumInfo) -> syn::Result<TokenStream> {
+    let enum_name = &info.name;
+    let vis = &info.vis;
+    let kind_ident = format_ident!("{enum_name}Kind");
+    let enum_size = info.total_bytes();
+    let id_type = info.id_ident()?;
+    let read_id = format_ident!("read_{}", EnumInfo::VARIANT_ID_NAME);
+    let last_variant = info.variants.len() - 1;
+    let mut kind_variants = quote! {};
+    let mut id_arms = quote! {};
+    let mut read_arms = quote! {};
+    let mut kind_arms = quote! {};
+    for (i, variant) in info.variants.iter().enumerate() {
+        let variant_name = &variant.name;
+        let id = variant.attrs.id.ok_or_else(|| {
+            syn::Error::new(
+                variant_name.span(),
+                "variant id was unknown at time of code generation",
+            )
+        })?;
+        let id = variant.attrs.id_literal(id);
+        // the last variant catches every id the other variants do not use, same as `from_bytes`.
+        let pattern = if i == last_variant {
+            quote! {_}
+        } else {
+            variant.id_pattern()?
+        };
+        kind_variants = quote! {
+            #kind_variants
+            #variant_name,
+        };
+        id_arms = quote! {
+            #id_arms
+            Self::#variant_name => #id,
+        };
+        read_arms = quote! {
+            #read_arms
+            #pattern => #kind_ident::#variant_name,
+        };
+        kind_arms = quote! {
+            #kind_arms
+            Self::#variant_name { .. } => #kind_ident::#variant_name,
+        };
+    }
+    let kind_comment = format!("The variants of a [{enum_name}] without their fields, see [{enum_name}::read_kind] and [{enum_name}::kind].");
+    let id_comment = format!("Returns the id a [{enum_name}] is written with for this variant. Variants which use a set of ids return the first id of the set.");
+    let read_comment = format!("Reads the variant id within `input_byte_buffer` and returns which variant of a [{enum_name}] it holds, without decoding any of the variant's fields.");
+    let kind_fn_comment = format!("Returns which variant of a [{enum_name}] this is.");
+    Ok(quote! {
+        #[doc = #kind_comment]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        #vis enum #kind_ident {
+            #kind_variants
+        }
+        impl #kind_ident {
+            #[doc = #id_comment]
+            pub fn id(&self) -> #id_type {
+                match self {
+                    #id_arms
+                }
+            }
+        }
+        impl #enum_name {
+            #[doc = #read_comment]
+            pub fn read_kind(input_byte_buffer: &[u8;#enum_size]) -> #kind_ident {
+                match Self::#read_id(input_byte_buffer) {
+                    #read_arms
+                }
+            }
+            #[doc = #kind_fn_comment]
+            pub fn kind(&self) -> #kind_ident {
+                match self {
+                    #kind_arms
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,127 @@
+use crate::structs::common::{LengthInfo, LengthPayload, StructInfo};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Returns an `i128` expression for the value `length` should hold. `elements` is an expression for
+/// the amount of elements in the `count_from` array, only used for variable payloads.
+pub fn expected_length_quote(
+    length: &LengthInfo,
+    element_size: usize,
+    elements: &TokenStream,
+) -> TokenStream {
+    let adjust = length.adjust;
+    match length.payload {
+        LengthPayload::Fixed(bytes) => {
+            let value = bytes as i128 + adjust;
+            quote! {#value}
+        }
+        LengthPayload::Variable => quote! {((#elements * #element_size) as i128 + #adjust)},
+    }
+}
+
+fn length_type_quote(info: &StructInfo, length: &LengthInfo) -> TokenStream {
+    info.fields
+        .iter()
+        .find(|f| f.ident().ident() == length.ident)
+        .map(|f| f.ty.type_quote())
+        .unwrap_or_else(|| quote! {u128})
+}
+
+/// Returns the `start..end` bit range of the length field, for errors.
+fn length_bit_range_quote(info: &StructInfo, length: &LengthInfo) -> TokenStream {
+    let bit_range = info
+        .fields
+        .iter()
+        .find(|f| f.ident().ident() == length.ident)
+        .map(|f| f.attrs.bit_range.clone())
+        .unwrap_or_default();
+    let (start, end) = (bit_range.start, bit_range.end);
+    quote! {#start..#end}
+}
+
+/// Generates the code `into_bytes` and `write_to` run after every other field has been written,
+/// which fills in each `length_of` field measuring a fixed size field. `buffer` is how the
+/// `output_byte_buffer` is passed to the field's write function.
+pub fn create_fill_quotes(info: &StructInfo, buffer: &TokenStream) -> TokenStream {
+    let mut fill_quote = quote! {};
+    for length in info.lengths.iter() {
+        if let LengthPayload::Fixed(_) = length.payload {
+            let value = expected_length_quote(length, 0, &quote! {0usize});
+            let ty = length_type_quote(info, length);
+            let write_fn = format_ident!("write_{}", length.ident);
+            fill_quote = quote! {
+                #fill_quote
+                Self::#write_fn(#buffer, #value as #ty);
+            };
+        }
+    }
+    fill_quote
+}
+
+/// Generates the checks `try_from_bytes` and `from_slice` do to make sure each `length_of` field
+/// matches the size of the field it measures. `elements` is an expression for the amount of
+/// elements in the `count_from` array.
+pub fn create_check_quotes(
+    info: &StructInfo,
+    element_size: usize,
+    elements: &TokenStream,
+) -> TokenStream {
+    let mut check_quote = quote! {};
+    for length in info.lengths.iter() {
+        let expected = expected_length_quote(length, element_size, elements);
+        let read_fn = format_ident!("read_{}", length.ident);
+        let name = length.ident.to_string();
+        let bit_range = length_bit_range_quote(info, length);
+        check_quote = quote! {
+            #check_quote
+            {
+                let found = Self::#read_fn(&input_byte_buffer);
+                let expected: i128 = #expected;
+                if expected < 0 || found as u128 != expected as u128 {
+                    return Err(bondrewd::BitfieldTryError {
+                        field: #name,
+                        bit_range: #bit_range,
+                        kind: bondrewd::BitfieldTryErrorKind::LengthMismatch(found as u128, expected),
+                    }
+                    .into());
+                }
+            }
+        };
+    }
+    check_quote
+}
+
+/// Generates the code `into_slice` runs to fill in each `length_of` field, returning an error if
+/// the length can not be stored in the field. `elements` is an expression for the amount of
+/// elements in the `count_from` array.
+pub fn create_variable_fill_quotes(
+    info: &StructInfo,
+    element_size: usize,
+    elements: &TokenStream,
+) -> TokenStream {
+    let mut fill_quote = quote! {};
+    for length in info.lengths.iter() {
+        let value = expected_length_quote(length, element_size, elements);
+        let ty = length_type_quote(info, length);
+        let write_fn = format_ident!("write_{}", length.ident);
+        let name = length.ident.to_string();
+        let max = length.max;
+        let bit_range = length_bit_range_quote(info, length);
+        fill_quote = quote! {
+            #fill_quote
+            {
+                let length: i128 = #value;
+                if length < 0 || length as u128 > #max {
+                    return Err(bondrewd::BitfieldTryError {
+                        field: #name,
+                        bit_range: #bit_range,
+                        kind: bondrewd::BitfieldTryErrorKind::LengthOutOfRange(length),
+                    }
+                    .into());
+                }
+                Self::#write_fn(&mut output_byte_buffer, length as #ty);
+            }
+        };
+    }
+    fill_quote
+}
@@ -1,5 +1,16 @@
+pub mod checksum;
 pub mod common;
+pub mod compact;
+pub mod constant;
+pub mod fill;
 pub mod from_bytes;
 pub mod into_bytes;
+pub mod kind;
+pub mod length;
+pub mod nested;
 pub mod parse;
+pub mod serde;
 pub mod struct_fns;
+pub mod tag;
+pub mod variable;
+pub mod view;
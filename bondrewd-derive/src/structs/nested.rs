@@ -0,0 +1,42 @@
+use crate::structs::common::{FieldDataType, FieldInfo, StructInfo};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Returns the nested bitfields fields of `info`, tagged payloads are left out because they are
+/// stored without their id.
+fn nested_fields(info: &StructInfo) -> impl Iterator<Item = (&FieldInfo, usize, &TokenStream)> {
+    info.fields.iter().filter_map(|field| match field.ty {
+        FieldDataType::Struct(size, ref ty) if field.attrs.tag_from.is_none() => {
+            Some((field, size, ty))
+        }
+        _ => None,
+    })
+}
+
+/// Generates compile time checks that every nested bitfields field (a struct or enum, which may be
+/// a variant's payload) was given enough bits to hold the `BIT_SIZE` of its type. Without them a
+/// nested enum given too few bits would silently lose the end of its variants.
+pub fn create_size_checks_quote(info: &StructInfo) -> TokenStream {
+    let mut checks = quote! {};
+    for (field, size, ty) in nested_fields(info) {
+        let bit_length = field.attrs.bit_length();
+        let message = format!(
+            "`{}` field `{}` uses {bit_length} bits which is less than the BIT_SIZE of its type",
+            info.name,
+            field.ident().name(),
+        );
+        checks = quote! {
+            #checks
+            assert!(<#ty as bondrewd::Bitfields<#size>>::BIT_SIZE <= #bit_length, #message);
+        };
+    }
+    if checks.is_empty() {
+        checks
+    } else {
+        quote! {
+            const _: () = {
+                #checks
+            };
+        }
+    }
+}
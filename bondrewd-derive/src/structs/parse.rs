@@ -1,4 +1,4 @@
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
 use quote::format_ident;
 use std::ops::Range;
 use syn::parse::Error;
@@ -13,6 +13,8 @@ pub struct TryFromAttrBuilderError {
     pub reserve: ReserveFieldOption,
     pub overlap: OverlapOptions,
     pub capture_id: bool,
+    pub fill_pattern: Option<u8>,
+    pub read_through: Vec<(Ident, TokenStream)>,
 }
 
 impl TryFromAttrBuilderError {
@@ -23,6 +25,9 @@ impl TryFromAttrBuilderError {
             reserve: self.reserve,
             overlap: self.overlap,
             capture_id: self.capture_id,
+            fill_pattern: self.fill_pattern,
+            tag_from: None,
+            read_through: self.read_through,
         }
     }
 }
@@ -73,6 +78,28 @@ pub struct FieldAttrBuilder {
     /// This should only ever be true when it the first field in a variant
     /// of an enum.
     pub capture_id: bool,
+    /// name of the field which holds the amount of elements in a variable length array.
+    pub count_from: Option<Ident>,
+    /// maximum amount of elements a variable length array can hold.
+    pub max: Option<usize>,
+    /// `crc` or `parity` attribute name and its value.
+    pub checksum: Option<(Ident, String)>,
+    /// the range a checksum covers, and where it was defined.
+    pub covers: Option<(String, Span)>,
+    /// name of the field whose size in bytes this field holds.
+    pub length_of: Option<Ident>,
+    /// amount added to the size in bytes before it is stored in a `length_of` field, and where it was defined.
+    pub adjust: Option<(i128, Span)>,
+    /// value always written to the field, `true` and `false` are stored as 1 and 0.
+    pub constant: Option<(u128, Span)>,
+    /// byte pattern a reserve field is written with.
+    pub fill_pattern: Option<u8>,
+    /// name of the field the variant id of this enum field is stored in.
+    pub tag_from: Option<Ident>,
+    /// `read_` functions of a nested bitfields field and the type they return.
+    pub read_through: Vec<(Ident, TokenStream)>,
+    /// the field is shared by every variant of an enum.
+    pub common: bool,
 }
 
 impl FieldAttrBuilder {
@@ -85,6 +112,17 @@ impl FieldAttrBuilder {
             reserve: ReserveFieldOption::NotReserve,
             overlap: OverlapOptions::None,
             capture_id: false,
+            count_from: None,
+            max: None,
+            checksum: None,
+            covers: None,
+            length_of: None,
+            adjust: None,
+            constant: None,
+            fill_pattern: None,
+            tag_from: None,
+            read_through: Vec::new(),
+            common: false,
         }
     }
 
@@ -556,6 +594,144 @@ impl FieldAttrBuilder {
                                 ));
                             }
                         }
+                        "count_from" => {
+                            if let Lit::Str(val) = value.lit {
+                                builder.count_from = Some(Ident::new(&val.value(), val.span()));
+                            } else {
+                                return Err(Error::new(
+                                    builder.span(),
+                                    "count_from must use a literal str containing the name of the count field",
+                                ));
+                            }
+                        }
+                        "crc" | "parity" => {
+                            if builder.checksum.is_some() {
+                                return Err(Error::new(
+                                    builder.span(),
+                                    "a field can only hold one checksum",
+                                ));
+                            }
+                            if let Lit::Str(val) = value.lit {
+                                builder.checksum = Some((ident.clone(), val.value()));
+                            } else {
+                                return Err(Error::new(
+                                    builder.span(),
+                                    format!("{ident_as_str} must use a literal str ex. crc = \"crc16_ccitt_false\" or parity = \"even\""),
+                                ));
+                            }
+                        }
+                        "covers" => {
+                            if let Lit::Str(val) = value.lit {
+                                builder.covers = Some((val.value(), val.span()));
+                            } else {
+                                return Err(Error::new(
+                                    builder.span(),
+                                    "covers must use a literal str containing a range ex. covers = \"0..6\"",
+                                ));
+                            }
+                        }
+                        "length_of" => {
+                            if let Lit::Str(val) = value.lit {
+                                builder.length_of = Some(Ident::new(&val.value(), val.span()));
+                            } else {
+                                return Err(Error::new(
+                                    builder.span(),
+                                    "length_of must use a literal str containing the name of the field being measured",
+                                ));
+                            }
+                        }
+                        "read_through" => {
+                            if let Lit::Str(val) = value.lit {
+                                builder.read_through = parse_read_through(&val.value(), val.span())?;
+                            } else {
+                                return Err(Error::new(
+                                    builder.span(),
+                                    "read_through must use a literal str containing a list of read functions and their types ex. read_through = \"temperature_value: u16\"",
+                                ));
+                            }
+                        }
+                        "tag_from" => {
+                            if let Lit::Str(val) = value.lit {
+                                builder.tag_from = Some(Ident::new(&val.value(), val.span()));
+                            } else {
+                                return Err(Error::new(
+                                    builder.span(),
+                                    "tag_from must use a literal str containing the name of the field holding the variant id",
+                                ));
+                            }
+                        }
+                        "adjust" => {
+                            if let Lit::Int(val) = value.lit {
+                                match val.base10_parse::<i128>() {
+                                    Ok(adjust) => builder.adjust = Some((adjust, val.span())),
+                                    Err(err) => {
+                                        return Err(Error::new(
+                                            builder.span(),
+                                            format!("adjust must be a number that can be parsed as a i128 [{}]", err),
+                                        ));
+                                    }
+                                }
+                            } else {
+                                return Err(Error::new(
+                                    builder.span(),
+                                    "adjust must use a literal integer ex. adjust = -1",
+                                ));
+                            }
+                        }
+                        "constant" => match value.lit {
+                            Lit::Int(val) => match val.base10_parse::<u128>() {
+                                Ok(constant) => builder.constant = Some((constant, val.span())),
+                                Err(err) => {
+                                    return Err(Error::new(
+                                        builder.span(),
+                                        format!("constant must be a number that can be parsed as a u128 [{}]", err),
+                                    ));
+                                }
+                            },
+                            Lit::Bool(val) => builder.constant = Some((val.value as u128, val.span)),
+                            _ => {
+                                return Err(Error::new(
+                                    builder.span(),
+                                    "constant must use a literal unsigned integer or bool ex. constant = 0x1ACFFC1D",
+                                ));
+                            }
+                        },
+                        "fill_pattern" => {
+                            if let Lit::Int(val) = value.lit {
+                                match val.base10_parse::<u8>() {
+                                    Ok(pattern) => builder.fill_pattern = Some(pattern),
+                                    Err(err) => {
+                                        return Err(Error::new(
+                                            builder.span(),
+                                            format!("fill_pattern must be a number that can be parsed as a u8 [{}]", err),
+                                        ));
+                                    }
+                                }
+                            } else {
+                                return Err(Error::new(
+                                    builder.span(),
+                                    "fill_pattern must use a literal u8 ex. fill_pattern = 0xFF",
+                                ));
+                            }
+                        }
+                        "max" => {
+                            if let Lit::Int(val) = value.lit {
+                                match val.base10_parse::<usize>() {
+                                    Ok(max) => builder.max = Some(max),
+                                    Err(err) => {
+                                        return Err(Error::new(
+                                            builder.span(),
+                                            format!("max must be a number that can be parsed as a usize [{}]", err),
+                                        ));
+                                    }
+                                }
+                            } else {
+                                return Err(Error::new(
+                                    builder.span(),
+                                    "max must use a literal usize",
+                                ));
+                            }
+                        }
                         _ => {
                             if ident_as_str.as_str() != "doc" {
                                 return Err(Error::new(
@@ -579,6 +755,9 @@ impl FieldAttrBuilder {
                         "capture_id" => {
                             builder.capture_id = true;
                         }
+                        "common" => {
+                            builder.common = true;
+                        }
                         // TODO  can not enable this until i figure out a way to express exactly the amount
                         // of overlapping bits.
                         /*"allow_overlap" => {
@@ -609,6 +788,27 @@ impl FieldAttrBuilder {
     }
 }
 
+/// Parses a `read_through` list like `"temperature_value: u16, ping_sequence: u8"`.
+fn parse_read_through(value: &str, span: Span) -> syn::Result<Vec<(Ident, TokenStream)>> {
+    let mut reads = Vec::new();
+    for read in value.split(',').filter(|read| !read.trim().is_empty()) {
+        let parsed = read.split_once(':').and_then(|(name, ty)| {
+            let name = syn::parse_str::<Ident>(name.trim()).ok()?;
+            let ty = syn::parse_str::<syn::Type>(ty.trim()).ok()?;
+            Some((name, quote::quote! {#ty}))
+        });
+        if let Some(parsed) = parsed {
+            reads.push(parsed);
+        } else {
+            return Err(Error::new(
+                span,
+                format!("\"{}\" is not a read function and its type ex. temperature_value: u16", read.trim()),
+            ));
+        }
+    }
+    Ok(reads)
+}
+
 impl TryInto<FieldAttrs> for FieldAttrBuilder {
     type Error = TryFromAttrBuilderError;
     fn try_into(self) -> std::result::Result<FieldAttrs, Self::Error> {
@@ -619,6 +819,9 @@ impl TryInto<FieldAttrs> for FieldAttrBuilder {
                 reserve: self.reserve,
                 overlap: self.overlap,
                 capture_id: self.capture_id,
+                fill_pattern: self.fill_pattern,
+                tag_from: None,
+                read_through: self.read_through,
             })
         } else {
             Err(TryFromAttrBuilderError {
@@ -626,6 +829,8 @@ impl TryInto<FieldAttrs> for FieldAttrBuilder {
                 reserve: self.reserve,
                 overlap: self.overlap,
                 capture_id: self.capture_id,
+                fill_pattern: self.fill_pattern,
+                read_through: self.read_through,
             })
         }
     }
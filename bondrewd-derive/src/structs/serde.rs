@@ -0,0 +1,224 @@
+use crate::structs::common::{EnumInfo, FieldIdent, FieldInfo, StructInfo};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// The name of the shadow type serialized in place of the bitfields type.
+const REF_SHADOW: &str = "BondrewdSerdeRef";
+/// The name of the shadow type deserialized in place of the bitfields type.
+const OWNED_SHADOW: &str = "BondrewdSerdeOwned";
+
+/// A field the user defined, fake fields (variant ids and fill) are not (de)serialized.
+struct SerdeField<'a> {
+    /// the field's name, `None` for tuple fields.
+    member: Option<proc_macro2::Ident>,
+    /// the name used for the field in errors.
+    name: String,
+    /// a local binding for the field's value.
+    binding: proc_macro2::Ident,
+    info: &'a FieldInfo,
+}
+
+fn serde_fields(info: &StructInfo) -> Vec<SerdeField<'_>> {
+    info.fields
+        .iter()
+        .filter(|field| !field.attrs.reserve.is_fake_field())
+        .enumerate()
+        .map(|(i, field)| {
+            let (member, name) = match field.ident().as_ref() {
+                FieldIdent::Ident { ident, name: _ } if !info.tuple => {
+                    (Some(ident.clone()), ident.to_string())
+                }
+                _ => (None, format!("{i}")),
+            };
+            SerdeField {
+                member,
+                name,
+                binding: format_ident!("field_{i}"),
+                info: field,
+            }
+        })
+        .collect()
+}
+
+/// Returns the body of a shadow struct or variant. `reference` makes each field a reference
+/// borrowing from the bitfields type.
+fn shadow_body(info: &StructInfo, fields: &[SerdeField], reference: bool) -> TokenStream {
+    let types = fields.iter().map(|field| {
+        let ty = field.info.ty.type_quote();
+        if reference {
+            quote! {&'a #ty}
+        } else {
+            quote! {#ty}
+        }
+    });
+    if fields.is_empty() {
+        quote! {}
+    } else if info.tuple {
+        quote! {(#(#types,)*)}
+    } else {
+        let members = fields.iter().map(|field| &field.member);
+        quote! {{#(#members: #types,)*}}
+    }
+}
+
+/// Returns the fields of a struct or variant bound to (or built from) each field's `binding`, for
+/// use in patterns and constructors.
+fn bindings_quote(info: &StructInfo, fields: &[SerdeField]) -> TokenStream {
+    let bindings = fields.iter().map(|field| &field.binding);
+    if fields.is_empty() {
+        quote! {{}}
+    } else if info.tuple {
+        quote! {(#(#bindings,)*)}
+    } else {
+        let members = fields.iter().map(|field| &field.member);
+        quote! {{#(#members: #bindings,)*}}
+    }
+}
+
+/// Returns a check that the value of `field` stored in `binding` fits in the bits the field uses,
+/// returning a deserialize error if it does not.
+fn range_check_quote(field: &SerdeField) -> TokenStream {
+    let binding = &field.binding;
+    let message = format!("value of field `{}` does not fit in its bits", field.name);
+    let nested_message = format!(
+        "value of field `{}` does not fit in its bits: {{}}",
+        field.name
+    );
+    super::struct_fns::range_check_quote(
+        &field.info.ty,
+        field.info.attrs.bit_length(),
+        quote! {#binding},
+        &quote! {
+            return Err(<D::Error as bondrewd::serde::de::Error>::custom(#message));
+        },
+        &quote! {
+            return Err(<D::Error as bondrewd::serde::de::Error>::custom(format_args!(#nested_message, err)));
+        },
+    )
+}
+
+/// Generates `Serialize` and `Deserialize` implementations for a struct marked with
+/// `#[bondrewd(serde)]`. Deserializing rejects values that do not fit in their field's bit length.
+pub fn create_struct_serde_quotes(info: &StructInfo) -> TokenStream {
+    let name = &info.name;
+    let name_str = name.to_string();
+    let ref_ident = format_ident!("{REF_SHADOW}");
+    let owned_ident = format_ident!("{OWNED_SHADOW}");
+    let fields = serde_fields(info);
+    let ref_body = shadow_body(info, &fields, true);
+    let owned_body = shadow_body(info, &fields, false);
+    // structures without fields get unit shadows, which can not have a lifetime.
+    let semi = if info.tuple || fields.is_empty() {
+        quote! {;}
+    } else {
+        quote! {}
+    };
+    let lifetime = if fields.is_empty() {
+        quote! {}
+    } else {
+        quote! {<'a>}
+    };
+    let bindings = bindings_quote(info, &fields);
+    let checks = fields.iter().map(range_check_quote);
+    quote! {
+        const _: () = {
+            #[derive(bondrewd::serde::Serialize)]
+            #[serde(crate = "bondrewd::serde", rename = #name_str)]
+            struct #ref_ident #lifetime #ref_body #semi
+            #[derive(bondrewd::serde::Deserialize)]
+            #[serde(crate = "bondrewd::serde", rename = #name_str)]
+            struct #owned_ident #owned_body #semi
+            impl bondrewd::serde::Serialize for #name {
+                fn serialize<S: bondrewd::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let Self #bindings = self;
+                    bondrewd::serde::Serialize::serialize(&#ref_ident #bindings, serializer)
+                }
+            }
+            impl<'de> bondrewd::serde::Deserialize<'de> for #name {
+                fn deserialize<D: bondrewd::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let #owned_ident #bindings = <#owned_ident as bondrewd::serde::Deserialize>::deserialize(deserializer)?;
+                    #(#checks)*
+                    Ok(Self #bindings)
+                }
+            }
+        };
+    }
+}
+
+/// Generates `Serialize` and `Deserialize` implementations for an enum marked with
+/// `#[bondrewd(serde)]`. The variant id is not serialized, the variant name is used instead.
+pub fn create_enum_serde_quotes(info: &EnumInfo) -> TokenStream {
+    let name = &info.name;
+    let name_str = name.to_string();
+    let ref_ident = format_ident!("{REF_SHADOW}");
+    let owned_ident = format_ident!("{OWNED_SHADOW}");
+    let has_fields = info
+        .variants
+        .iter()
+        .any(|variant| !serde_fields(variant).is_empty());
+    let lifetime = if has_fields {
+        quote! {<'a>}
+    } else {
+        quote! {}
+    };
+    let mut ref_variants = quote! {};
+    let mut owned_variants = quote! {};
+    let mut serialize_arms = quote! {};
+    let mut deserialize_arms = quote! {};
+    for variant in info.variants.iter() {
+        let variant_name = &variant.name;
+        let fields = serde_fields(variant);
+        let ref_body = shadow_body(variant, &fields, true);
+        let owned_body = shadow_body(variant, &fields, false);
+        let bindings = bindings_quote(variant, &fields);
+        let checks = fields.iter().map(range_check_quote);
+        ref_variants = quote! {
+            #ref_variants
+            #variant_name #ref_body,
+        };
+        owned_variants = quote! {
+            #owned_variants
+            #variant_name #owned_body,
+        };
+        serialize_arms = quote! {
+            #serialize_arms
+            Self::#variant_name #bindings => #ref_ident::#variant_name #bindings,
+        };
+        deserialize_arms = quote! {
+            #deserialize_arms
+            #owned_ident::#variant_name #bindings => {
+                #(#checks)*
+                Ok(Self::#variant_name #bindings)
+            }
+        };
+    }
+    quote! {
+        const _: () = {
+            #[derive(bondrewd::serde::Serialize)]
+            #[serde(crate = "bondrewd::serde", rename = #name_str)]
+            enum #ref_ident #lifetime {
+                #ref_variants
+            }
+            #[derive(bondrewd::serde::Deserialize)]
+            #[serde(crate = "bondrewd::serde", rename = #name_str)]
+            enum #owned_ident {
+                #owned_variants
+            }
+            impl bondrewd::serde::Serialize for #name {
+                fn serialize<S: bondrewd::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let shadow = match self {
+                        #serialize_arms
+                    };
+                    bondrewd::serde::Serialize::serialize(&shadow, serializer)
+                }
+            }
+            impl<'de> bondrewd::serde::Deserialize<'de> for #name {
+                fn deserialize<D: bondrewd::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    match <#owned_ident as bondrewd::serde::Deserialize>::deserialize(deserializer)? {
+                        #deserialize_arms
+                    }
+                }
+            }
+        };
+    }
+}
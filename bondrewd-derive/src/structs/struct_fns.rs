@@ -1,170 +1,511 @@
-use crate::structs::common::{FieldDataType, FieldInfo, NumberSignage, StructInfo};
-use proc_macro2::TokenStream;
+use crate::structs::common::{EnumInfo, FieldDataType, FieldInfo, NumberSignage, StructInfo};
+use convert_case::{Case, Casing};
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 
-pub fn create_setters_quotes(info: &StructInfo) -> Result<TokenStream, syn::Error> {
-    // all of the fields set functions that disallow numbers that are too large to fit into bit length.
-    let mut set_fns_quote = quote! {};
-    for field in info.fields.iter() {
-        let q = make_set_field_quote(field)?;
-        set_fns_quote = quote! {
-            #set_fns_quote
-            #q
-        };
+/// Returns the fields setters are made for, fields which are never written (reserve and fake fields)
+/// are left out.
+fn setter_fields(info: &StructInfo) -> impl Iterator<Item = &FieldInfo> {
+    info.fields
+        .iter()
+        .filter(|field| field.attrs.reserve.write_field())
+}
+
+/// Returns the smallest and largest value a number of `bit_length` bits can hold.
+fn number_bounds(sign: &NumberSignage, bit_length: usize) -> (i128, u128) {
+    if bit_length == 0 {
+        return (0, 0);
+    }
+    match sign {
+        NumberSignage::Signed => {
+            let max: i128 = ((2_u128.pow(bit_length as u32) / 2_u128) - 1) as i128;
+            (-max - 1, max as u128)
+        }
+        NumberSignage::Unsigned => (0, 2_u128.pow(bit_length as u32) - 1),
     }
-    Ok(set_fns_quote)
 }
 
-fn make_set_field_quote(field: &FieldInfo) -> Result<TokenStream, syn::Error> {
-    let field_name = field.ident().ident();
-    Ok(match field.ty {
-        FieldDataType::Number(ref size, ref sign, ref type_ident) => {
-            let mut full_quote = quote! {
-                self.#field_name = value.clone();
-                value
-            };
-            let bit_length = field.bit_size();
-            if bit_length != size * 8 {
-                match sign {
-                    NumberSignage::Signed => {
-                        let max: i128 = ((2_u128.pow(bit_length as u32) / 2_u128) - 1) as i128;
-                        let min = -max - 1;
-                        let max_lit = proc_macro2::Literal::i128_unsuffixed(max);
-                        let min_lit = proc_macro2::Literal::i128_unsuffixed(min);
-                        full_quote = quote! {
-                            if value > #max_lit {
-                                self.#field_name = #max_lit;
-                                #max_lit
-                            } else if value < #min_lit {
-                                self.#field_name = #min_lit;
-                                #min_lit
-                            } else {
-                                #full_quote
-                            }
-                        };
-                    }
-                    NumberSignage::Unsigned => {
-                        let max: u128 = 2_u128.pow(bit_length as u32) - 1;
-                        let max_lit = proc_macro2::Literal::u128_unsuffixed(max);
-                        full_quote = quote! {
-                            if value > #max_lit {
-                                self.#field_name = #max_lit;
-                                #max_lit
-                            }else {
-                                #full_quote
-                            }
-                        };
+/// Returns the largest code point a char of `bit_length` bits can hold, `None` when every char fits.
+fn char_max(size: usize, bit_length: usize) -> Option<u32> {
+    if bit_length >= size * 8 {
+        return None;
+    }
+    let max: u32 = 2_u32.pow(bit_length as u32) - 1;
+    if max >= char::MAX as u32 {
+        None
+    } else {
+        Some(max)
+    }
+}
+
+/// Returns the bits each element of an array field gets. Block arrays drop the bits of the lowest
+/// indexes first.
+fn element_bit_lengths(
+    sub_ty: &FieldDataType,
+    length: usize,
+    bit_length: usize,
+    block: bool,
+) -> Vec<usize> {
+    if !block {
+        return vec![bit_length / length; length];
+    }
+    let element_bits = sub_ty.size() * 8;
+    let mut dropped = (element_bits * length).saturating_sub(bit_length);
+    (0..length)
+        .map(|_| {
+            let drop = dropped.min(element_bits);
+            dropped -= drop;
+            element_bits - drop
+        })
+        .collect()
+}
+
+/// Returns code that clamps the number or char `value` (a place expression) to the closest value
+/// which fits in `bit_length` bits. Other types can not be clamped and get an empty quote.
+fn clamp_value_quote(ty: &FieldDataType, bit_length: usize, value: TokenStream) -> TokenStream {
+    match ty {
+        FieldDataType::Number(ref size, ref sign, _) if bit_length < size * 8 => {
+            let (min, max) = number_bounds(sign, bit_length);
+            let max_lit = proc_macro2::Literal::u128_unsuffixed(max);
+            match sign {
+                NumberSignage::Signed => {
+                    let min_lit = proc_macro2::Literal::i128_unsuffixed(min);
+                    quote! {
+                        if #value > #max_lit {
+                            #value = #max_lit;
+                        } else if #value < #min_lit {
+                            #value = #min_lit;
+                        }
                     }
                 }
+                NumberSignage::Unsigned => quote! {
+                    if #value > #max_lit {
+                        #value = #max_lit;
+                    }
+                },
             }
-            let field_fn_name = format_ident!("set_{}", field_name);
-            quote! {
-                fn #field_fn_name(&mut self, value: #type_ident) -> #type_ident {
-                    #full_quote
+        }
+        FieldDataType::Char(ref size, _) => {
+            match char_max(*size, bit_length).and_then(char::from_u32) {
+                Some(max_char) => {
+                    let max = max_char as u32;
+                    quote! {
+                        if #value as u32 > #max {
+                            #value = #max_char;
+                        }
+                    }
                 }
+                None => quote! {},
             }
         }
-        FieldDataType::Float(ref size, ref type_ident) => {
-            let mut full_quote = quote! {
-                self.#field_name = value;
-                value
-            };
-            let bit_length = field.bit_size();
-            if bit_length != *size * 8_usize {
-                let max: f64 = if *size == 4_usize {
-                    f32::MAX as f64
-                } else if *size == 8_usize {
-                    f64::MAX
-                } else {
-                    return Err(syn::Error::new(
-                        field.span(),
-                        "unsupported floating point size",
-                    ));
-                };
-                let min: f64 = if *size == 4_usize {
-                    f32::MIN as f64
-                } else if *size == 8_usize {
-                    f64::MIN
-                } else {
-                    return Err(syn::Error::new(
-                        field.span(),
-                        "unsupported floating point size",
-                    ));
-                };
-                let max_lit = proc_macro2::Literal::f64_unsuffixed(max);
-                let min_lit = proc_macro2::Literal::f64_unsuffixed(min);
-                full_quote = quote! {
-                    if value > #max_lit {
-                        self.#field_name = #max_lit;
-                        #max_lit
-                    }else if value < #min_lit {
-                        self.#field_name = #min_lit;
-                        #min_lit
-                    }else {
-                        #full_quote
+        FieldDataType::ElementArray(ref sub, ref length, _)
+        | FieldDataType::BlockArray(ref sub, ref length, _) => {
+            let block = matches!(ty, FieldDataType::BlockArray(..));
+            let bit_lengths = element_bit_lengths(&sub.ty, *length, bit_length, block);
+            if !block {
+                let clamp = clamp_value_quote(&sub.ty, bit_lengths[0], quote! {*element});
+                if clamp.is_empty() {
+                    return clamp;
+                }
+                return quote! {
+                    for element in #value.iter_mut() {
+                        #clamp
                     }
                 };
             }
-            let field_fn_name = format_ident!("set_{}", field_name);
-            quote! {
-                fn #field_fn_name(&mut self, value: #type_ident) -> #type_ident {
-                    #full_quote
+            let clamps = bit_lengths.iter().enumerate().map(|(i, element_bits)| {
+                clamp_value_quote(&sub.ty, *element_bits, quote! {#value[#i]})
+            });
+            quote! {#(#clamps)*}
+        }
+        _ => quote! {},
+    }
+}
+
+/// Returns the error a checked setter or `check_fields` returns when the value of `field` does not
+/// fit in its bits.
+fn out_of_range_quote(field: &FieldInfo) -> TokenStream {
+    let name = field.ident().ident().to_string();
+    let (start, end) = (field.attrs.bit_range.start, field.attrs.bit_range.end);
+    quote! {
+        return Err(bondrewd::BitfieldTryError {
+            field: #name,
+            bit_range: #start..#end,
+            kind: bondrewd::BitfieldTryErrorKind::ValueOutOfRange,
+        });
+    }
+}
+
+/// Returns code that runs `error` when `value` does not fit in `bit_length` bits. Nested bitfields
+/// are checked with their own [check_fields](bondrewd::Bitfields::check_fields), running
+/// `nested_error` with its error bound to `err`. `enum_primitive` types are `Copy`, so their
+/// primitive is taken from a copy of the value. Checked setters, `check_fields` and the serde
+/// `Deserialize` implementations share these checks.
+pub fn range_check_quote(
+    ty: &FieldDataType,
+    bit_length: usize,
+    value: TokenStream,
+    error: &TokenStream,
+    nested_error: &TokenStream,
+) -> TokenStream {
+    match ty {
+        FieldDataType::Number(ref size, ref sign, _) if bit_length < size * 8 => {
+            let (min, max) = number_bounds(sign, bit_length);
+            let max_lit = proc_macro2::Literal::u128_unsuffixed(max);
+            match sign {
+                NumberSignage::Signed => {
+                    let min_lit = proc_macro2::Literal::i128_unsuffixed(min);
+                    quote! {
+                        if #value > #max_lit || #value < #min_lit {
+                            #error
+                        }
+                    }
                 }
+                NumberSignage::Unsigned => quote! {
+                    if #value > #max_lit {
+                        #error
+                    }
+                },
             }
         }
-        FieldDataType::Enum(_, _, ref type_ident) => {
-            let field_fn_name = format_ident!("set_{}", field_name);
+        FieldDataType::Char(ref size, _) => match char_max(*size, bit_length) {
+            Some(max) => quote! {
+                if #value as u32 > #max {
+                    #error
+                }
+            },
+            None => quote! {},
+        },
+        FieldDataType::Enum(_, ref size, _) if bit_length < size * 8 => {
+            let (_, max) = number_bounds(&NumberSignage::Unsigned, bit_length);
+            let max_lit = proc_macro2::Literal::u128_unsuffixed(max);
             quote! {
-                fn #field_fn_name(&mut self, value: #type_ident) {
-                    self.value;
+                if (#value).into_primitive() as u128 > #max_lit {
+                    #error
                 }
             }
         }
-        FieldDataType::Struct(_, ref type_ident) => {
-            let field_fn_name = format_ident!("{}_mut_ref", field_name);
-            quote! {
-                fn #field_fn_name(&mut self) -> &mut #type_ident {
-                    &mut self.#field_name
+        FieldDataType::Struct(_, _) => quote! {
+            if let Err(err) = bondrewd::Bitfields::check_fields(&#value) {
+                #nested_error
+            }
+        },
+        FieldDataType::ElementArray(ref sub, ref length, _)
+        | FieldDataType::BlockArray(ref sub, ref length, _) => {
+            let block = matches!(ty, FieldDataType::BlockArray(..));
+            let bit_lengths = element_bit_lengths(&sub.ty, *length, bit_length, block);
+            if !block {
+                let check = range_check_quote(
+                    &sub.ty,
+                    bit_lengths[0],
+                    quote! {(*element)},
+                    error,
+                    nested_error,
+                );
+                if check.is_empty() {
+                    return check;
                 }
+                return quote! {
+                    for element in #value.iter() {
+                        #check
+                    }
+                };
             }
+            let checks = bit_lengths.iter().enumerate().map(|(i, element_bits)| {
+                range_check_quote(
+                    &sub.ty,
+                    *element_bits,
+                    quote! {#value[#i]},
+                    error,
+                    nested_error,
+                )
+            });
+            quote! {#(#checks)*}
+        }
+        _ => quote! {},
+    }
+}
+
+/// Returns code that returns a `BitfieldTryError` when `value` does not fit in the bits of `field`,
+/// errors of nested bitfields are returned as is.
+fn check_value_quote(field: &FieldInfo, value: TokenStream) -> TokenStream {
+    range_check_quote(
+        &field.ty,
+        field.attrs.bit_length(),
+        value,
+        &out_of_range_quote(field),
+        &quote! {return Err(err);},
+    )
+}
+
+/// Returns the body of a setter which stores `value` using `store`. Checked setters return an error
+/// instead of clamping values that do not fit.
+fn setter_body(field: &FieldInfo, checked: bool, store: TokenStream) -> (TokenStream, TokenStream) {
+    let bit_length = field.attrs.bit_length();
+    if checked {
+        let check = check_value_quote(field, quote! {value});
+        (quote! {value}, quote! {#check #store})
+    } else {
+        let clamp = clamp_value_quote(&field.ty, bit_length, quote! {value});
+        if clamp.is_empty() {
+            (quote! {value}, store)
+        } else {
+            (quote! {mut value}, quote! {#clamp #store})
         }
-        FieldDataType::Char(ref size, ref type_ident) => {
-            let mut full_quote = quote! {
-                self.#field_name = value.clone();
-                value
+    }
+}
+
+/// Returns the fields of a struct which hold a value along with how they are accessed through `self`,
+/// constant fields have no value to set or check.
+fn value_fields(info: &StructInfo) -> impl Iterator<Item = (&FieldInfo, TokenStream)> {
+    info.fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| {
+            field.attrs.reserve.write_field()
+                && !super::constant::is_constant(info, &field.ident().ident())
+        })
+        .map(|(i, field)| {
+            let access = if info.tuple {
+                let index = syn::Index::from(i);
+                quote! {#index}
+            } else {
+                let ident = field.ident().ident();
+                quote! {#ident}
             };
-            let bit_length = field.bit_size();
-            if bit_length != size * 8 {
-                let max: u32 = 2_u32.pow(bit_length as u32) - 1;
-                full_quote = quote! {
-                    if value as u32 > #max {
-                        self.#field_name = #max;
-                        #max
-                    }else {
-                        #full_quote
-                    }
-                };
+            (field, access)
+        })
+}
+
+/// Generates the `check_fields` function of the `Bitfields` implementation of a struct.
+pub fn create_check_fields_quote(info: &StructInfo) -> TokenStream {
+    let checks =
+        value_fields(info).map(|(field, access)| check_value_quote(field, quote! {self.#access}));
+    quote! {
+        fn check_fields(&self) -> Result<(), bondrewd::BitfieldTryError> {
+            #(#checks)*
+            Ok(())
+        }
+    }
+}
+
+/// Generates a `set_{field}` function for each field of a struct. Setters clamp values that do not
+/// fit in their field's bits, or return an error when `checked` (`#[bondrewd(checked_setters)]`).
+pub fn create_setters_quotes(info: &StructInfo, checked: bool) -> Result<TokenStream, syn::Error> {
+    let mut set_fns_quote = quote! {};
+    for (field, access) in value_fields(info) {
+        let field_name = field.ident().ident();
+        let field_fn_name = format_ident!("set_{field_name}");
+        let type_ident = field.ty.type_quote();
+        // the field holding the variant id of a `tag_from` enum is kept in agreement with it.
+        let store = if let Some(ref tag) = field.attrs.tag_from {
+            let tag_name = tag.ident().ident();
+            let tag_value = super::tag::tag_from_payload_quote(tag, quote! {value});
+            quote! {
+                self.#tag_name = #tag_value;
+                self.#access = value;
+            }
+        } else {
+            quote! {self.#access = value;}
+        };
+        let (value, body) = setter_body(field, checked, store);
+        set_fns_quote = if checked {
+            let comment = format!("Sets `{field_name}`, returning an error without changing it when the value does not fit in the field's bits.");
+            quote! {
+                #set_fns_quote
+                #[doc = #comment]
+                pub fn #field_fn_name(&mut self, #value: #type_ident) -> Result<(), bondrewd::BitfieldTryError> {
+                    #body
+                    Ok(())
+                }
             }
-            let field_fn_name = format_ident!("set_{}", field_name);
+        } else {
+            let comment = format!(
+                "Sets `{field_name}`, values that do not fit in the field's bits are clamped."
+            );
             quote! {
-                fn #field_fn_name(&mut self, value: #type_ident) -> #type_ident {
-                    #full_quote
+                #set_fns_quote
+                #[doc = #comment]
+                pub fn #field_fn_name(&mut self, #value: #type_ident) {
+                    #body
                 }
             }
+        };
+    }
+    Ok(set_fns_quote)
+}
+
+/// Returns a pattern matching `variant` which binds the fields in `bind` by their names, or to
+/// `rename` when it is given so the field can not shadow the value a setter was given.
+fn variant_pattern(
+    variant: &StructInfo,
+    bind: &[&FieldInfo],
+    rename: Option<&Ident>,
+) -> TokenStream {
+    let variant_name = &variant.name;
+    if bind.is_empty() {
+        return quote! {Self::#variant_name { .. }};
+    }
+    let binding = |name: Ident| match rename {
+        Some(rename) => rename.clone(),
+        None => name,
+    };
+    if variant.tuple {
+        let fields = variant
+            .fields
+            .iter()
+            .filter(|field| !field.attrs.reserve.is_fake_field())
+            .map(|field| {
+                let name = field.ident().ident();
+                if bind.iter().any(|b| b.ident().ident() == name) {
+                    let name = binding(name);
+                    quote! {#name}
+                } else {
+                    quote! {_}
+                }
+            });
+        quote! {Self::#variant_name(#(#fields),*)}
+    } else {
+        let fields = bind.iter().map(|field| {
+            let name = field.ident().ident();
+            match rename {
+                Some(rename) => quote! {#name: #rename},
+                None => quote! {#name},
+            }
+        });
+        quote! {Self::#variant_name { #(#fields,)* .. }}
+    }
+}
+
+/// Generates the `check_fields` function of the `Bitfields` implementation of a bitfields enum, which
+/// checks the fields of the current variant.
+pub fn create_enum_check_fields_quote(info: &EnumInfo) -> TokenStream {
+    let arms = info.variants.iter().map(|variant| {
+        let mut checks = quote! {};
+        // only the fields which need checking are bound.
+        let mut checked_fields = Vec::new();
+        for field in setter_fields(variant) {
+            let name = field.ident().ident();
+            let check = check_value_quote(field, quote! {(*#name)});
+            if !check.is_empty() {
+                checked_fields.push(field);
+            }
+            checks = quote! {
+                #checks
+                #check
+            };
         }
-        FieldDataType::ElementArray(_, _, _) => {
-            quote! {}
+        let pattern = variant_pattern(variant, &checked_fields, None);
+        quote! {
+            #pattern => {
+                #checks
+            }
         }
-        FieldDataType::BlockArray(_, _, _) => {
-            quote! {}
+    });
+    quote! {
+        fn check_fields(&self) -> Result<(), bondrewd::BitfieldTryError> {
+            match self {
+                #(#arms)*
+            }
+            Ok(())
         }
-        FieldDataType::Boolean => {
-            let field_fn_name = format_ident!("set_{}", field_name);
+    }
+}
+
+/// Generates a `set_{variant}_{field}` function for the fields of each variant of a bitfields enum,
+/// which only sets the field when `self` is that variant, and a `set_{field}` function for each
+/// common field.
+pub fn create_enum_setters_quotes(
+    info: &EnumInfo,
+    checked: bool,
+) -> Result<TokenStream, syn::Error> {
+    let common_names: Vec<_> = info
+        .common_fields
+        .iter()
+        .map(|field| field.ident().ident())
+        .collect();
+    let binding = format_ident!("field");
+    let mut set_fns_quote = quote! {};
+    for variant in info.variants.iter() {
+        let fields: Vec<&FieldInfo> = setter_fields(variant).collect();
+        let lower_name = variant.name.to_string().to_case(Case::Snake);
+        for field in fields.iter() {
+            let field_name = field.ident().ident();
+            if common_names.contains(&field_name) {
+                continue;
+            }
+            let field_fn_name = format_ident!("set_{lower_name}_{field_name}");
+            let type_ident = field.ty.type_quote();
+            let pattern = variant_pattern(variant, &[field], Some(&binding));
+            let (value, body) = setter_body(field, checked, quote! {*#binding = value;});
+            set_fns_quote = if checked {
+                let comment = format!("Sets `{field_name}` when this is a `{}`, returning whether it was. Returns an error without changing the field when the value does not fit in its bits.", variant.name);
+                quote! {
+                    #set_fns_quote
+                    #[doc = #comment]
+                    pub fn #field_fn_name(&mut self, #value: #type_ident) -> Result<bool, bondrewd::BitfieldTryError> {
+                        if let #pattern = self {
+                            #body
+                            Ok(true)
+                        } else {
+                            Ok(false)
+                        }
+                    }
+                }
+            } else {
+                let comment = format!("Sets `{field_name}` when this is a `{}`, returning whether it was. Values that do not fit in the field's bits are clamped.", variant.name);
+                quote! {
+                    #set_fns_quote
+                    #[doc = #comment]
+                    pub fn #field_fn_name(&mut self, #value: #type_ident) -> bool {
+                        if let #pattern = self {
+                            #body
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                }
+            };
+        }
+    }
+    for field in info.common_fields.iter() {
+        let field_name = field.ident().ident();
+        let field_fn_name = format_ident!("set_{field_name}");
+        let type_ident = field.ty.type_quote();
+        let variants = info.variants.iter().map(|variant| {
+            let variant_name = &variant.name;
+            quote! {Self::#variant_name { #field_name: #binding, .. }}
+        });
+        let (value, body) = setter_body(field, checked, quote! {*#binding = value;});
+        set_fns_quote = if checked {
+            let comment = format!("Sets the `{field_name}` field every variant has, returning an error without changing it when the value does not fit in the field's bits.");
             quote! {
-                fn #field_fn_name(&mut self, value: bool) {
-                    self.#field_name = value;
+                #set_fns_quote
+                #[doc = #comment]
+                pub fn #field_fn_name(&mut self, #value: #type_ident) -> Result<(), bondrewd::BitfieldTryError> {
+                    match self {
+                        #(#variants)|* => {
+                            #body
+                        }
+                    }
+                    Ok(())
                 }
             }
-        }
-    })
+        } else {
+            let comment = format!("Sets the `{field_name}` field every variant has, values that do not fit in the field's bits are clamped.");
+            quote! {
+                #set_fns_quote
+                #[doc = #comment]
+                pub fn #field_fn_name(&mut self, #value: #type_ident) {
+                    match self {
+                        #(#variants)|* => {
+                            #body
+                        }
+                    }
+                }
+            }
+        };
+    }
+    Ok(set_fns_quote)
 }
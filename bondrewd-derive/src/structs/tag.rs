@@ -0,0 +1,93 @@
+use crate::structs::common::{EnumInfo, FieldDataType, FieldInfo, StructInfo};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Returns the value of a field which holds the variant id of a `tag_from` enum, the id is taken
+/// from the enum so the two always agree. `None` if no `tag_from` field uses `field`.
+pub fn tag_value_quote(info: &StructInfo, field: &FieldInfo) -> Option<TokenStream> {
+    let field_name = field.ident().ident();
+    let payload = info.fields.iter().find(|f| {
+        f.attrs
+            .tag_from
+            .as_ref()
+            .is_some_and(|tag| tag.ident().ident() == field_name)
+    })?;
+    let payload_name = payload.ident().ident();
+    Some(tag_from_payload_quote(field, quote! {self.#payload_name}))
+}
+
+/// Returns the value of the `tag` field holding the variant id of the enum `payload`.
+pub fn tag_from_payload_quote(tag: &FieldInfo, payload: TokenStream) -> TokenStream {
+    let ty = tag.ty.type_quote();
+    match tag.ty {
+        FieldDataType::Enum(..) => quote! {#ty::from_primitive(#payload.id() as _)},
+        _ => quote! {(#payload.id() as #ty)},
+    }
+}
+
+/// Generates the functions `tag_from` fields use to store an enum without its id,
+/// `into_payload_bytes` (or `to_payload_bytes`) and `from_payload_bytes`.
+pub fn create_payload_quotes(info: &EnumInfo) -> syn::Result<TokenStream> {
+    let enum_name = &info.name;
+    let enum_size = info.total_bytes();
+    let id_type = info.id_ident()?;
+    let id_range = info.id_range();
+    let total_bits = info.total_bits();
+    let payload_bits = total_bits - info.attrs.id_bits;
+    let payload_size = (payload_bits as f64 / 8.0f64).ceil() as usize;
+    // the bits before the id keep their position, the bits after it move over the id.
+    let (id_start, id_end) = (id_range.start, id_range.end);
+    let after_id_bits = total_bits - id_end;
+    let mut remove_id = quote! {};
+    let mut insert_id = quote! {};
+    if id_start != 0 {
+        remove_id = quote! {
+            bondrewd::bits::copy_bits(&input_byte_buffer, 0, &mut output_byte_buffer, 0, #id_start);
+        };
+        insert_id = remove_id.clone();
+    }
+    if after_id_bits != 0 {
+        remove_id = quote! {
+            #remove_id
+            bondrewd::bits::copy_bits(&input_byte_buffer, #id_end, &mut output_byte_buffer, #id_start, #after_id_bits);
+        };
+        insert_id = quote! {
+            #insert_id
+            bondrewd::bits::copy_bits(&input_byte_buffer, #id_start, &mut output_byte_buffer, #id_end, #after_id_bits);
+        };
+    }
+    let size_comment = format!(
+        "Amount of Bytes a [{enum_name}] uses without the bits of its id, which is how `tag_from` fields store it."
+    );
+    let into_comment = format!(
+        "Returns the bitfield form of a [{enum_name}] without the bits of its id, which `tag_from` fields store in another field."
+    );
+    let to_comment = format!(
+        "Same as [{enum_name}::into_payload_bytes] without consuming the [{enum_name}]."
+    );
+    let from_comment = format!(
+        "Extracts a [{enum_name}] from the bitfield form [{enum_name}::into_payload_bytes] returns, using `id` as the variant id."
+    );
+    Ok(quote! {
+        #[doc = #size_comment]
+        pub const PAYLOAD_BYTE_SIZE: usize = #payload_size;
+        #[doc = #into_comment]
+        pub fn into_payload_bytes(self) -> [u8;#payload_size] {
+            self.to_payload_bytes()
+        }
+        #[doc = #to_comment]
+        pub fn to_payload_bytes(&self) -> [u8;#payload_size] {
+            let input_byte_buffer = bondrewd::Bitfields::to_bytes(self);
+            let mut output_byte_buffer = [0u8;#payload_size];
+            #remove_id
+            output_byte_buffer
+        }
+        #[doc = #from_comment]
+        pub fn from_payload_bytes(id: #id_type, input_byte_buffer: [u8;#payload_size]) -> Self {
+            let mut output_byte_buffer = [0u8;#enum_size];
+            #insert_id
+            Self::write_variant_id(&mut output_byte_buffer, id);
+            bondrewd::Bitfields::from_bytes(output_byte_buffer)
+        }
+    })
+}
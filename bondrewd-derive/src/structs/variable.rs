@@ -0,0 +1,191 @@
+use crate::structs::common::{Endianness, StructInfo, VariableArrayInfo, VariableElementType};
+use crate::structs::constant::{create_check_constants_quote, is_constant};
+use crate::structs::fill::create_fill_pattern_quote;
+use crate::structs::from_bytes::create_from_bytes_field_quotes;
+use crate::structs::into_bytes::create_into_bytes_field_quotes_struct;
+use crate::structs::length::{create_check_quotes, create_variable_fill_quotes};
+use crate::structs::tag::tag_value_quote;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Generates the `read_`/`write_` functions for the fixed size fields and an implementation of
+/// `BitfieldsVariable` for a structure containing a `count_from` array.
+pub fn create_variable_quotes(
+    info: &StructInfo,
+    variable: &VariableArrayInfo,
+) -> syn::Result<TokenStream> {
+    let struct_name = &info.name;
+    let peek_quotes = create_from_bytes_field_quotes(info, false)?.peek_field_fns;
+    let set_quotes = create_into_bytes_field_quotes_struct(info, false)?.set_field_fns;
+    let header_size = info.total_bytes();
+    let element_size = variable.element.size();
+    let max = variable.max;
+    let array_name = &variable.ident;
+    let count_name = &variable.count_from;
+    let count_ty = if let Some(field) = info
+        .fields
+        .iter()
+        .find(|f| f.ident().ident() == variable.count_from)
+    {
+        field.ty.type_quote()
+    } else {
+        return Err(syn::Error::new(
+            variable.count_from.span(),
+            "count_from field was not found",
+        ));
+    };
+
+    // the fixed size fields use the same read/write functions as a normal Bitfields structure.
+    let mut field_name_list = quote! {};
+    let mut read_header_quote = quote! {};
+    let mut write_header_quote = quote! {};
+    for field in info.fields.iter() {
+        if field.attrs.reserve.is_fake_field() {
+            continue;
+        }
+        let field_name = field.ident().ident();
+        field_name_list = quote! {#field_name_list #field_name,};
+        if is_constant(info, &field_name) {
+            read_header_quote = quote! {
+                #read_header_quote
+                let #field_name = bondrewd::Constant::new();
+            };
+        } else if field.attrs.reserve.read_field() {
+            let fn_name = format_ident!("read_{field_name}");
+            read_header_quote = quote! {
+                #read_header_quote
+                let #field_name = Self::#fn_name(&input_byte_buffer);
+            };
+        } else {
+            read_header_quote = quote! {
+                #read_header_quote
+                let #field_name = Default::default();
+            };
+        }
+        if field.attrs.reserve.write_field()
+            && field_name != variable.count_from
+            && !info.lengths.iter().any(|length| length.ident == field_name)
+        {
+            let fn_name = format_ident!("write_{field_name}");
+            // constant fields are always written with their value by their write function.
+            write_header_quote = if is_constant(info, &field_name) {
+                quote! {
+                    #write_header_quote
+                    Self::#fn_name(&mut output_byte_buffer);
+                }
+            } else {
+                let value = tag_value_quote(info, field).unwrap_or_else(|| quote! {self.#field_name});
+                quote! {
+                    #write_header_quote
+                    Self::#fn_name(&mut output_byte_buffer, #value);
+                }
+            };
+        }
+    }
+    let write_count_fn = format_ident!("write_{count_name}");
+    let check_constants_quote = create_check_constants_quote(info);
+    let constant_checks = if info.constants.is_empty() {
+        quote! {}
+    } else {
+        quote! {Self::check_constants(&input_byte_buffer)?;}
+    };
+    let fill_quote = create_fill_pattern_quote(info, &quote! {output_byte_buffer});
+    let length_checks =
+        create_check_quotes(info, element_size, &quote! {bondrewd_count});
+    let write_lengths_quote =
+        create_variable_fill_quotes(info, element_size, &quote! {bondrewd_count});
+
+    let (element_from, element_into) = match variable.element {
+        VariableElementType::Number(_, ref endianness, ref ty) => match endianness {
+            Endianness::Little => (
+                quote! {#ty::from_le_bytes(element_bytes)},
+                quote! {element.to_le_bytes()},
+            ),
+            _ => (
+                quote! {#ty::from_be_bytes(element_bytes)},
+                quote! {element.to_be_bytes()},
+            ),
+        },
+        VariableElementType::Struct(size, ref ty) => (
+            quote! {<#ty as bondrewd::Bitfields<#size>>::from_bytes(element_bytes)},
+            quote! {bondrewd::Bitfields::<#size>::into_bytes(element)},
+        ),
+    };
+
+    let from_slice_comment = format!("Reads the `{count_name}` field, then the amount of `{array_name}` elements it describes.");
+    let into_slice_comment = format!("Writes all fields into the start of `output_byte_buffer`, `{count_name}` is always written as the length of `{array_name}`.");
+    Ok(quote! {
+        impl #struct_name {
+            #peek_quotes
+            #set_quotes
+            #check_constants_quote
+        }
+        impl bondrewd::BitfieldsVariable for #struct_name {
+            const HEADER_BYTE_SIZE: usize = #header_size;
+            const ELEMENT_BYTE_SIZE: usize = #element_size;
+            const MAX_ELEMENTS: usize = #max;
+            fn byte_size(&self) -> usize {
+                #header_size + (self.#array_name.len() * #element_size)
+            }
+            #[doc = #from_slice_comment]
+            fn from_slice(input_byte_buffer: &[u8]) -> Result<Self, bondrewd::BitfieldVariableError> {
+                if input_byte_buffer.len() < #header_size {
+                    return Err(bondrewd::BitfieldLengthError(input_byte_buffer.len(), #header_size).into());
+                }
+                let (bondrewd_header, bondrewd_elements) = input_byte_buffer.split_at(#header_size);
+                let mut input_byte_buffer: [u8;#header_size] = [0u8;#header_size];
+                input_byte_buffer.copy_from_slice(bondrewd_header);
+                #constant_checks
+                #read_header_quote
+                let bondrewd_count = #count_name as usize;
+                if bondrewd_count > #max {
+                    return Err(bondrewd::BitfieldVariableError::Count(bondrewd_count, #max));
+                }
+                #length_checks
+                if bondrewd_elements.len() < bondrewd_count * #element_size {
+                    return Err(bondrewd::BitfieldLengthError(#header_size + bondrewd_elements.len(), #header_size + (bondrewd_count * #element_size)).into());
+                }
+                let #array_name = bondrewd_elements
+                    .chunks_exact(#element_size)
+                    .take(bondrewd_count)
+                    .map(|chunk| {
+                        let mut element_bytes: [u8;#element_size] = [0u8;#element_size];
+                        element_bytes.copy_from_slice(chunk);
+                        #element_from
+                    })
+                    .collect();
+                Ok(Self {
+                    #field_name_list
+                    #array_name
+                })
+            }
+            #[doc = #into_slice_comment]
+            fn into_slice(self, output_byte_buffer: &mut [u8]) -> Result<usize, bondrewd::BitfieldVariableError> {
+                let bondrewd_count = self.#array_name.len();
+                if bondrewd_count > #max {
+                    return Err(bondrewd::BitfieldVariableError::Count(bondrewd_count, #max));
+                }
+                let bondrewd_size = #header_size + (bondrewd_count * #element_size);
+                if output_byte_buffer.len() < bondrewd_size {
+                    return Err(bondrewd::BitfieldLengthError(output_byte_buffer.len(), bondrewd_size).into());
+                }
+                let (bondrewd_header, bondrewd_elements) = output_byte_buffer.split_at_mut(#header_size);
+                {
+                    let mut output_byte_buffer: [u8;#header_size] = [0u8;#header_size];
+                    #write_header_quote
+                    Self::#write_count_fn(&mut output_byte_buffer, bondrewd_count as #count_ty);
+                    #write_lengths_quote
+                    #fill_quote
+                    bondrewd_header.copy_from_slice(&output_byte_buffer);
+                }
+                for (chunk, element) in bondrewd_elements
+                    .chunks_exact_mut(#element_size)
+                    .zip(self.#array_name.into_iter())
+                {
+                    chunk.copy_from_slice(&#element_into);
+                }
+                Ok(bondrewd_size)
+            }
+        }
+    })
+}
@@ -0,0 +1,72 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::Visibility;
+
+/// Generates the `{name}View` and `{name}ViewMut` types which wrap any byte container and
+/// read/write fields directly within it.
+pub fn create_view_quotes(
+    name: &Ident,
+    vis: &Visibility,
+    struct_size: usize,
+    peek_view_fns: &TokenStream,
+    set_view_fns: &TokenStream,
+) -> TokenStream {
+    let view_ident = format_ident!("{name}View");
+    let view_mut_ident = format_ident!("{name}ViewMut");
+    let comment = format!("A view of a [{name}] in its bitfield form, which reads fields directly from a byte container `B` (ex. `[u8; {struct_size}]`, `&[u8]`, `Vec<u8>`).");
+    let comment_mut = format!("A view of a [{name}] in its bitfield form, which reads and writes fields directly within a byte container `B` (ex. `[u8; {struct_size}]`, `&mut [u8]`, `Vec<u8>`).");
+    let new_comment = format!("Wraps `buffer` if it contains at least {struct_size} bytes, otherwise a [BitfieldLengthError](bondrewd::BitfieldLengthError) is returned.");
+    let unchecked_comment = "Wraps `buffer` without checking its length. Reading or writing a field will panic if `buffer` does not contain the bytes that field uses.";
+    let into_inner_comment = "Returns the wrapped byte container.";
+    quote! {
+        #[doc = #comment]
+        #vis struct #view_ident<B: AsRef<[u8]>> {
+            buffer: B,
+        }
+        impl<B: AsRef<[u8]>> #view_ident<B> {
+            #[doc = #new_comment]
+            pub fn new(buffer: B) -> Result<Self, bondrewd::BitfieldLengthError> {
+                let buf_len = buffer.as_ref().len();
+                if buf_len >= #struct_size {
+                    Ok(Self { buffer })
+                } else {
+                    Err(bondrewd::BitfieldLengthError(buf_len, #struct_size))
+                }
+            }
+            #[doc = #unchecked_comment]
+            pub fn new_unchecked(buffer: B) -> Self {
+                Self { buffer }
+            }
+            #[doc = #into_inner_comment]
+            pub fn into_inner(self) -> B {
+                self.buffer
+            }
+            #peek_view_fns
+        }
+        #[doc = #comment_mut]
+        #vis struct #view_mut_ident<B: AsRef<[u8]> + AsMut<[u8]>> {
+            buffer: B,
+        }
+        impl<B: AsRef<[u8]> + AsMut<[u8]>> #view_mut_ident<B> {
+            #[doc = #new_comment]
+            pub fn new(buffer: B) -> Result<Self, bondrewd::BitfieldLengthError> {
+                let buf_len = buffer.as_ref().len();
+                if buf_len >= #struct_size {
+                    Ok(Self { buffer })
+                } else {
+                    Err(bondrewd::BitfieldLengthError(buf_len, #struct_size))
+                }
+            }
+            #[doc = #unchecked_comment]
+            pub fn new_unchecked(buffer: B) -> Self {
+                Self { buffer }
+            }
+            #[doc = #into_inner_comment]
+            pub fn into_inner(self) -> B {
+                self.buffer
+            }
+            #peek_view_fns
+            #set_view_fns
+        }
+    }
+}
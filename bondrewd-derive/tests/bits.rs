@@ -0,0 +1,127 @@
+use bondrewd::bits::{BitOrder, BitReader, BitWriter, Endianness};
+use bondrewd::Bitfields;
+
+macro_rules! mixed_struct {
+    ($name:ident, $($attr:tt)*) => {
+        #[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+        #[bondrewd($($attr)*)]
+        struct $name {
+            #[bondrewd(bit_length = 3)]
+            one: u8,
+            #[bondrewd(bit_length = 14)]
+            two: i16,
+            #[bondrewd(bit_length = 27)]
+            three: u32,
+            four: bool,
+            #[bondrewd(bit_length = 43)]
+            five: i64,
+            #[bondrewd(bit_length = 6)]
+            six: u16,
+        }
+    };
+}
+
+mixed_struct!(BigMsb0, default_endianness = "be");
+mixed_struct!(LittleMsb0, default_endianness = "le");
+mixed_struct!(BigLsb0, default_endianness = "be", read_from = "lsb0");
+mixed_struct!(LittleLsb0, default_endianness = "le", read_from = "lsb0");
+mixed_struct!(BigReverse, default_endianness = "be", reverse);
+mixed_struct!(LittleReverse, default_endianness = "le", reverse);
+
+/// Simple deterministic value generator so the tests cover many bit patterns.
+struct Values(u64);
+impl Values {
+    fn next(&mut self, bits: u32) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 11) & (u64::MAX >> (64 - bits))
+    }
+    fn signed(&mut self, bits: u32) -> i64 {
+        let value = self.next(bits);
+        ((value << (64 - bits)) as i64) >> (64 - bits)
+    }
+}
+
+macro_rules! check_layout {
+    ($name:ident, $endianness:expr, $order:expr, $reverse:expr) => {{
+        let mut values = Values(0x1234_5678);
+        for _ in 0..200 {
+            let value = $name {
+                one: values.next(3) as u8,
+                two: values.signed(14) as i16,
+                three: values.next(27) as u32,
+                four: values.next(1) == 1,
+                five: values.signed(43),
+                six: values.next(6) as u16,
+            };
+            let bytes = value.clone().into_bytes();
+
+            let mut written = [0u8; $name::BYTE_SIZE];
+            let mut writer = BitWriter::with_layout(&mut written, $order, $reverse)
+                .with_bit_size($name::BIT_SIZE);
+            writer.write(value.one, 3, $endianness).unwrap();
+            writer.write(value.two, 14, $endianness).unwrap();
+            writer.write(value.three, 27, $endianness).unwrap();
+            writer.write_bool(value.four).unwrap();
+            writer.write(value.five, 43, $endianness).unwrap();
+            writer.write(value.six, 6, $endianness).unwrap();
+            assert_eq!(writer.position(), $name::BIT_SIZE);
+            assert_eq!(written, bytes);
+
+            let mut reader =
+                BitReader::with_layout(&bytes, $order, $reverse).with_bit_size($name::BIT_SIZE);
+            assert_eq!(reader.read::<u8>(3, $endianness).unwrap(), value.one);
+            assert_eq!(reader.read::<i16>(14, $endianness).unwrap(), value.two);
+            assert_eq!(reader.read::<u32>(27, $endianness).unwrap(), value.three);
+            assert_eq!(reader.read_bool().unwrap(), value.four);
+            assert_eq!(reader.read::<i64>(43, $endianness).unwrap(), value.five);
+            assert_eq!(reader.read::<u16>(6, $endianness).unwrap(), value.six);
+            assert_eq!(reader.remaining(), 0);
+        }
+    }};
+}
+
+#[test]
+fn bits_match_derive_big_endian() {
+    check_layout!(BigMsb0, Endianness::Big, BitOrder::Msb0, false);
+    check_layout!(BigLsb0, Endianness::Big, BitOrder::Lsb0, false);
+    check_layout!(BigReverse, Endianness::Big, BitOrder::Msb0, true);
+}
+
+#[test]
+fn bits_match_derive_little_endian() {
+    check_layout!(LittleMsb0, Endianness::Little, BitOrder::Msb0, false);
+    check_layout!(LittleLsb0, Endianness::Little, BitOrder::Lsb0, false);
+    check_layout!(LittleReverse, Endianness::Little, BitOrder::Msb0, true);
+}
+
+#[test]
+fn bits_cursor() {
+    let mut bytes = [0b1010_1010u8, 0xFF];
+    let mut writer = BitWriter::new(&mut bytes);
+    writer.skip(4);
+    writer.write(0u8, 8, Endianness::Big).unwrap();
+    // bits outside of the written value are untouched.
+    assert_eq!(bytes, [0b1010_0000, 0b0000_1111]);
+
+    let mut writer = BitWriter::new(&mut bytes);
+    writer.seek(12);
+    match writer.write(0u8, 5, Endianness::Big) {
+        Err(bondrewd::BitfieldLengthError(2, 3)) => {}
+        other => panic!("expected length error, got {other:?}"),
+    }
+
+    let reader = BitReader::new(&bytes);
+    assert_eq!(reader.peek_at::<u8>(0, 4, Endianness::Big).unwrap(), 0b1010);
+    assert_eq!(reader.peek_at::<i8>(0, 4, Endianness::Big).unwrap(), -6);
+    assert_eq!(reader.peek_at::<u8>(12, 4, Endianness::Big).unwrap(), 0b1111);
+    assert!(reader.peek_at::<u8>(12, 5, Endianness::Big).is_err());
+    // a position near `usize::MAX` is past the end instead of overflowing.
+    let mut reader = BitReader::new(&bytes);
+    reader.seek(usize::MAX - 2);
+    assert!(reader.read::<u8>(8, Endianness::Big).is_err());
+    reader.skip(8);
+    assert_eq!(reader.remaining(), 0);
+}
@@ -0,0 +1,223 @@
+use bondrewd::checksum::{CRC16_ARC, CRC16_CCITT_FALSE, CRC16_XMODEM, CRC32, CRC32C, CRC8};
+use bondrewd::Bitfields;
+
+#[test]
+fn crc_check_values() {
+    let check = b"123456789";
+    assert_eq!(CRC8.checksum(check), 0xF4);
+    assert_eq!(CRC16_CCITT_FALSE.checksum(check), 0x29B1);
+    assert_eq!(CRC16_XMODEM.checksum(check), 0x31C3);
+    assert_eq!(CRC16_ARC.checksum(check), 0xBB3D);
+    assert_eq!(CRC32.checksum(check), 0xCBF4_3926);
+    assert_eq!(CRC32C.checksum(check), 0xE306_9283);
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Frame {
+    #[bondrewd(bit_length = 3)]
+    version: u8,
+    #[bondrewd(bit_length = 13)]
+    id: u16,
+    sequence: u16,
+    length: u16,
+    #[bondrewd(crc = "crc16_ccitt_false", covers = "0..6")]
+    crc: u16,
+}
+
+#[test]
+fn crc_filled_by_into_bytes() {
+    let frame = Frame {
+        version: 1,
+        id: 0x123,
+        sequence: 0xC001,
+        length: 42,
+        // ignored, into_bytes computes it.
+        crc: 0,
+    };
+    let bytes = frame.clone().into_bytes();
+    let expected = CRC16_CCITT_FALSE.checksum(&bytes[0..6]) as u16;
+    assert_eq!(Frame::read_crc(&bytes), expected);
+    assert_eq!(bytes[6..8], expected.to_be_bytes());
+    assert!(Frame::verify_crc(&bytes));
+    assert_eq!(frame.to_bytes(), bytes);
+
+    let mut corrupted = bytes;
+    corrupted[3] ^= 0b0001_0000;
+    assert!(!Frame::verify_crc(&corrupted));
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le")]
+struct Trailer {
+    kind: u8,
+    value: u32,
+    #[bondrewd(crc = "crc32", covers = "kind..value")]
+    crc: u32,
+}
+
+#[test]
+fn crc_field_range() {
+    let trailer = Trailer {
+        kind: 7,
+        value: 0xDEAD_BEEF,
+        crc: 0,
+    };
+    let bytes = trailer.into_bytes();
+    assert_eq!(
+        Trailer::read_crc(&bytes),
+        CRC32.checksum(&bytes[0..5]) as u32
+    );
+    assert!(Trailer::verify_crc(&bytes));
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Flags {
+    #[bondrewd(bit_length = 3)]
+    one: u8,
+    #[bondrewd(bit_length = 6)]
+    two: u8,
+    #[bondrewd(parity = "even", covers = "one..two")]
+    even: bool,
+    #[bondrewd(parity = "odd", covers = "one..=two")]
+    odd: bool,
+    #[bondrewd(bit_length = 6)]
+    three: u8,
+}
+
+#[test]
+fn parity_filled_by_into_bytes() {
+    for (one, two, three) in [
+        (0, 0, 0),
+        (0b101, 0b110011, 31),
+        (0b001, 0b000001, 1),
+        (7, 63, 0),
+    ] {
+        let flags = Flags {
+            one,
+            two,
+            even: false,
+            odd: false,
+            three,
+        };
+        let covered_ones = one.count_ones() + two.count_ones();
+        let bytes = flags.into_bytes();
+        assert_eq!(Flags::read_even(&bytes), covered_ones % 2 == 1);
+        assert_eq!(Flags::read_odd(&bytes), covered_ones % 2 == 0);
+        assert!(Flags::verify_even(&bytes));
+        assert!(Flags::verify_odd(&bytes));
+        let mut corrupted = bytes;
+        corrupted[0] ^= 0b0010_0000;
+        assert!(!Flags::verify_even(&corrupted));
+        assert!(!Flags::verify_odd(&corrupted));
+    }
+}
+
+macro_rules! layout_frame {
+    ($name:ident, $($attr:tt)*) => {
+        #[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+        #[bondrewd($($attr)*)]
+        struct $name {
+            #[bondrewd(bit_length = 4)]
+            one: u8,
+            #[bondrewd(bit_length = 11)]
+            two: u16,
+            #[bondrewd(parity = "odd", covers = "one..two")]
+            parity: bool,
+            three: u16,
+            #[bondrewd(crc = "crc8", covers = "one..three")]
+            crc: u8,
+        }
+    };
+}
+
+layout_frame!(LayoutMsb0, default_endianness = "be");
+layout_frame!(LayoutLsb0, default_endianness = "be", read_from = "lsb0");
+layout_frame!(LayoutReverse, default_endianness = "be", reverse);
+
+macro_rules! check_layout {
+    ($name:ident, $crc_bytes:expr) => {{
+        let value = $name {
+            one: 0b1011,
+            two: 0b101_0000_0001,
+            parity: false,
+            three: 0xBEEF,
+            crc: 0,
+        };
+        let bytes = value.into_bytes();
+        assert!($name::verify_parity(&bytes));
+        assert!($name::verify_crc(&bytes));
+        assert_eq!(
+            $name::read_crc(&bytes),
+            CRC8.checksum(&bytes[$crc_bytes]) as u8
+        );
+        let covered_ones = 0b1011u8.count_ones() + 0b101_0000_0001u16.count_ones();
+        assert_eq!($name::read_parity(&bytes), covered_ones % 2 == 0);
+        let mut corrupted = bytes;
+        corrupted[$crc_bytes][0] ^= 1;
+        assert!(!$name::verify_crc(&corrupted));
+    }};
+}
+
+#[test]
+fn checksums_follow_layout() {
+    check_layout!(LayoutMsb0, 0..4);
+    check_layout!(LayoutLsb0, 1..5);
+    check_layout!(LayoutReverse, 1..5);
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Chained {
+    #[bondrewd(crc = "crc8", covers = "1..3")]
+    crc: u8,
+    value: u8,
+    #[bondrewd(bit_length = 7)]
+    flags: u8,
+    #[bondrewd(parity = "even", covers = "value..flags")]
+    parity: bool,
+}
+
+#[test]
+fn checksum_covering_later_checksum() {
+    let chained = Chained {
+        crc: 0,
+        value: 0x5A,
+        flags: 0b100,
+        parity: false,
+    };
+    let bytes = chained.into_bytes();
+    // the parity bit is filled in before the crc which covers it.
+    assert!(Chained::read_parity(&bytes));
+    assert!(Chained::verify_parity(&bytes));
+    assert!(Chained::verify_crc(&bytes));
+    assert_eq!(Chained::read_crc(&bytes), CRC8.checksum(&bytes[1..3]) as u8);
+}
+
+#[test]
+fn update_checksums_after_write() {
+    let mut bytes = Chained {
+        crc: 0,
+        value: 0x5A,
+        flags: 0b101,
+        parity: false,
+    }
+    .into_bytes();
+    Chained::write_value(&mut bytes, 0x5B);
+    assert!(!Chained::verify_parity(&bytes));
+    assert!(!Chained::verify_crc(&bytes));
+    Chained::update_checksums(&mut bytes);
+    assert!(Chained::verify_parity(&bytes));
+    assert!(Chained::verify_crc(&bytes));
+    assert_eq!(
+        bytes,
+        Chained {
+            crc: 0,
+            value: 0x5B,
+            flags: 0b101,
+            parity: false,
+        }
+        .into_bytes()
+    );
+}
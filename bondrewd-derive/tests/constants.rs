@@ -0,0 +1,99 @@
+use bondrewd::{
+    BitfieldTryErrorKind, BitfieldVariableError, Bitfields, BitfieldsVariable, Constant,
+    TryBitfields,
+};
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Frame {
+    #[bondrewd(constant = 0x1ACFFC1D)]
+    sync: Constant<u32>,
+    #[bondrewd(bit_length = 2, constant = 0b01)]
+    version: Constant<u8>,
+    #[bondrewd(constant = true)]
+    must_be_one: Constant<bool>,
+    #[bondrewd(bit_length = 5)]
+    kind: u8,
+}
+
+#[test]
+fn constants_always_written() -> anyhow::Result<()> {
+    let frame = Frame {
+        sync: Constant::new(),
+        version: Constant::new(),
+        must_be_one: Constant::new(),
+        kind: 0b10101,
+    };
+    let bytes = frame.clone().into_bytes();
+    assert_eq!(bytes, [0x1A, 0xCF, 0xFC, 0x1D, 0b01_1_10101]);
+    assert_eq!(frame.to_bytes(), bytes);
+    Frame::check_constants(&bytes)?;
+
+    let decoded = Frame::try_from_bytes(bytes)?;
+    assert_eq!(decoded, frame);
+    assert_eq!(Frame::read_sync(&bytes), 0x1ACFFC1D);
+    assert_eq!(Frame::read_version(&bytes), 1);
+    assert!(Frame::read_must_be_one(&bytes));
+
+    // the write functions of constant fields take no value.
+    let mut bytes = [0u8; 5];
+    Frame::write_sync(&mut bytes);
+    Frame::write_version(&mut bytes);
+    Frame::write_must_be_one(&mut bytes);
+    Frame::write_kind(&mut bytes, 0b10101);
+    assert_eq!(bytes, frame.into_bytes());
+    Ok(())
+}
+
+#[test]
+fn constants_checked() {
+    let mut bytes = [0x1A, 0xCF, 0xFC, 0x1D, 0b01_0_10101];
+    let err = Frame::check_constants(&bytes).unwrap_err();
+    assert_eq!(err.field, "must_be_one");
+    assert_eq!(err.bit_range, 34..35);
+    assert_eq!(err.kind, BitfieldTryErrorKind::ConstantMismatch(0, 1));
+
+    bytes[1] = 0;
+    let err = Frame::try_from_bytes(bytes).unwrap_err();
+    assert_eq!(err.field, "sync");
+    assert_eq!(
+        err.kind,
+        BitfieldTryErrorKind::ConstantMismatch(0x1A00FC1D, 0x1ACFFC1D)
+    );
+    // the infallible api ignores the constant fields.
+    assert_eq!(Frame::from_bytes(bytes).kind, 0b10101);
+    assert_eq!(Frame::read_sync(&bytes), 0x1A00FC1D);
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le", read_from = "lsb0")]
+struct Marked {
+    #[bondrewd(bit_length = 4, constant = 0xA)]
+    marker: Constant<u8>,
+    #[bondrewd(bit_length = 4)]
+    count: u8,
+    #[bondrewd(count_from = "count", max = 4)]
+    data: Vec<u8>,
+}
+
+#[test]
+fn constants_in_variable_structures() -> anyhow::Result<()> {
+    let marked = Marked {
+        marker: Constant::new(),
+        count: 2,
+        data: vec![7, 8],
+    };
+    let bytes = marked.clone().into_vec()?;
+    assert_eq!(bytes, vec![0x2A, 7, 8]);
+    Marked::check_constants(&[bytes[0]])?;
+    assert!(Marked::check_constants(&[0x2B]).is_err());
+    assert_eq!(Marked::from_slice(&bytes)?, marked);
+    match Marked::from_slice(&[0x2B, 7, 8]) {
+        Err(BitfieldVariableError::Invalid(err)) => {
+            assert_eq!(err.field, "marker");
+            assert_eq!(err.kind, BitfieldTryErrorKind::ConstantMismatch(0xB, 0xA));
+        }
+        other => panic!("expected a constant mismatch, found {other:?}"),
+    }
+    Ok(())
+}
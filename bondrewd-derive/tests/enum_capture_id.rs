@@ -0,0 +1,145 @@
+use bondrewd::{BitfieldTryErrorKind, Bitfields, TryBitfields};
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 8)]
+enum Packet {
+    #[bondrewd(variant_id = 1)]
+    Ping,
+    #[bondrewd(variant_id = "0x80..=0xFE")]
+    User {
+        #[bondrewd(capture_id)]
+        command: u8,
+        value: u8,
+    },
+    #[bondrewd(invalid)]
+    Unknown(#[bondrewd(capture_id)] u8, u8),
+}
+
+#[test]
+fn capture_id_in_any_variant() -> anyhow::Result<()> {
+    let user = Packet::from_bytes([0x93, 7]);
+    assert_eq!(
+        user,
+        Packet::User {
+            command: 0x93,
+            value: 7
+        }
+    );
+    assert_eq!(user.id(), 0x93);
+    assert_eq!(user.clone().try_into_bytes()?, [0x93, 7]);
+    assert_eq!(user.into_bytes(), [0x93, 7]);
+    assert_eq!(Packet::Ping.try_into_bytes()?, [1, 0]);
+    Ok(())
+}
+
+#[test]
+fn try_into_bytes_rejects_foreign_ids() -> anyhow::Result<()> {
+    let err = Packet::User {
+        command: 0x10,
+        value: 0,
+    }
+    .try_into_bytes()
+    .unwrap_err();
+    assert_eq!(err.field, "variant_id");
+    assert_eq!(err.bit_range, 0..8);
+    assert_eq!(err.kind, BitfieldTryErrorKind::CapturedIdMismatch(0x10));
+    assert!(Packet::User {
+        command: 0xFF,
+        value: 0
+    }
+    .try_into_bytes()
+    .is_err());
+
+    // the invalid variant may capture any id the other variants do not use.
+    assert_eq!(Packet::Unknown(0xFF, 3).try_into_bytes()?, [0xFF, 3]);
+    assert_eq!(Packet::Unknown(0, 3).try_into_bytes()?, [0, 3]);
+    for id in [1, 0x80, 0xFE] {
+        let err = Packet::Unknown(id, 3).try_into_bytes().unwrap_err();
+        assert_eq!(
+            err.kind,
+            BitfieldTryErrorKind::CapturedIdMismatch(id as u128)
+        );
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "captured id of `User` does not belong to the variant")]
+fn into_bytes_asserts_captured_ids() {
+    Packet::User {
+        command: 0x10,
+        value: 0,
+    }
+    .into_bytes();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "captured id of `Unknown` does not belong to the variant")]
+fn write_to_asserts_captured_ids() {
+    Packet::Unknown(1, 3).to_bytes();
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 3)]
+enum Small {
+    #[bondrewd(variant_id = "0..=2")]
+    Low {
+        #[bondrewd(capture_id)]
+        id: u8,
+        #[bondrewd(bit_length = 5)]
+        value: u8,
+    },
+    High {
+        #[bondrewd(capture_id)]
+        id: u8,
+        #[bondrewd(bit_length = 5)]
+        value: u8,
+    },
+}
+
+#[test]
+fn catch_all_id_must_fit() -> anyhow::Result<()> {
+    assert_eq!(
+        Small::High { id: 7, value: 1 }.try_into_bytes()?,
+        [0b111_00001]
+    );
+    let err = Small::High { id: 8, value: 1 }
+        .try_into_bytes()
+        .unwrap_err();
+    assert_eq!(err.kind, BitfieldTryErrorKind::CapturedIdMismatch(8));
+    let err = Small::High { id: 2, value: 1 }
+        .try_into_bytes()
+        .unwrap_err();
+    assert_eq!(err.kind, BitfieldTryErrorKind::CapturedIdMismatch(2));
+    assert_eq!(
+        Small::Low { id: 2, value: 1 }.try_into_bytes()?,
+        [0b010_00001]
+    );
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 4)]
+enum Plain {
+    One {
+        #[bondrewd(bit_length = 4)]
+        value: u8,
+    },
+    Two,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Header {
+    kind: u8,
+}
+
+#[test]
+fn try_into_bytes_without_capture_id() -> anyhow::Result<()> {
+    assert_eq!(Plain::One { value: 5 }.try_into_bytes()?, [0x05]);
+    assert_eq!(Plain::Two.try_into_bytes()?, [0x10]);
+    assert_eq!(Header { kind: 7 }.try_into_bytes()?, [7]);
+    Ok(())
+}
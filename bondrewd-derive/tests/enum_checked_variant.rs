@@ -0,0 +1,61 @@
+use bondrewd::Bitfields;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 4)]
+enum Command {
+    #[bondrewd(variant_id = 1)]
+    Move {
+        #[bondrewd(bit_length = 12)]
+        x: u16,
+        y: u8,
+    },
+    #[bondrewd(variant_id = 2)]
+    Wait(#[bondrewd(bit_length = 4)] u8),
+    #[bondrewd(invalid)]
+    Unknown {
+        #[bondrewd(capture_id)]
+        id: u8,
+    },
+}
+
+#[test]
+fn checked_variant() {
+    let bytes = Command::Move { x: 0xABC, y: 0x12 }.into_bytes();
+    let checked = Command::check_slice(&bytes).unwrap();
+    assert_eq!(checked.read_variant_id(), 1);
+    match checked.variant() {
+        CommandCheckedVariant::Move(view) => {
+            assert_eq!(view.read_x(), 0xABC);
+            assert_eq!(view.read_y(), 0x12);
+        }
+        _ => panic!("expected Move"),
+    }
+
+    let bytes = Command::Wait(9).into_bytes();
+    let checked = Command::check_slice(&bytes).unwrap();
+    match checked.variant() {
+        CommandCheckedVariant::Wait(view) => assert_eq!(view.read_field_1(), 9),
+        _ => panic!("expected Wait"),
+    }
+
+    let bytes = [0xE0, 0x00, 0x00];
+    let checked = Command::check_slice(&bytes).unwrap();
+    match checked.variant() {
+        CommandCheckedVariant::Unknown(view) => assert_eq!(view.read_id(), 0xE),
+        _ => panic!("expected Unknown"),
+    }
+}
+
+#[test]
+fn checked_variant_longer_slice() {
+    let mut bytes = Command::Move { x: 1, y: 2 }.into_bytes().to_vec();
+    bytes.extend_from_slice(&[0xFF, 0xFF]);
+    let checked = Command::check_slice(&bytes).unwrap();
+    if let CommandCheckedVariant::Move(view) = checked.variant() {
+        assert_eq!(view.read_x(), 1);
+        assert_eq!(view.read_y(), 2);
+    } else {
+        panic!("expected Move");
+    }
+    assert!(Command::check_slice(&bytes[..2]).is_err());
+}
@@ -0,0 +1,78 @@
+use bondrewd::Bitfields;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 4)]
+enum Packet {
+    #[bondrewd(variant_id = 1)]
+    Telemetry {
+        #[bondrewd(common, bit_length = 12)]
+        sequence: u16,
+        value: u8,
+    },
+    // common fields are moved to the same bits in every variant, wherever they are declared.
+    #[bondrewd(variant_id = 2)]
+    Command { opcode: u8, sequence: u16 },
+    #[bondrewd(invalid)]
+    Unknown {
+        #[bondrewd(capture_id)]
+        id: u8,
+        sequence: u16,
+    },
+}
+
+#[test]
+fn common_layout() {
+    assert_eq!(Packet::BYTE_SIZE, 3);
+    let telemetry = Packet::Telemetry {
+        sequence: 0xABC,
+        value: 0x12,
+    };
+    let bytes = telemetry.clone().into_bytes();
+    assert_eq!(bytes, [0x1A, 0xBC, 0x12]);
+    assert_eq!(Packet::from_bytes(bytes), telemetry);
+
+    let command = Packet::Command {
+        opcode: 0x34,
+        sequence: 0x123,
+    };
+    let bytes = command.clone().into_bytes();
+    assert_eq!(bytes, [0x21, 0x23, 0x34]);
+    assert_eq!(Packet::from_bytes(bytes), command);
+
+    assert_eq!(
+        Packet::from_bytes([0xF4, 0x56, 0x00]),
+        Packet::Unknown {
+            id: 0xF,
+            sequence: 0x456
+        }
+    );
+}
+
+#[test]
+fn common_accessors() {
+    let command = Packet::Command {
+        opcode: 0x34,
+        sequence: 0x123,
+    };
+    assert_eq!(command.sequence(), 0x123);
+    let mut bytes = command.into_bytes();
+    assert_eq!(Packet::read_sequence(&bytes), 0x123);
+    Packet::write_sequence(&mut bytes, 0xFED);
+    assert_eq!(bytes, [0x2F, 0xED, 0x34]);
+    assert_eq!(Packet::read_command_sequence(&bytes), 0xFED);
+    assert_eq!(Packet::from_bytes(bytes).sequence(), 0xFED);
+
+    let mut bytes = [0x1A, 0xBC, 0x12];
+    let checked = Packet::check_slice(&bytes).unwrap();
+    assert_eq!(checked.read_sequence(), 0xABC);
+    let mut checked = Packet::check_slice_mut(&mut bytes).unwrap();
+    checked.write_sequence(1);
+    assert_eq!(bytes, [0x10, 0x01, 0x12]);
+    assert_eq!(
+        Packet::from_bytes(bytes),
+        Packet::Telemetry {
+            sequence: 1,
+            value: 0x12
+        }
+    );
+}
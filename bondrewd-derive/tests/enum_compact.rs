@@ -0,0 +1,90 @@
+use bondrewd::{BitfieldLengthError, Bitfields};
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 8)]
+enum Downlink {
+    #[bondrewd(variant_id = 1)]
+    Ping,
+    #[bondrewd(variant_id = 2)]
+    Short { value: u8 },
+    #[bondrewd(variant_id = 3)]
+    Long { value: u32 },
+}
+
+#[test]
+fn into_bytes_compact() {
+    assert_eq!(Downlink::BYTE_SIZE, 5);
+    assert_eq!(Downlink::Ping.into_bytes_compact(), (1, [1, 0, 0, 0, 0]));
+    assert_eq!(
+        Downlink::Short { value: 7 }.into_bytes_compact(),
+        (2, [2, 7, 0, 0, 0])
+    );
+    assert_eq!(
+        Downlink::Long { value: 0x0102_0304 }.into_bytes_compact(),
+        (5, [3, 1, 2, 3, 4])
+    );
+}
+
+#[test]
+fn from_slice_compact() {
+    for value in [
+        Downlink::Ping,
+        Downlink::Short { value: 0xAB },
+        Downlink::Long { value: 0xDEAD_BEEF },
+    ] {
+        let (size, bytes) = value.clone().into_bytes_compact();
+        assert_eq!(Downlink::from_slice_compact(&bytes[..size]).unwrap(), value);
+    }
+    // bytes after the variant are ignored.
+    assert_eq!(
+        Downlink::from_slice_compact(&[2, 7, 0xFF, 0xFF]).unwrap(),
+        Downlink::Short { value: 7 }
+    );
+    assert!(matches!(
+        Downlink::from_slice_compact(&[]),
+        Err(BitfieldLengthError(0, 1))
+    ));
+    assert!(matches!(
+        Downlink::from_slice_compact(&[2]),
+        Err(BitfieldLengthError(1, 2))
+    ));
+    assert!(matches!(
+        Downlink::from_slice_compact(&[3, 1, 2]),
+        Err(BitfieldLengthError(3, 5))
+    ));
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 4, id_bits = "8..12")]
+enum MiddleId {
+    #[bondrewd(variant_id = 1)]
+    Small { value: u8 },
+    #[bondrewd(variant_id = 2)]
+    Large {
+        value: u8,
+        #[bondrewd(bit_length = 20)]
+        extra: u32,
+    },
+}
+
+#[test]
+fn compact_id_not_leading() {
+    // every byte up to the end of the id is needed to find the variant.
+    let small = MiddleId::Small { value: 0x55 };
+    let (size, bytes) = small.clone().into_bytes_compact();
+    assert_eq!(size, 2);
+    assert_eq!(bytes[..size], [0x55, 0x10]);
+    assert_eq!(MiddleId::from_slice_compact(&bytes[..size]).unwrap(), small);
+    assert!(matches!(
+        MiddleId::from_slice_compact(&bytes[..1]),
+        Err(BitfieldLengthError(1, 2))
+    ));
+
+    let large = MiddleId::Large {
+        value: 0x55,
+        extra: 0xABCDE,
+    };
+    let (size, bytes) = large.clone().into_bytes_compact();
+    assert_eq!(size, 4);
+    assert_eq!(MiddleId::from_slice_compact(&bytes[..size]).unwrap(), large);
+}
@@ -0,0 +1,70 @@
+use bondrewd::Bitfields;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+enum Mode {
+    Off,
+    Idle,
+    Active,
+}
+
+#[test]
+fn infer_from_assigned_ids() {
+    assert_eq!(Mode::BIT_SIZE, 2);
+    assert_eq!(Mode::BYTE_SIZE, 1);
+    assert_eq!(Mode::Active.into_bytes(), [0b1000_0000]);
+    assert_eq!(Mode::from_bytes([0b0100_0000]), Mode::Idle);
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+enum Opcode {
+    #[bondrewd(variant_id = 0x1F)]
+    Reset {
+        level: u8,
+    },
+    #[bondrewd(variant_id = "4..=7")]
+    Jump {
+        #[bondrewd(capture_id)]
+        id: u8,
+    },
+    Nop,
+}
+
+#[test]
+fn infer_from_largest_id() {
+    // 0x1F needs 5 bits, which is followed by the largest variant.
+    assert_eq!(Opcode::BIT_SIZE, 13);
+    assert_eq!(Opcode::Reset { level: 0xFF }.into_bytes(), [0xFF, 0xF8]);
+    assert_eq!(Opcode::Nop.into_bytes(), [0, 0]);
+    assert_eq!(Opcode::from_bytes([0b00110_000, 0]), Opcode::Jump { id: 6 });
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+#[repr(u16)]
+enum Wide {
+    One { value: u8 } = 1,
+    Two = 2,
+}
+
+#[test]
+fn repr_sets_default_id_size() {
+    assert_eq!(Wide::BIT_SIZE, 24);
+    assert_eq!(Wide::One { value: 9 }.into_bytes(), [0, 1, 9]);
+    assert_eq!(Wide::from_bytes([0, 2, 0]), Wide::Two);
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 3)]
+#[repr(u8)]
+enum Explicit {
+    One = 1,
+    Two = 2,
+}
+
+#[test]
+fn explicit_size_wins_over_repr() {
+    assert_eq!(Explicit::BIT_SIZE, 3);
+    assert_eq!(Explicit::Two.into_bytes(), [0b0100_0000]);
+}
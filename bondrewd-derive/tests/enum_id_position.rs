@@ -0,0 +1,130 @@
+use bondrewd::Bitfields;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 4, id_tail)]
+enum Tail {
+    #[bondrewd(variant_id = 1)]
+    One {
+        #[bondrewd(bit_length = 4)]
+        a: u8,
+    },
+    #[bondrewd(variant_id = 2)]
+    Two {
+        #[bondrewd(bit_length = 12)]
+        b: u16,
+    },
+    #[bondrewd(variant_id = 3)]
+    Empty,
+}
+
+#[test]
+fn id_tail() {
+    assert_eq!(Tail::BYTE_SIZE, 2);
+    let one = Tail::One { a: 0xA };
+    let bytes = one.clone().into_bytes();
+    assert_eq!(bytes, [0xA0, 0x01]);
+    assert_eq!(Tail::read_variant_id(&bytes), 1);
+    assert_eq!(Tail::from_bytes(bytes), one);
+
+    let two = Tail::Two { b: 0xBCD };
+    let bytes = two.clone().into_bytes();
+    assert_eq!(bytes, [0xBC, 0xD2]);
+    assert_eq!(Tail::from_bytes(bytes), two);
+
+    assert_eq!(Tail::Empty.into_bytes(), [0x00, 0x03]);
+    assert_eq!(Tail::from_bytes([0x00, 0x03]), Tail::Empty);
+}
+
+/// a header with the type code in the middle.
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bits = "4..8")]
+enum Header {
+    #[bondrewd(variant_id = 1)]
+    Command {
+        #[bondrewd(bit_length = 4)]
+        version: u8,
+        opcode: u8,
+    },
+    #[bondrewd(variant_id = 2)]
+    Telemetry {
+        #[bondrewd(bit_length = 4)]
+        version: u8,
+        #[bondrewd(bit_length = 8)]
+        value: u8,
+    },
+    #[bondrewd(variant_id = 3)]
+    Ping,
+    #[bondrewd(invalid)]
+    Unknown {
+        #[bondrewd(capture_id)]
+        id: u8,
+    },
+}
+
+#[test]
+fn id_bits_range() {
+    assert_eq!(Header::BYTE_SIZE, 2);
+    let command = Header::Command {
+        version: 0xA,
+        opcode: 0x5C,
+    };
+    let bytes = command.clone().into_bytes();
+    assert_eq!(bytes, [0xA1, 0x5C]);
+    assert_eq!(Header::read_variant_id(&bytes), 1);
+    assert_eq!(Header::from_bytes(bytes), command);
+
+    let telemetry = Header::Telemetry {
+        version: 0x3,
+        value: 0xFE,
+    };
+    let bytes = telemetry.clone().into_bytes();
+    assert_eq!(bytes, [0x32, 0xFE]);
+    assert_eq!(Header::from_bytes(bytes), telemetry);
+    assert_eq!(Header::read_telemetry_value(&bytes), 0xFE);
+
+    // fill is placed on both sides of the id when no fields come after it.
+    assert_eq!(Header::Ping.into_bytes(), [0x03, 0x00]);
+    assert_eq!(Header::from_bytes([0xF3, 0xFF]), Header::Ping);
+
+    let unknown = Header::from_bytes([0x09, 0x00]);
+    assert_eq!(unknown, Header::Unknown { id: 9 });
+    assert_eq!(unknown.into_bytes(), [0x09, 0x00]);
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(
+    default_endianness = "be",
+    id_bits = "4..8",
+    fill_position = "after_id"
+)]
+enum AfterId {
+    #[bondrewd(variant_id = 1)]
+    Short {
+        #[bondrewd(bit_length = 4)]
+        flags: u8,
+        #[bondrewd(bit_length = 4)]
+        a: u8,
+    },
+    #[bondrewd(variant_id = 2)]
+    Long {
+        #[bondrewd(bit_length = 4)]
+        flags: u8,
+        #[bondrewd(bit_length = 12)]
+        b: u16,
+    },
+}
+
+#[test]
+fn id_bits_range_fill_after_id() {
+    let short = AfterId::Short { flags: 0xF, a: 0x7 };
+    let bytes = short.clone().into_bytes();
+    assert_eq!(bytes, [0xF1, 0x00, 0x70]);
+    assert_eq!(AfterId::from_bytes(bytes), short);
+    let long = AfterId::Long {
+        flags: 0x1,
+        b: 0xABC,
+    };
+    let bytes = long.clone().into_bytes();
+    assert_eq!(bytes, [0x12, 0xAB, 0xC0]);
+    assert_eq!(AfterId::from_bytes(bytes), long);
+}
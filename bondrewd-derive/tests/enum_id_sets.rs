@@ -0,0 +1,86 @@
+use bondrewd::{BitfieldTryErrorKind, Bitfields, TryBitfields};
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 8)]
+enum Message {
+    #[bondrewd(variant_id = 1)]
+    Ping,
+    #[bondrewd(variant_id = "3, 7, 9")]
+    Reserved,
+    #[bondrewd(variant_id = "0x10..=0x1F")]
+    Vendor {
+        #[bondrewd(capture_id)]
+        id: u8,
+        value: u8,
+    },
+    #[bondrewd(variant_id = "0x20..0x30, 0x40")]
+    Extension {
+        value: u8,
+    },
+    // gets the first id no other variant uses.
+    Other,
+}
+
+#[test]
+fn id_sets() -> anyhow::Result<()> {
+    assert_eq!(Message::Other.id(), 0);
+    for id in [3, 7, 9] {
+        assert_eq!(Message::from_bytes([id, 0]), Message::Reserved);
+    }
+    // variants without capture_id are written with their first id.
+    assert_eq!(Message::Reserved.into_bytes(), [3, 0]);
+    assert_eq!(Message::Reserved.id(), 3);
+
+    for id in 0x10..=0x1F {
+        let vendor = Message::from_bytes([id, 0xAB]);
+        assert_eq!(vendor, Message::Vendor { id, value: 0xAB });
+        assert_eq!(vendor.id(), id);
+        assert_eq!(vendor.into_bytes(), [id, 0xAB]);
+    }
+
+    assert_eq!(
+        Message::from_bytes([0x2F, 5]),
+        Message::Extension { value: 5 }
+    );
+    assert_eq!(
+        Message::from_bytes([0x40, 6]),
+        Message::Extension { value: 6 }
+    );
+    assert_eq!(Message::Extension { value: 6 }.into_bytes(), [0x20, 6]);
+
+    // the last variant catches unknown ids, unless decoding strictly.
+    assert_eq!(Message::from_bytes([0x30, 0]), Message::Other);
+    assert_eq!(Message::try_from_bytes([0x0, 0])?, Message::Other);
+    let err = Message::try_from_bytes([0x30, 0]).unwrap_err();
+    assert_eq!(err.kind, BitfieldTryErrorKind::InvalidVariant(0x30));
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 4)]
+enum Command {
+    #[bondrewd(variant_id = "0..=3")]
+    Read {
+        #[bondrewd(capture_id)]
+        bank: u8,
+    },
+    #[bondrewd(variant_id = "4..8")]
+    Write {
+        #[bondrewd(capture_id)]
+        bank: u8,
+    },
+    #[bondrewd(invalid)]
+    Unknown {
+        #[bondrewd(capture_id)]
+        id: u8,
+    },
+}
+
+#[test]
+fn capture_id_ranges() {
+    assert_eq!(Command::BIT_SIZE, 4);
+    assert_eq!(Command::from_bytes([0x20]), Command::Read { bank: 2 });
+    assert_eq!(Command::from_bytes([0x50]), Command::Write { bank: 5 });
+    assert_eq!(Command::from_bytes([0xC0]), Command::Unknown { id: 12 });
+    assert_eq!(Command::Write { bank: 6 }.into_bytes(), [0x60]);
+}
@@ -0,0 +1,57 @@
+use bondrewd::Bitfields;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 8)]
+enum Message {
+    #[bondrewd(variant_id = 1)]
+    Ping,
+    #[bondrewd(variant_id = 2)]
+    Data {
+        length: u8,
+    },
+    #[bondrewd(variant_id = "0x10..=0x1F")]
+    Vendor(#[bondrewd(capture_id)] u8, u8),
+    Status(u8),
+    #[bondrewd(invalid)]
+    Unknown {
+        #[bondrewd(capture_id)]
+        id: u8,
+    },
+}
+
+#[test]
+fn kind_ids() {
+    assert_eq!(MessageKind::Ping.id(), 1);
+    assert_eq!(MessageKind::Data.id(), 2);
+    assert_eq!(MessageKind::Vendor.id(), 0x10);
+    // unassigned ids are guessed the same way as for the enum itself.
+    assert_eq!(MessageKind::Status.id(), 0);
+    assert_eq!(MessageKind::Unknown.id(), 3);
+    assert_eq!(Message::Status(7).id(), MessageKind::Status.id());
+}
+
+#[test]
+fn read_kind() {
+    assert_eq!(Message::read_kind(&[1, 0]), MessageKind::Ping);
+    assert_eq!(Message::read_kind(&[2, 9]), MessageKind::Data);
+    assert_eq!(Message::read_kind(&[0x1A, 9]), MessageKind::Vendor);
+    assert_eq!(Message::read_kind(&[0, 9]), MessageKind::Status);
+    assert_eq!(Message::read_kind(&[0xEE, 9]), MessageKind::Unknown);
+    let bytes = Message::Data { length: 4 }.into_bytes();
+    assert_eq!(Message::read_kind(&bytes), MessageKind::Data);
+}
+
+#[test]
+fn kind() {
+    assert_eq!(Message::Ping.kind(), MessageKind::Ping);
+    assert_eq!(Message::Data { length: 4 }.kind(), MessageKind::Data);
+    assert_eq!(Message::Vendor(0x12, 3).kind(), MessageKind::Vendor);
+    assert_eq!(Message::Status(3).kind(), MessageKind::Status);
+    assert_eq!(Message::Unknown { id: 0x80 }.kind(), MessageKind::Unknown);
+    for bytes in [[1, 0], [2, 5], [0x1F, 1], [0, 2], [0xFF, 0]] {
+        assert_eq!(
+            Message::from_bytes(bytes).kind(),
+            Message::read_kind(&bytes)
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use bondrewd::{BitfieldTryErrorKind, Bitfields, TryBitfields};
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+enum Status {
+    Fault = -1,
+    Idle = 0,
+    Busy = 1,
+    Overheat = -4,
+}
+
+#[test]
+fn negative_discriminants() {
+    // -4 needs 3 bits with the sign.
+    assert_eq!(Status::BIT_SIZE, 3);
+    assert_eq!(Status::Fault.into_bytes(), [0b1110_0000]);
+    assert_eq!(Status::Overheat.into_bytes(), [0b1000_0000]);
+    assert_eq!(Status::from_bytes([0b1110_0000]), Status::Fault);
+    assert_eq!(Status::from_bytes([0b0010_0000]), Status::Busy);
+    assert_eq!(Status::read_variant_id(&[0b1110_0000]), -1_i8);
+    assert_eq!(Status::Fault.id(), -1);
+    assert_eq!(Status::Overheat.id(), -4);
+    assert_eq!(Status::Fault, -1_i8);
+    assert_eq!(StatusKind::Overheat.id(), -4);
+    assert_eq!(Status::read_kind(&[0b1000_0000]), StatusKind::Overheat);
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 4)]
+enum Code {
+    #[bondrewd(variant_id = "-3..=-1")]
+    Error {
+        #[bondrewd(capture_id)]
+        code: i8,
+        #[bondrewd(bit_length = 4)]
+        detail: u8,
+    },
+    #[bondrewd(variant_id = "-8..-5, 5..=6")]
+    Warning {
+        #[bondrewd(bit_length = 4)]
+        detail: u8,
+    },
+    Ok {
+        #[bondrewd(bit_length = 4)]
+        detail: u8,
+    },
+    #[bondrewd(invalid)]
+    Unknown {
+        #[bondrewd(capture_id)]
+        code: i8,
+        #[bondrewd(bit_length = 4)]
+        detail: u8,
+    },
+}
+
+#[test]
+fn signed_id_sets() -> anyhow::Result<()> {
+    assert_eq!(Code::BIT_SIZE, 8);
+    for code in -3..=-1 {
+        let bytes = [((code as u8) << 4) | 0x5];
+        assert_eq!(Code::from_bytes(bytes), Code::Error { code, detail: 5 });
+        assert_eq!(Code::Error { code, detail: 5 }.try_into_bytes()?, bytes);
+    }
+    for code in [-8_i8, -6, 5, 6] {
+        let bytes = [((code as u8) << 4) | 0x1];
+        assert_eq!(Code::from_bytes(bytes), Code::Warning { detail: 1 });
+    }
+    // the lowest id of a set is the one it is written with.
+    assert_eq!(Code::Warning { detail: 1 }.into_bytes(), [0x81]);
+    assert_eq!(Code::Warning { detail: 1 }.id(), -8);
+    // variants without an id get the first unused one.
+    assert_eq!(Code::Ok { detail: 0 }.id(), 0);
+    assert_eq!(
+        Code::from_bytes([0x70]),
+        Code::Unknown { code: 7, detail: 0 }
+    );
+    assert_eq!(
+        Code::from_bytes([0xC0]),
+        Code::Unknown {
+            code: -4,
+            detail: 0
+        }
+    );
+    let err = Code::Error { code: 2, detail: 0 }
+        .try_into_bytes()
+        .unwrap_err();
+    assert_eq!(err.kind, BitfieldTryErrorKind::CapturedIdMismatch(2));
+    // the catch all may not capture an id which does not fit in 4 signed bits.
+    assert!(Code::Unknown { code: 8, detail: 0 }
+        .try_into_bytes()
+        .is_err());
+    assert!(Code::Unknown {
+        code: -9,
+        detail: 0
+    }
+    .try_into_bytes()
+    .is_err());
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le")]
+#[repr(i16)]
+enum Wide {
+    Low { value: u8 } = -300,
+    High = 300,
+}
+
+#[test]
+fn signed_repr() {
+    assert_eq!(Wide::BIT_SIZE, 24);
+    assert_eq!(Wide::High.id(), 300_i16);
+    let bytes = Wide::Low { value: 7 }.into_bytes();
+    assert_eq!(Wide::read_variant_id(&bytes), -300_i16);
+    assert_eq!(Wide::from_bytes(bytes), Wide::Low { value: 7 });
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2)]
+#[repr(i8)]
+enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+    D = 3,
+}
+
+#[test]
+fn signed_repr_with_positive_ids() {
+    assert_eq!(Mode::BIT_SIZE, 2);
+    assert_eq!(Mode::D.id(), 3_u8);
+    let bytes = Mode::C.into_bytes();
+    assert_eq!(bytes, [0b1000_0000]);
+    assert_eq!(Mode::read_variant_id(&bytes), 2);
+    assert_eq!(Mode::from_bytes(bytes), Mode::C);
+    assert_eq!(Mode::from_bytes(Mode::D.into_bytes()), Mode::D);
+}
@@ -0,0 +1,92 @@
+use bondrewd::{BitfieldTryErrorKind, Bitfields, TryBitfields};
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", fill_pattern = 0xFF, fill_bytes = 3)]
+struct Idle {
+    #[bondrewd(bit_length = 4)]
+    one: u8,
+    #[bondrewd(bit_length = 4, reserve)]
+    reserved: u8,
+    #[bondrewd(bit_length = 4, reserve, fill_pattern = 0x55)]
+    alternating: u8,
+    #[bondrewd(bit_length = 4)]
+    two: u8,
+}
+
+#[test]
+fn struct_fill_pattern() -> anyhow::Result<()> {
+    let idle = Idle {
+        one: 0xA,
+        reserved: 0,
+        alternating: 0,
+        two: 0x3,
+    };
+    let bytes = idle.clone().into_bytes();
+    assert_eq!(bytes, [0xAF, 0x53, 0xFF]);
+    assert_eq!(idle.to_bytes(), bytes);
+    assert_eq!(Idle::try_from_bytes(bytes)?, idle);
+
+    // reserve fields must match their pattern, zero is no longer valid.
+    let err = Idle::try_from_bytes([0xA0, 0x53, 0xFF]).unwrap_err();
+    assert_eq!(err.field, "reserved");
+    assert_eq!(err.kind, BitfieldTryErrorKind::NonZeroReserve);
+    let err = Idle::try_from_bytes([0xAF, 0x73, 0xFF]).unwrap_err();
+    assert_eq!(err.field, "alternating");
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(
+    default_endianness = "be",
+    id_bit_length = 8,
+    fill_position = "after_id",
+    fill_pattern = 0xAA
+)]
+enum Message {
+    #[bondrewd(variant_id = 1)]
+    Short {
+        a: u8,
+    },
+    #[bondrewd(variant_id = 2)]
+    Long {
+        a: u8,
+        b: u16,
+    },
+    Empty,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 8)]
+enum TrailingMessage {
+    #[bondrewd(variant_id = 1)]
+    Short {
+        a: u8,
+    },
+    #[bondrewd(variant_id = 2)]
+    Long {
+        a: u8,
+        b: u16,
+    },
+    Empty,
+}
+
+#[test]
+fn enum_fill_after_id() {
+    let short = Message::Short { a: 0x12 };
+    let bytes = short.clone().into_bytes();
+    assert_eq!(bytes, [1, 0xAA, 0xAA, 0x12]);
+    assert_eq!(Message::from_bytes(bytes), short);
+
+    let long = Message::Long { a: 0x12, b: 0x3456 };
+    let bytes = long.clone().into_bytes();
+    assert_eq!(bytes, [2, 0x12, 0x34, 0x56]);
+    assert_eq!(Message::from_bytes(bytes), long);
+
+    assert_eq!(Message::Empty.into_bytes(), [0, 0xAA, 0xAA, 0xAA]);
+
+    // the default keeps the fill after the variant's fields, using zeros.
+    assert_eq!(
+        TrailingMessage::Short { a: 0x12 }.into_bytes(),
+        [1, 0x12, 0, 0]
+    );
+}
@@ -79,3 +79,141 @@ mod hex_tests {
         assert_eq!(from_slice_hex_obj, og);
     }
 }
+
+// the text functions only need bondrewd's `hex_fns` feature, which the dev-dependency enables.
+mod text_tests {
+    use bondrewd::{BitfieldHex, Bitfields};
+    #[derive(Bitfields, Clone, Debug, PartialEq)]
+    #[bondrewd(default_endianness = "be")]
+    pub struct Packet {
+        #[bondrewd(bit_length = 4)]
+        pub version: u8,
+        #[bondrewd(bit_length = 12)]
+        pub length: u16,
+        pub sequence: u16,
+    }
+
+    #[derive(Bitfields, Clone, Debug, PartialEq)]
+    #[bondrewd(default_endianness = "be")]
+    pub struct Wide {
+        pub one: u64,
+    }
+
+    #[derive(Bitfields, Clone, Debug, PartialEq)]
+    #[bondrewd(default_endianness = "be")]
+    pub struct Odd {
+        #[bondrewd(bit_length = 40)]
+        pub one: u64,
+    }
+
+    #[derive(Bitfields, Clone, Debug, PartialEq)]
+    #[bondrewd(default_endianness = "be")]
+    pub struct Triple {
+        #[bondrewd(bit_length = 24)]
+        pub one: u32,
+    }
+
+    #[test]
+    fn hex_str_test() {
+        let og = Packet {
+            version: 0xD,
+            length: 0xEAD,
+            sequence: 0xBEEF,
+        };
+        for text in [
+            "deadbeef",
+            "0xDEADBEEF",
+            "  DE AD\tBE EF\n",
+            "de:ad:be:ef",
+            "0xde 0xad 0xbe 0xef",
+            "0xDEAD_BEEF",
+        ] {
+            assert_eq!(Packet::from_hex_str(text).unwrap(), og, "{text}");
+        }
+        match Packet::from_hex_str("de:ad:bg:ef") {
+            Err(bondrewd::BitfieldTextError::Character(bondrewd::BitfieldHexError('g', 7))) => {}
+            other => panic!("expected character error, got {other:?}"),
+        }
+        // a prefix is only allowed at the start of a group.
+        match Packet::from_hex_str("de0xadbeef") {
+            Err(bondrewd::BitfieldTextError::Character(bondrewd::BitfieldHexError('x', 3))) => {}
+            other => panic!("expected character error, got {other:?}"),
+        }
+        match Packet::from_hex_str("0xdeadbee") {
+            Err(bondrewd::BitfieldTextError::Length(bondrewd::BitfieldLengthError(7, 8))) => {}
+            other => panic!("expected length error, got {other:?}"),
+        }
+        assert!(Packet::from_hex_str("deadbeef00").is_err());
+    }
+
+    #[test]
+    fn binary_str_test() {
+        let og = Packet {
+            version: 0b1010,
+            length: 0b0101_0000_1111,
+            sequence: 0x00FF,
+        };
+        let mut binary = String::new();
+        og.write_binary(&mut binary).unwrap();
+        assert_eq!(binary, "10100101000011110000000011111111");
+        assert_eq!(Packet::from_binary_str(&binary).unwrap(), og);
+        assert_eq!(
+            Packet::from_binary_str("0b1010_0101 0b0000_1111 00000000:11111111").unwrap(),
+            og
+        );
+        match Packet::from_binary_str("0b1010_0102") {
+            Err(bondrewd::BitfieldTextError::Character(bondrewd::BitfieldHexError('2', 10))) => {}
+            other => panic!("expected character error, got {other:?}"),
+        }
+        match Packet::from_binary_str("0b1010_0101") {
+            Err(bondrewd::BitfieldTextError::Length(bondrewd::BitfieldLengthError(8, 32))) => {}
+            other => panic!("expected length error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn base64_str_test() {
+        let og = Packet {
+            version: 0xD,
+            length: 0xEAD,
+            sequence: 0xBEEF,
+        };
+        let mut base64 = String::new();
+        og.write_base64(&mut base64).unwrap();
+        assert_eq!(base64, "3q2+7w==");
+        for text in ["3q2+7w==", "3q2+7w", "3q2-7w", " 3q2+\n7w= "] {
+            assert_eq!(Packet::from_base64_str(text).unwrap(), og, "{text}");
+        }
+        match Packet::from_base64_str("3q2*7w==") {
+            Err(bondrewd::BitfieldTextError::Character(bondrewd::BitfieldHexError('*', 3))) => {}
+            other => panic!("expected character error, got {other:?}"),
+        }
+        match Packet::from_base64_str("3q2=+7w") {
+            Err(bondrewd::BitfieldTextError::Character(bondrewd::BitfieldHexError('+', 4))) => {}
+            other => panic!("expected character error, got {other:?}"),
+        }
+        match Packet::from_base64_str("3q2+") {
+            Err(bondrewd::BitfieldTextError::Length(bondrewd::BitfieldLengthError(4, 6))) => {}
+            other => panic!("expected length error, got {other:?}"),
+        }
+
+        // every amount of padding.
+        let og = Wide {
+            one: 0x0123_4567_89AB_CDEF_u64,
+        };
+        let mut base64 = String::new();
+        og.write_base64(&mut base64).unwrap();
+        assert_eq!(base64, "ASNFZ4mrze8=");
+        assert_eq!(Wide::from_base64_str(&base64).unwrap(), og);
+        let og = Odd { one: 0x01_2345_6789 };
+        let mut base64 = String::new();
+        og.write_base64(&mut base64).unwrap();
+        assert_eq!(base64, "ASNFZ4k=");
+        assert_eq!(Odd::from_base64_str(&base64).unwrap(), og);
+        let og = Triple { one: 0xFF_EE_DD };
+        let mut base64 = String::new();
+        og.write_base64(&mut base64).unwrap();
+        assert_eq!(base64, "/+7d");
+        assert_eq!(Triple::from_base64_str("_-7d").unwrap(), og);
+    }
+}
@@ -0,0 +1,115 @@
+use bondrewd::Bitfields;
+use std::io::Cursor;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Record {
+    #[bondrewd(bit_length = 4)]
+    kind: u8,
+    #[bondrewd(bit_length = 12)]
+    value: u16,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 8)]
+enum Message {
+    Ping,
+    Data {
+        value: u32,
+    },
+    Short {
+        value: u8,
+    },
+    Unknown {
+        #[bondrewd(capture_id)]
+        id: u8,
+    },
+}
+
+#[test]
+fn stream_structs() -> std::io::Result<()> {
+    let records = [
+        Record { kind: 1, value: 300 },
+        Record { kind: 15, value: 4095 },
+        Record { kind: 0, value: 7 },
+    ];
+    let mut stream = Vec::new();
+    for record in records.iter() {
+        bondrewd::io::write_to(record, &mut stream)?;
+    }
+    assert_eq!(stream.len(), Record::BYTE_SIZE * records.len());
+
+    let mut reader = Cursor::new(stream);
+    for record in records.iter() {
+        let read: Record = bondrewd::io::read_from(&mut reader)?;
+        assert_eq!(&read, record);
+    }
+    let end = bondrewd::io::read_from::<Record, _, 2>(&mut reader);
+    assert_eq!(
+        end.map_err(|err| err.kind()),
+        Err(std::io::ErrorKind::UnexpectedEof)
+    );
+    Ok(())
+}
+
+#[test]
+fn stream_enums_only_use_variant_bytes() -> std::io::Result<()> {
+    assert_eq!(Message::BYTE_SIZE, 5);
+    let messages = [
+        Message::Data { value: 0xDEADBEEF },
+        Message::Ping,
+        Message::Short { value: 9 },
+        Message::Unknown { id: 200 },
+    ];
+    let mut stream = Vec::new();
+    for message in messages.iter() {
+        bondrewd::io::write_to(message, &mut stream)?;
+    }
+    assert_eq!(stream, [1, 0xDE, 0xAD, 0xBE, 0xEF, 0, 2, 9, 200]);
+
+    let mut reader = Cursor::new(stream);
+    for message in messages.iter() {
+        let read: Message = bondrewd::io::read_from(&mut reader)?;
+        assert_eq!(&read, message);
+    }
+    assert_eq!(reader.position(), 9);
+    Ok(())
+}
+
+/// the id is not in the first byte so the stream header has to reach the end of it.
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bits = "8..16")]
+enum Framed {
+    #[bondrewd(variant_id = 1)]
+    Flags { flags: u8 },
+    #[bondrewd(variant_id = 2)]
+    Wide { flags: u8, value: u16 },
+    #[bondrewd(variant_id = 3)]
+    Ping,
+}
+
+#[test]
+fn stream_enums_with_id_after_fields() -> std::io::Result<()> {
+    assert_eq!(Framed::STREAM_HEADER_BYTE_SIZE, 2);
+    let messages = [
+        Framed::Wide {
+            flags: 5,
+            value: 0x1234,
+        },
+        Framed::Flags { flags: 0xAA },
+        Framed::Ping,
+    ];
+    let mut stream = Vec::new();
+    for message in messages.iter() {
+        bondrewd::io::write_to(message, &mut stream)?;
+    }
+    assert_eq!(stream, [5, 2, 0x12, 0x34, 0xAA, 1, 0, 3]);
+
+    let mut reader = Cursor::new(stream);
+    for message in messages.iter() {
+        let read: Framed = bondrewd::io::read_from(&mut reader)?;
+        assert_eq!(&read, message);
+    }
+    assert_eq!(reader.position(), 8);
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use bondrewd::{
+    BitfieldTryErrorKind, BitfieldVariableError, Bitfields, BitfieldsVariable, TryBitfields,
+};
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Payload {
+    kind: u8,
+    value: u32,
+    flags: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Packet {
+    #[bondrewd(bit_length = 3)]
+    version: u8,
+    #[bondrewd(bit_length = 13)]
+    apid: u16,
+    #[bondrewd(length_of = "payload", adjust = -1)]
+    packet_data_length: u16,
+    #[bondrewd(struct_size = 6)]
+    payload: Payload,
+    #[bondrewd(crc = "crc16_ccitt_false", covers = "version..payload")]
+    crc: u16,
+}
+
+#[test]
+fn fixed_length_filled_by_into_bytes() -> anyhow::Result<()> {
+    let packet = Packet {
+        version: 0,
+        apid: 0x7FF,
+        // ignored, into_bytes computes it.
+        packet_data_length: 0,
+        payload: Payload {
+            kind: 1,
+            value: 2,
+            flags: 3,
+        },
+        crc: 0,
+    };
+    let bytes = packet.clone().into_bytes();
+    assert_eq!(bytes[2..4], [0, 5]);
+    assert_eq!(Packet::read_packet_data_length(&bytes), 5);
+    // the crc is computed after the length.
+    assert!(Packet::verify_crc(&bytes));
+    assert_eq!(packet.to_bytes(), bytes);
+
+    let decoded = Packet::try_from_bytes(bytes)?;
+    assert_eq!(decoded.packet_data_length, 5);
+
+    let mut wrong = bytes;
+    Packet::write_packet_data_length(&mut wrong, 6);
+    let err = Packet::try_from_bytes(wrong).unwrap_err();
+    assert_eq!(err.field, "packet_data_length");
+    assert_eq!(err.bit_range, 16..32);
+    assert_eq!(err.kind, BitfieldTryErrorKind::LengthMismatch(6, 5));
+    // the infallible api still reads the field as is.
+    assert_eq!(Packet::from_bytes(wrong).packet_data_length, 6);
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Message {
+    #[bondrewd(bit_length = 4)]
+    count: u8,
+    #[bondrewd(bit_length = 12, length_of = "samples", adjust = 2)]
+    length: u16,
+    #[bondrewd(count_from = "count", max = 15)]
+    samples: Vec<u16>,
+}
+
+#[test]
+fn variable_length_filled_by_into_slice() -> anyhow::Result<()> {
+    let message = Message {
+        count: 0,
+        length: 0,
+        samples: vec![1, 2, 3],
+    };
+    let bytes = message.into_vec()?;
+    assert_eq!(bytes[..2], [0x30, 0x08]);
+    let decoded = Message::from_slice(&bytes)?;
+    assert_eq!(decoded.count, 3);
+    assert_eq!(decoded.length, 8);
+    assert_eq!(decoded.samples, vec![1, 2, 3]);
+
+    let mut wrong = bytes.clone();
+    wrong[1] = 0x0A;
+    match Message::from_slice(&wrong) {
+        Err(BitfieldVariableError::Invalid(err)) => {
+            assert_eq!(err.field, "length");
+            assert_eq!(err.kind, BitfieldTryErrorKind::LengthMismatch(10, 8));
+        }
+        other => panic!("expected a length mismatch, got {other:?}"),
+    }
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Shrunk {
+    count: u8,
+    #[bondrewd(length_of = "data", adjust = -1)]
+    length: u8,
+    #[bondrewd(count_from = "count", max = 4)]
+    data: Vec<u8>,
+}
+
+#[test]
+fn variable_length_out_of_range() {
+    let empty = Shrunk {
+        count: 0,
+        length: 0,
+        data: Vec::new(),
+    };
+    match empty.into_vec() {
+        Err(BitfieldVariableError::Invalid(err)) => {
+            assert_eq!(err.field, "length");
+            assert_eq!(err.kind, BitfieldTryErrorKind::LengthOutOfRange(-1));
+        }
+        other => panic!("expected an out of range length, got {other:?}"),
+    }
+    // a length of 0 can never match an empty payload.
+    assert!(Shrunk::from_slice(&[0, 0]).is_err());
+    assert_eq!(Shrunk::from_slice(&[1, 0, 9]).unwrap().data, vec![9]);
+}
@@ -0,0 +1,119 @@
+use bondrewd::Bitfields;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 4)]
+enum Reading {
+    #[bondrewd(variant_id = 1)]
+    Temperature {
+        #[bondrewd(bit_length = 12)]
+        value: u16,
+    },
+    #[bondrewd(variant_id = 2)]
+    Voltage { value: u8 },
+    #[bondrewd(invalid)]
+    Unknown,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 4)]
+enum Service {
+    #[bondrewd(variant_id = 3)]
+    Housekeeping(
+        #[bondrewd(
+            bit_length = 16,
+            read_through = "temperature_value: u16, voltage_value: u8"
+        )]
+        Reading,
+    ),
+    #[bondrewd(variant_id = 4)]
+    Ping { sequence: u8 },
+    #[bondrewd(invalid)]
+    Unknown,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 4)]
+enum Class {
+    #[bondrewd(variant_id = 0xA)]
+    Telemetry(
+        #[bondrewd(
+            bit_length = 20,
+            read_through = "housekeeping_temperature_value: u16, ping_sequence: u8"
+        )]
+        Service,
+    ),
+    #[bondrewd(variant_id = 0)]
+    Idle,
+}
+
+#[test]
+fn nested_sizes() {
+    assert_eq!(Reading::BIT_SIZE, 16);
+    assert_eq!(Service::BIT_SIZE, 20);
+    assert_eq!(Class::BIT_SIZE, 24);
+    assert_eq!(Class::BYTE_SIZE, 3);
+}
+
+#[test]
+fn nested_dispatch() {
+    let temperature =
+        Class::Telemetry(Service::Housekeeping(Reading::Temperature { value: 0xBCD }));
+    let bytes = temperature.clone().into_bytes();
+    assert_eq!(bytes, [0xA3, 0x1B, 0xCD]);
+    assert_eq!(Class::from_bytes(bytes), temperature);
+
+    let voltage = Class::Telemetry(Service::Housekeeping(Reading::Voltage { value: 0x5E }));
+    let bytes = voltage.clone().into_bytes();
+    assert_eq!(bytes, [0xA3, 0x25, 0xE0]);
+    assert_eq!(Class::from_bytes(bytes), voltage);
+
+    let ping = Class::Telemetry(Service::Ping { sequence: 0x42 });
+    let bytes = ping.clone().into_bytes();
+    assert_eq!(bytes, [0xA4, 0x42, 0x00]);
+    assert_eq!(Class::from_bytes(bytes), ping);
+
+    // unknown ids fall into the invalid variant of the level they belong to.
+    assert_eq!(
+        Class::from_bytes([0xA3, 0xF0, 0x00]),
+        Class::Telemetry(Service::Housekeeping(Reading::Unknown))
+    );
+    assert_eq!(
+        Class::from_bytes([0xAF, 0x00, 0x00]),
+        Class::Telemetry(Service::Unknown)
+    );
+    assert_eq!(Class::from_bytes([0x00, 0x00, 0x00]), Class::Idle);
+}
+
+#[test]
+fn nested_read_bytes() {
+    let bytes =
+        Class::Telemetry(Service::Housekeeping(Reading::Temperature { value: 0xBCD })).into_bytes();
+    assert_eq!(Class::read_variant_id(&bytes), 0xA);
+    let service = Class::read_telemetry_field_1_bytes(&bytes);
+    assert_eq!(service, [0x31, 0xBC, 0xD0]);
+    assert_eq!(Service::read_variant_id(&service), 3);
+    let reading = Service::read_housekeeping_field_1_bytes(&service);
+    assert_eq!(reading, [0x1B, 0xCD]);
+    assert_eq!(Reading::read_variant_id(&reading), 1);
+    assert_eq!(Reading::read_temperature_value(&reading), 0xBCD);
+}
+
+#[test]
+fn nested_read_through() {
+    let bytes =
+        Class::Telemetry(Service::Housekeeping(Reading::Temperature { value: 0xBCD })).into_bytes();
+    assert_eq!(
+        Service::read_housekeeping_temperature_value(&[0x31, 0xBC, 0xD0]),
+        0xBCD
+    );
+    assert_eq!(
+        Class::read_telemetry_housekeeping_temperature_value(&bytes),
+        0xBCD
+    );
+
+    let bytes = Class::Telemetry(Service::Ping { sequence: 0x42 }).into_bytes();
+    assert_eq!(Class::read_telemetry_ping_sequence(&bytes), 0x42);
+
+    let bytes = Service::Housekeeping(Reading::Voltage { value: 0x5E }).into_bytes();
+    assert_eq!(Service::read_housekeeping_voltage_value(&bytes), 0x5E);
+}
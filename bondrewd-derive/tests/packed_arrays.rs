@@ -0,0 +1,64 @@
+use bondrewd::{Bitfields, PackedArray};
+
+#[derive(Bitfields, Debug, Clone, PartialEq, Eq)]
+#[bondrewd(default_endianness = "be")]
+struct Status {
+    one: bool,
+    #[bondrewd(bit_length = 2)]
+    two: u8,
+}
+
+#[derive(Bitfields, Debug, Clone, PartialEq, Eq)]
+#[bondrewd(default_endianness = "le", read_from = "lsb0")]
+struct Sample {
+    #[bondrewd(bit_length = 11)]
+    value: i16,
+    flag: bool,
+}
+
+const STATUS_BYTES: usize = ((4 * Status::BIT_SIZE) + 7) / 8;
+
+#[test]
+fn packed_array_get_set() {
+    assert_eq!(STATUS_BYTES, 2);
+    assert_eq!(PackedArray::<Status, 4, [u8; STATUS_BYTES]>::byte_size(), 2);
+    let mut array: PackedArray<Status, 4, [u8; STATUS_BYTES]> =
+        PackedArray::new([0u8; STATUS_BYTES]).unwrap();
+    assert_eq!(array.len(), 4);
+    array.set(2, &Status { one: true, two: 3 });
+    array.set(0, &Status { one: false, two: 1 });
+    assert_eq!(array.get(2), Some(Status { one: true, two: 3 }));
+    assert_eq!(array.get(1), Some(Status { one: false, two: 0 }));
+    assert_eq!(array.get(0), Some(Status { one: false, two: 1 }));
+    assert_eq!(array.get(4), None);
+    assert_eq!(array.into_inner(), [0b001_000_11, 0b1_000_0000]);
+}
+
+#[test]
+fn packed_array_over_borrowed_bytes() {
+    let samples = [
+        Sample { value: -1024, flag: true },
+        Sample { value: 1023, flag: false },
+        Sample { value: -3, flag: true },
+        Sample { value: 0, flag: false },
+        Sample { value: 77, flag: true },
+    ];
+    // 5 records of 12 bits is 60 bits instead of the 10 bytes padding would use.
+    let mut bytes = vec![0xFFu8; 8];
+    {
+        let mut array = PackedArray::<Sample, 5, _>::new(&mut bytes[..]).unwrap();
+        for (i, sample) in samples.iter().enumerate() {
+            array.set(i, sample);
+        }
+    }
+    // bits after the last record are untouched.
+    assert_eq!(bytes[7] & 0x0F, 0x0F);
+    let array = PackedArray::<Sample, 5, _>::new(&bytes[..]).unwrap();
+    for (i, sample) in samples.iter().enumerate() {
+        assert_eq!(array.get(i).as_ref(), Some(sample));
+    }
+    match PackedArray::<Sample, 5, _>::new(&bytes[..7]) {
+        Err(bondrewd::BitfieldLengthError(7, 8)) => {}
+        _ => panic!("expected a length error"),
+    }
+}
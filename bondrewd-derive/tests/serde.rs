@@ -0,0 +1,217 @@
+// `enum_primitive` fields still use the deprecated `BitfieldEnum` trait.
+#![allow(deprecated)]
+use bondrewd::{BitfieldEnum, Bitfields};
+use serde::{Deserialize, Serialize};
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Header {
+    #[bondrewd(bit_length = 3)]
+    one: u8,
+    #[bondrewd(bit_length = 13)]
+    two: u16,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+struct Config {
+    #[serde(with = "bondrewd::serde_bytes_form")]
+    header: Header,
+    #[serde(with = "bondrewd::serde_bytes_form::bytes")]
+    raw: Header,
+}
+
+#[test]
+fn serde_bytes_form_hex_and_bytes() -> anyhow::Result<()> {
+    let config = Config {
+        header: Header { one: 5, two: 1234 },
+        raw: Header { one: 1, two: 2 },
+    };
+    assert_eq!(config.header.clone().into_bytes(), [0xA4, 0xD2]);
+    let json = serde_json::to_string(&config)?;
+    assert_eq!(json, r#"{"header":"a4d2","raw":[32,2]}"#);
+    assert_eq!(serde_json::from_str::<Config>(&json)?, config);
+    assert_eq!(
+        serde_json::from_str::<Config>(r#"{"header":"A4D2","raw":[32,2]}"#)?,
+        config
+    );
+    assert!(serde_json::from_str::<Config>(r#"{"header":"a4d","raw":[32,2]}"#).is_err());
+    assert!(serde_json::from_str::<Config>(r#"{"header":"a4zz","raw":[32,2]}"#).is_err());
+    assert!(serde_json::from_str::<Config>(r#"{"header":"a4d2","raw":[32,2,0]}"#).is_err());
+
+    let mut cbor = Vec::new();
+    ciborium::into_writer(&config, &mut cbor)?;
+    assert_eq!(ciborium::from_reader::<Config, _>(cbor.as_slice())?, config);
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", serde)]
+struct Limited {
+    #[bondrewd(bit_length = 3)]
+    small: u8,
+    #[bondrewd(bit_length = 5)]
+    signed: i8,
+    flag: bool,
+    #[bondrewd(element_bit_length = 4)]
+    nibbles: [u8; 2],
+    #[bondrewd(bit_length = 7)]
+    letter: char,
+}
+
+#[test]
+fn serde_derive_round_trip() -> anyhow::Result<()> {
+    let value = Limited {
+        small: 7,
+        signed: -16,
+        flag: true,
+        nibbles: [15, 3],
+        letter: 'z',
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(
+        json,
+        r#"{"small":7,"signed":-16,"flag":true,"nibbles":[15,3],"letter":"z"}"#
+    );
+    assert_eq!(serde_json::from_str::<Limited>(&json)?, value);
+    Ok(())
+}
+
+#[test]
+fn serde_derive_rejects_out_of_range() {
+    for json in [
+        r#"{"small":8,"signed":0,"flag":true,"nibbles":[0,0],"letter":"a"}"#,
+        r#"{"small":0,"signed":16,"flag":true,"nibbles":[0,0],"letter":"a"}"#,
+        r#"{"small":0,"signed":-17,"flag":true,"nibbles":[0,0],"letter":"a"}"#,
+        r#"{"small":0,"signed":0,"flag":true,"nibbles":[0,16],"letter":"a"}"#,
+        r#"{"small":0,"signed":0,"flag":true,"nibbles":[0,0],"letter":"é"}"#,
+    ] {
+        let err = serde_json::from_str::<Limited>(json).unwrap_err();
+        assert!(err.to_string().contains("does not fit in"), "{err}");
+    }
+}
+
+#[derive(BitfieldEnum, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+enum Speed {
+    Stopped,
+    Slow,
+    Fast,
+    Invalid,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", serde)]
+struct Blocks {
+    #[bondrewd(enum_primitive = "u8", bit_length = 1)]
+    speed: Speed,
+    #[bondrewd(block_bit_length = 12)]
+    block: [u8; 2],
+    #[bondrewd(bit_length = 3)]
+    rest: u8,
+}
+
+#[test]
+fn serde_derive_checks_enums_and_block_arrays() -> anyhow::Result<()> {
+    let value = Blocks {
+        speed: Speed::Slow,
+        block: [15, 255],
+        rest: 0,
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(serde_json::from_str::<Blocks>(&json)?, value);
+    for json in [
+        r#"{"speed":"Fast","block":[0,0],"rest":0}"#,
+        r#"{"speed":"Slow","block":[255,255],"rest":0}"#,
+        r#"{"speed":"Slow","block":[16,0],"rest":0}"#,
+    ] {
+        let err = serde_json::from_str::<Blocks>(json).unwrap_err();
+        assert!(err.to_string().contains("does not fit in"), "{err}");
+    }
+    Ok(())
+}
+
+/// Uses the plain serde derive, so only `check_fields` notices a value that does not fit.
+#[derive(Bitfields, Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Plain {
+    #[bondrewd(bit_length = 4)]
+    nibble: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", serde)]
+struct HoldsPlain {
+    #[bondrewd(struct_size = 1, bit_length = 4)]
+    plain: Plain,
+    #[bondrewd(bit_length = 4)]
+    rest: u8,
+}
+
+#[test]
+fn serde_derive_checks_nested_fields() -> anyhow::Result<()> {
+    let value = HoldsPlain {
+        plain: Plain { nibble: 15 },
+        rest: 1,
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(serde_json::from_str::<HoldsPlain>(&json)?, value);
+    let err =
+        serde_json::from_str::<HoldsPlain>(r#"{"plain":{"nibble":16},"rest":1}"#).unwrap_err();
+    assert!(
+        err.to_string().contains("field `plain` does not fit in"),
+        "{err}"
+    );
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", serde)]
+pub struct Empty {}
+
+#[test]
+fn serde_derive_empty_struct() -> anyhow::Result<()> {
+    let json = serde_json::to_string(&Empty {})?;
+    assert_eq!(serde_json::from_str::<Empty>(&json)?, Empty {});
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2, serde)]
+enum Command {
+    Stop,
+    Move {
+        #[bondrewd(bit_length = 6)]
+        speed: u8,
+    },
+    Turn(#[bondrewd(bit_length = 6)] i8),
+    Unknown {
+        #[bondrewd(capture_id)]
+        id: u8,
+    },
+}
+
+#[test]
+fn serde_derive_enums() -> anyhow::Result<()> {
+    let commands = vec![
+        Command::Stop,
+        Command::Move { speed: 63 },
+        Command::Turn(-32),
+        Command::Unknown { id: 3 },
+    ];
+    let json = serde_json::to_string(&commands)?;
+    assert_eq!(
+        json,
+        r#"["Stop",{"Move":{"speed":63}},{"Turn":-32},{"Unknown":{"id":3}}]"#
+    );
+    assert_eq!(serde_json::from_str::<Vec<Command>>(&json)?, commands);
+    assert!(serde_json::from_str::<Command>(r#"{"Move":{"speed":64}}"#).is_err());
+    assert!(serde_json::from_str::<Command>(r#"{"Turn":32}"#).is_err());
+    assert!(serde_json::from_str::<Command>(r#"{"Unknown":{"id":4}}"#).is_err());
+
+    let mut cbor = Vec::new();
+    ciborium::into_writer(&commands, &mut cbor)?;
+    assert_eq!(
+        ciborium::from_reader::<Vec<Command>, _>(cbor.as_slice())?,
+        commands
+    );
+    Ok(())
+}
@@ -0,0 +1,256 @@
+// `enum_primitive` fields still use the deprecated `BitfieldEnum` trait.
+#![allow(deprecated)]
+use bondrewd::{BitfieldEnum, BitfieldTryErrorKind, Bitfields};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Mode {
+    Off,
+    On,
+    Other(u8),
+}
+
+impl BitfieldEnum for Mode {
+    type Primitive = u8;
+    fn from_primitive(prim: u8) -> Self {
+        match prim {
+            0 => Self::Off,
+            1 => Self::On,
+            other => Self::Other(other),
+        }
+    }
+    fn into_primitive(self) -> u8 {
+        match self {
+            Self::Off => 0,
+            Self::On => 1,
+            Self::Other(other) => other,
+        }
+    }
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Inner {
+    #[bondrewd(bit_length = 4)]
+    value: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Clamped {
+    #[bondrewd(bit_length = 5)]
+    unsigned: u16,
+    #[bondrewd(bit_length = 4)]
+    signed: i8,
+    #[bondrewd(bit_length = 7)]
+    letter: char,
+    #[bondrewd(element_bit_length = 3)]
+    elements: [u8; 2],
+    #[bondrewd(block_bit_length = 9)]
+    block: [u8; 2],
+    flag: bool,
+}
+
+#[test]
+fn setters_clamp() {
+    let mut value = Clamped {
+        unsigned: 0,
+        signed: 0,
+        letter: 'a',
+        elements: [0; 2],
+        block: [0; 2],
+        flag: false,
+    };
+    value.set_unsigned(40);
+    assert_eq!(value.unsigned, 31);
+    value.set_unsigned(7);
+    assert_eq!(value.unsigned, 7);
+    value.set_signed(100);
+    assert_eq!(value.signed, 7);
+    value.set_signed(-100);
+    assert_eq!(value.signed, -8);
+    value.set_letter('é');
+    assert_eq!(value.letter, '\u{7F}');
+    value.set_letter('z');
+    assert_eq!(value.letter, 'z');
+    value.set_elements([2, 9]);
+    assert_eq!(value.elements, [2, 7]);
+    // the block drops the high bits of the first element.
+    value.set_block([3, 255]);
+    assert_eq!(value.block, [1, 255]);
+    value.set_flag(true);
+    assert!(value.flag);
+    assert!(value.check_fields().is_ok());
+    let bytes = value.clone().into_bytes();
+    assert_eq!(Clamped::from_bytes(bytes), value);
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", checked_setters)]
+struct Checked {
+    #[bondrewd(bit_length = 3)]
+    number: u8,
+    #[bondrewd(enum_primitive = "u8", bit_length = 2)]
+    mode: Mode,
+    #[bondrewd(bit_length = 4)]
+    inner: Inner,
+    #[bondrewd(element_bit_length = 2)]
+    elements: [u8; 3],
+}
+
+#[test]
+fn checked_setters() -> anyhow::Result<()> {
+    let mut value = Checked {
+        number: 0,
+        mode: Mode::Off,
+        inner: Inner { value: 0 },
+        elements: [0; 3],
+    };
+    value.set_number(7)?;
+    assert_eq!(value.number, 7);
+    let err = value.set_number(8).unwrap_err();
+    assert_eq!(err.field, "number");
+    assert_eq!(err.bit_range, 0..3);
+    assert_eq!(err.kind, BitfieldTryErrorKind::ValueOutOfRange);
+    // the field is left as it was.
+    assert_eq!(value.number, 7);
+
+    value.set_mode(Mode::Other(3))?;
+    assert!(value.set_mode(Mode::Other(4)).is_err());
+    assert_eq!(value.mode, Mode::Other(3));
+
+    value.set_inner(Inner { value: 15 })?;
+    let err = value.set_inner(Inner { value: 16 }).unwrap_err();
+    assert_eq!(err.field, "value");
+    assert_eq!(value.inner, Inner { value: 15 });
+
+    value.set_elements([1, 2, 3])?;
+    assert!(value.set_elements([1, 4, 3]).is_err());
+    assert_eq!(value.elements, [1, 2, 3]);
+
+    assert!(value.check_fields().is_ok());
+    value.number = 9;
+    assert_eq!(
+        value.check_fields().unwrap_err().kind,
+        BitfieldTryErrorKind::ValueOutOfRange
+    );
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2)]
+enum Packet {
+    Data {
+        #[bondrewd(common, bit_length = 4)]
+        sequence: u8,
+        #[bondrewd(bit_length = 6)]
+        value: u8,
+    },
+    Ack {
+        sequence: u8,
+    },
+}
+
+#[test]
+fn enum_setters() {
+    let mut packet = Packet::Data {
+        sequence: 0,
+        value: 0,
+    };
+    assert!(packet.set_data_value(100));
+    packet.set_sequence(20);
+    assert_eq!(
+        packet,
+        Packet::Data {
+            sequence: 15,
+            value: 63
+        }
+    );
+    let mut ack = Packet::Ack { sequence: 0 };
+    // setters of other variants leave the value alone.
+    assert!(!ack.set_data_value(1));
+    ack.set_sequence(3);
+    assert_eq!(ack, Packet::Ack { sequence: 3 });
+    assert!(ack.check_fields().is_ok());
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2, checked_setters)]
+enum Command {
+    Move(
+        #[bondrewd(bit_length = 3)] u8,
+        #[bondrewd(bit_length = 3)] u8,
+    ),
+    Stop,
+}
+
+#[test]
+fn checked_enum_setters() -> anyhow::Result<()> {
+    let mut command = Command::Move(0, 0);
+    assert!(command.set_move_field_2(5)?);
+    assert!(command.set_move_field_1(8).is_err());
+    assert_eq!(command, Command::Move(0, 5));
+    assert!(!Command::Stop.set_move_field_1(1)?);
+    assert!(command.check_fields().is_ok());
+    let Command::Move(ref mut x, _) = command else {
+        unreachable!()
+    };
+    *x = 9;
+    assert_eq!(
+        command.check_fields().unwrap_err().kind,
+        BitfieldTryErrorKind::ValueOutOfRange
+    );
+    Ok(())
+}
+
+/// `enum_primitive` fields are checked through a copy of their primitive.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Level {
+    Low,
+    High(u8),
+}
+
+impl BitfieldEnum for Level {
+    type Primitive = u8;
+    fn from_primitive(prim: u8) -> Self {
+        match prim {
+            0 => Self::Low,
+            other => Self::High(other),
+        }
+    }
+    fn into_primitive(self) -> u8 {
+        match self {
+            Self::Low => 0,
+            Self::High(other) => other,
+        }
+    }
+}
+
+#[derive(Bitfields, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", checked_setters)]
+struct Levels {
+    #[bondrewd(enum_primitive = "u8", bit_length = 2)]
+    level: Level,
+    #[bondrewd(enum_primitive = "u8", element_bit_length = 3)]
+    history: [Level; 2],
+}
+
+#[test]
+fn checked_setters_enum_fields() -> anyhow::Result<()> {
+    let mut value = Levels {
+        level: Level::Low,
+        history: [Level::Low, Level::Low],
+    };
+    value.set_level(Level::High(3))?;
+    assert!(value.set_level(Level::High(4)).is_err());
+    assert_eq!(value.level, Level::High(3));
+    value.set_history([Level::High(7), Level::Low])?;
+    assert!(value.set_history([Level::High(8), Level::Low]).is_err());
+    assert_eq!(value.history, [Level::High(7), Level::Low]);
+    assert!(value.check_fields().is_ok());
+    value.level = Level::High(4);
+    assert_eq!(value.check_fields().unwrap_err().field, "level");
+    value.level = Level::Low;
+    value.history[1] = Level::High(8);
+    assert_eq!(value.check_fields().unwrap_err().field, "history");
+    Ok(())
+}
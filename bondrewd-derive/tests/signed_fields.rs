@@ -0,0 +1,47 @@
+use bondrewd::Bitfields;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct SmallSigned {
+    #[bondrewd(bit_length = 4)]
+    one: i8,
+    #[bondrewd(bit_length = 4)]
+    two: i8,
+}
+
+#[test]
+fn small_signed_fields_sign_extend() {
+    let simple = SmallSigned { one: -3, two: 5 };
+    let bytes = simple.clone().into_bytes();
+    assert_eq!(bytes, [0b1101_0101]);
+    assert_eq!(SmallSigned::read_one(&bytes), -3);
+    assert_eq!(SmallSigned::from_bytes(bytes), simple);
+    for one in -8..=7 {
+        for two in [-8, -1, 0, 7] {
+            let simple = SmallSigned { one, two };
+            assert_eq!(SmallSigned::from_bytes(simple.clone().into_bytes()), simple);
+        }
+    }
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le")]
+struct SmallSignedLe {
+    #[bondrewd(bit_length = 3)]
+    one: i8,
+    #[bondrewd(bit_length = 5)]
+    two: i8,
+}
+
+#[test]
+fn small_signed_fields_sign_extend_le() {
+    for one in -4..=3 {
+        for two in [-16, -9, -1, 0, 15] {
+            let simple = SmallSignedLe { one, two };
+            let bytes = simple.clone().into_bytes();
+            assert_eq!(SmallSignedLe::read_one(&bytes), one);
+            assert_eq!(SmallSignedLe::read_two(&bytes), two);
+            assert_eq!(SmallSignedLe::from_bytes(bytes), simple);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use bondrewd::Bitfields;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2)]
+enum Payload {
+    #[bondrewd(variant_id = 0)]
+    Telemetry { temperature: i16 },
+    #[bondrewd(variant_id = 1)]
+    Command {
+        opcode: u8,
+        #[bondrewd(bit_length = 4)]
+        argument: u8,
+    },
+    #[bondrewd(invalid)]
+    Unknown {
+        #[bondrewd(capture_id)]
+        id: u8,
+    },
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", enforce_bytes = 4)]
+struct Packet {
+    #[bondrewd(bit_length = 3)]
+    version: u8,
+    #[bondrewd(bit_length = 2)]
+    packet_type: u8,
+    #[bondrewd(bit_length = 3)]
+    flags: u8,
+    #[bondrewd(tag_from = "packet_type", bit_length = 16)]
+    payload: Payload,
+    sequence: u8,
+}
+
+#[test]
+fn payload_bytes() {
+    assert_eq!(Payload::BYTE_SIZE, 3);
+    assert_eq!(Payload::PAYLOAD_BYTE_SIZE, 2);
+    let command = Payload::Command {
+        opcode: 0xAB,
+        argument: 0xC,
+    };
+    let payload = command.clone().into_payload_bytes();
+    assert_eq!(payload, [0xAB, 0xC0]);
+    assert_eq!(Payload::from_payload_bytes(1, payload), command);
+    assert_eq!(
+        Payload::from_payload_bytes(0, [0xFF, 0xFE]),
+        Payload::Telemetry { temperature: -2 }
+    );
+    assert_eq!(
+        Payload::from_payload_bytes(3, [0, 0]),
+        Payload::Unknown { id: 3 }
+    );
+}
+
+#[test]
+fn tag_from_sibling_field() {
+    let packet = Packet {
+        version: 1,
+        // ignored, into_bytes takes the id from the payload.
+        packet_type: 0,
+        flags: 0b101,
+        payload: Payload::Command {
+            opcode: 0xAB,
+            argument: 0xC,
+        },
+        sequence: 7,
+    };
+    let bytes = packet.clone().into_bytes();
+    assert_eq!(bytes, [0b001_01_101, 0xAB, 0xC0, 7]);
+    assert_eq!(packet.to_bytes(), bytes);
+    assert_eq!(Packet::read_packet_type(&bytes), 1);
+    assert_eq!(
+        Packet::read_payload(&bytes),
+        Payload::Command {
+            opcode: 0xAB,
+            argument: 0xC
+        }
+    );
+    let decoded = Packet::from_bytes(bytes);
+    assert_eq!(decoded.packet_type, 1);
+    assert_eq!(decoded.payload, packet.payload);
+
+    // changing the tag changes how the payload is read.
+    let mut bytes = bytes;
+    Packet::write_packet_type(&mut bytes, 0);
+    assert_eq!(
+        Packet::from_bytes(bytes).payload,
+        Payload::Telemetry {
+            temperature: 0xABC0_u16 as i16
+        }
+    );
+}
+
+#[derive(Bitfields, Clone, Copy, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 1)]
+enum Reading {
+    #[bondrewd(variant_id = 0)]
+    Voltage {
+        #[bondrewd(bit_length = 12)]
+        millivolts: u16,
+    },
+    #[bondrewd(variant_id = 1)]
+    Current {
+        #[bondrewd(bit_length = 12)]
+        milliamps: u16,
+    },
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le")]
+struct Sample {
+    #[bondrewd(tag_from = "kind", bit_length = 12)]
+    reading: Reading,
+    #[bondrewd(bit_length = 4)]
+    kind: u8,
+}
+
+#[test]
+fn tag_after_payload() {
+    let sample = Sample {
+        reading: Reading::Current { milliamps: 0x123 },
+        kind: 0,
+    };
+    let bytes = sample.into_bytes();
+    assert_eq!(Sample::read_kind(&bytes), 1);
+    let decoded = Sample::from_bytes(bytes);
+    assert_eq!(decoded.kind, 1);
+    assert_eq!(decoded.reading, Reading::Current { milliamps: 0x123 });
+}
+
+#[test]
+fn payload_writers_update_tag() -> anyhow::Result<()> {
+    let command = Payload::Command {
+        opcode: 0xAB,
+        argument: 0xC,
+    };
+    let mut packet = Packet {
+        version: 1,
+        packet_type: 1,
+        flags: 0,
+        payload: command.clone(),
+        sequence: 7,
+    };
+    let mut bytes = packet.clone().into_bytes();
+    Packet::write_payload(&mut bytes, Payload::Telemetry { temperature: -2 });
+    assert_eq!(Packet::read_packet_type(&bytes), 0);
+    assert_eq!(
+        Packet::read_payload(&bytes),
+        Payload::Telemetry { temperature: -2 }
+    );
+
+    let mut checked = Packet::check_slice_mut(&mut bytes)?;
+    checked.write_payload(command.clone());
+    assert_eq!(Packet::read_packet_type(&bytes), 1);
+    assert_eq!(bytes, packet.clone().into_bytes());
+
+    Packet::write_slice_payload(&mut bytes[..], Payload::Unknown { id: 3 })?;
+    assert_eq!(Packet::read_packet_type(&bytes), 3);
+
+    let mut view = PacketViewMut::new(&mut bytes[..])?;
+    view.write_payload(command.clone());
+    assert_eq!(view.read_packet_type(), 1);
+
+    // setters keep the tag field of the structure in agreement too.
+    packet.set_payload(Payload::Telemetry { temperature: 5 });
+    assert_eq!(packet.packet_type, 0);
+    Ok(())
+}
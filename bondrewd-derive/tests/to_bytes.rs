@@ -0,0 +1,183 @@
+#![allow(deprecated)]
+use bondrewd::{BitfieldEnum, Bitfields};
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Inner {
+    #[bondrewd(bit_length = 3)]
+    one: u8,
+    #[bondrewd(bit_length = 5)]
+    two: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2, enforce_bits = 14)]
+enum Message {
+    One {
+        #[bondrewd(bit_length = 4)]
+        a: u8,
+    },
+    Two {
+        #[bondrewd(struct_size = 1, bit_length = 8)]
+        inner: Inner,
+    },
+    Other {
+        #[bondrewd(capture_id)]
+        id: u8,
+    },
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le", reverse)]
+struct Outer {
+    flag: bool,
+    #[bondrewd(bit_length = 13)]
+    number: u16,
+    #[bondrewd(element_bit_length = 3)]
+    small: [u8; 3],
+    #[bondrewd(struct_size = 1, bit_length = 8)]
+    inner: Inner,
+    #[bondrewd(bit_length = 14)]
+    message: Message,
+    #[bondrewd(bit_length = 3, reserve)]
+    reserve: u8,
+}
+
+fn outer() -> Outer {
+    Outer {
+        flag: true,
+        number: 0x1234,
+        small: [1, 5, 7],
+        inner: Inner { one: 5, two: 17 },
+        message: Message::Two {
+            inner: Inner { one: 2, two: 30 },
+        },
+        reserve: 0,
+    }
+}
+
+#[test]
+fn to_bytes_matches_into_bytes() {
+    let outer = outer();
+    let bytes = outer.to_bytes();
+    assert_eq!(bytes, outer.clone().into_bytes());
+    assert_eq!(Outer::from_bytes(bytes), outer);
+
+    for message in [
+        Message::One { a: 9 },
+        Message::Two {
+            inner: Inner { one: 7, two: 1 },
+        },
+        Message::Other { id: 3 },
+    ] {
+        assert_eq!(message.to_bytes(), message.clone().into_bytes());
+    }
+}
+
+#[test]
+fn write_to_overwrites_buffer() {
+    let outer = outer();
+    let mut bytes = [0xFF; Outer::BYTE_SIZE];
+    outer.write_to(&mut bytes);
+    assert_eq!(bytes, outer.clone().into_bytes());
+
+    let message = Message::Other { id: 3 };
+    let mut bytes = [0xFF; Message::BYTE_SIZE];
+    message.write_to(&mut bytes);
+    assert_eq!(bytes, [0b1100_0000, 0b0000_0000]);
+}
+
+#[test]
+fn update_from_existing_value() {
+    let bytes = outer().into_bytes();
+    let mut updated = Outer {
+        flag: false,
+        number: 0,
+        small: [0; 3],
+        inner: Inner { one: 0, two: 0 },
+        message: Message::One { a: 0 },
+        reserve: 6,
+    };
+    updated.update_from(&bytes);
+    // reserve fields are not read, so they keep whatever was in the value already.
+    assert_eq!(updated.reserve, 6);
+    updated.reserve = 0;
+    assert_eq!(updated, outer());
+
+    let mut message = Message::One { a: 1 };
+    message.update_from(&Message::Other { id: 3 }.into_bytes());
+    assert_eq!(message, Message::Other { id: 3 });
+}
+
+#[derive(BitfieldEnum, Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    Zero,
+    One,
+    Two,
+    Three,
+}
+
+#[derive(Bitfields, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be")]
+struct Flags {
+    #[bondrewd(bit_length = 8)]
+    flags: u8,
+}
+
+/// neither the structure nor its nested structure are `Clone`, `enum_primitive` fields only need to
+/// be `Copy`.
+#[derive(Bitfields, Debug, PartialEq)]
+#[bondrewd(default_endianness = "be")]
+struct NotClone {
+    #[bondrewd(enum_primitive = "u8", bit_length = 2)]
+    kind: Kind,
+    #[bondrewd(bit_length = 6)]
+    value: u8,
+    #[bondrewd(struct_size = 1, bit_length = 8)]
+    flags: Flags,
+}
+
+#[test]
+fn to_bytes_without_clone() {
+    let value = NotClone {
+        kind: Kind::One,
+        value: 3,
+        flags: Flags { flags: 0xA5 },
+    };
+    assert_eq!(value.to_bytes(), [0b0100_0011, 0xA5]);
+    let mut bytes = [0xFF; 2];
+    value.write_to(&mut bytes);
+    assert_eq!(bytes, [0b0100_0011, 0xA5]);
+    let bytes = value.into_bytes();
+    assert_eq!(bytes, [0b0100_0011, 0xA5]);
+    assert_eq!(
+        NotClone::from_bytes(bytes),
+        NotClone {
+            kind: Kind::One,
+            value: 3,
+            flags: Flags { flags: 0xA5 },
+        }
+    );
+}
+
+/// a hand written implementation gets `into_bytes` and `to_bytes` from `write_to`.
+struct Manual(u8);
+
+impl Bitfields<1> for Manual {
+    const BIT_SIZE: usize = 8;
+    fn write_to(&self, output_byte_buffer: &mut [u8; 1]) {
+        *output_byte_buffer = [self.0];
+    }
+    fn from_bytes(input_byte_buffer: [u8; 1]) -> Self {
+        Manual(input_byte_buffer[0])
+    }
+}
+
+#[test]
+fn default_into_bytes() {
+    assert_eq!(Manual(7).to_bytes(), [7]);
+    assert_eq!(Manual(8).into_bytes(), [8]);
+    let mut bytes = [0xFF];
+    Manual(9).write_to(&mut bytes);
+    assert_eq!(bytes, [9]);
+}
@@ -0,0 +1,261 @@
+use bondrewd::{Bitfields, BitfieldTryErrorKind, TryBitfields};
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Reserved {
+    #[bondrewd(bit_length = 4)]
+    one: u8,
+    #[bondrewd(bit_length = 6, reserve)]
+    reserve: u8,
+    #[bondrewd(bit_length = 6)]
+    two: u8,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Chars {
+    one: u8,
+    letter: char,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[repr(u8)]
+#[bondrewd(default_endianness = "be", id_bit_length = 4)]
+enum Strict {
+    One { a: u8 } = 1,
+    Two { a: u16 } = 2,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[repr(u8)]
+#[bondrewd(default_endianness = "be", id_bit_length = 4)]
+enum Lenient {
+    One { a: u8 } = 1,
+    #[bondrewd(invalid)]
+    Other { a: u8 },
+}
+
+#[test]
+fn try_reserve() -> anyhow::Result<()> {
+    let bytes = [0b0101_0000, 0b0000_0011];
+    assert_eq!(
+        Reserved::try_from_bytes(bytes)?,
+        Reserved {
+            one: 5,
+            reserve: 0,
+            two: 3,
+        }
+    );
+    let err = Reserved::try_from_bytes([0b0101_0000, 0b0100_0011]).unwrap_err();
+    assert_eq!(err.field, "reserve");
+    assert_eq!(err.bit_range, 4..10);
+    assert_eq!(err.kind, BitfieldTryErrorKind::NonZeroReserve);
+    Ok(())
+}
+
+#[test]
+fn try_char() -> anyhow::Result<()> {
+    let bytes = Chars {
+        one: 1,
+        letter: 'b',
+    }
+    .into_bytes();
+    assert_eq!(Chars::try_from_bytes(bytes)?.letter, 'b');
+    // 0xD800 is a surrogate which is not a valid char.
+    let bytes = [1, 0x00, 0x00, 0xD8, 0x00];
+    assert_eq!(Chars::from_bytes(bytes).letter, '�');
+    let err = Chars::try_from_bytes(bytes).unwrap_err();
+    assert_eq!(err.field, "letter");
+    assert_eq!(err.bit_range, 8..40);
+    assert_eq!(err.kind, BitfieldTryErrorKind::InvalidChar(0xD800));
+    Ok(())
+}
+
+#[test]
+fn try_enum() -> anyhow::Result<()> {
+    let bytes = Strict::Two { a: 0x0123 }.into_bytes();
+    assert_eq!(Strict::try_from_bytes(bytes)?, Strict::Two { a: 0x0123 });
+    let err = Strict::try_from_bytes([0b0011_0000, 0, 0]).unwrap_err();
+    assert_eq!(err.field, "variant_id");
+    assert_eq!(err.bit_range, 0..4);
+    assert_eq!(err.kind, BitfieldTryErrorKind::InvalidVariant(3));
+
+    // enums with an invalid variant accept any id.
+    assert_eq!(
+        Lenient::try_from_bytes([0b0011_0000, 0b0001_0000])?,
+        Lenient::Other { a: 1 }
+    );
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le", read_from = "lsb0", reverse)]
+struct ReservedLsb {
+    #[bondrewd(bit_length = 5)]
+    one: u8,
+    #[bondrewd(bit_length = 7, reserve)]
+    reserve: u8,
+    #[bondrewd(bit_length = 9)]
+    two: u16,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le", read_from = "lsb0", reverse)]
+struct NotReservedLsb {
+    #[bondrewd(bit_length = 5)]
+    one: u8,
+    #[bondrewd(bit_length = 7)]
+    reserve: u8,
+    #[bondrewd(bit_length = 9)]
+    two: u16,
+}
+
+#[test]
+fn try_reserve_lsb0_reverse() {
+    let bytes = NotReservedLsb {
+        one: 0b1_1111,
+        reserve: 0,
+        two: 0b1_1111_1111,
+    }
+    .into_bytes();
+    assert!(ReservedLsb::try_from_bytes(bytes).is_ok());
+    let bytes = NotReservedLsb {
+        one: 0,
+        reserve: 0b100_0000,
+        two: 0,
+    }
+    .into_bytes();
+    let err = ReservedLsb::try_from_bytes(bytes).unwrap_err();
+    assert_eq!(err.field, "reserve");
+    assert_eq!(err.kind, BitfieldTryErrorKind::NonZeroReserve);
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Outer {
+    #[bondrewd(bit_length = 40)]
+    letter: Chars,
+    x: u8,
+    #[bondrewd(bit_length = 20)]
+    inner: Strict,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 8)]
+enum OuterEnum {
+    #[bondrewd(variant_id = 1)]
+    Holds {
+        #[bondrewd(bit_length = 20)]
+        inner: Strict,
+    },
+    #[bondrewd(variant_id = 2)]
+    Empty,
+}
+
+#[test]
+fn try_nested() -> anyhow::Result<()> {
+    let outer = Outer {
+        letter: Chars {
+            one: 1,
+            letter: 'a',
+        },
+        x: 7,
+        inner: Strict::One { a: 9 },
+    };
+    let bytes = outer.clone().into_bytes();
+    assert_eq!(Outer::try_from_bytes(bytes)?, outer);
+
+    // the nested enum id is checked by the nested try_from_bytes, the error names the outer field
+    // and the bits of the id within the outer structure.
+    let mut bad_id = bytes;
+    bad_id[6] = 0x90;
+    let err = Outer::try_from_bytes(bad_id).unwrap_err();
+    assert_eq!(err.field, "inner");
+    assert_eq!(err.bit_range, 48..52);
+    assert_eq!(err.kind, BitfieldTryErrorKind::InvalidVariant(9));
+
+    // so are the fields of nested structures.
+    let mut bad_char = bytes;
+    bad_char[3] = 0xD8;
+    let err = Outer::try_from_bytes(bad_char).unwrap_err();
+    assert_eq!(err.field, "letter");
+    assert_eq!(err.bit_range, 8..40);
+    assert_eq!(err.kind, BitfieldTryErrorKind::InvalidChar(0xD861));
+
+    let holds = OuterEnum::Holds {
+        inner: Strict::Two { a: 3 },
+    };
+    let bytes = holds.clone().into_bytes();
+    assert_eq!(OuterEnum::try_from_bytes(bytes)?, holds);
+    let err = OuterEnum::try_from_bytes([1, 0xF0, 0, 0]).unwrap_err();
+    assert_eq!(err.field, "Holds::inner");
+    assert_eq!(err.bit_range, 8..12);
+    assert_eq!(err.kind, BitfieldTryErrorKind::InvalidVariant(15));
+    Ok(())
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct NestedArray {
+    one: u8,
+    #[bondrewd(struct_size = 3, bit_length = 40)]
+    inner: [Strict; 2],
+}
+
+#[test]
+fn try_nested_array() -> anyhow::Result<()> {
+    let array = NestedArray {
+        one: 1,
+        inner: [Strict::One { a: 2 }, Strict::Two { a: 3 }],
+    };
+    let bytes = array.clone().into_bytes();
+    assert_eq!(NestedArray::try_from_bytes(bytes)?, array);
+    // the first element loses the 8 bits the array does not have, so the id of the second element
+    // starts 24 bits in.
+    let mut bad_id = bytes;
+    bad_id[3] = (bad_id[3] & 0x0F) | 0xF0;
+    let err = NestedArray::try_from_bytes(bad_id).unwrap_err();
+    assert_eq!(err.field, "inner");
+    assert_eq!(err.bit_range, 24..28);
+    assert_eq!(err.kind, BitfieldTryErrorKind::InvalidVariant(15));
+    Ok(())
+}
+
+/// A hand written implementation, which only has the infallible `Bitfields` functions.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Manual(u8);
+
+impl Bitfields<1> for Manual {
+    const BIT_SIZE: usize = 8;
+    fn write_to(&self, output_byte_buffer: &mut [u8; 1]) {
+        *output_byte_buffer = [self.0];
+    }
+    fn from_bytes(input_byte_buffer: [u8; 1]) -> Self {
+        Self(input_byte_buffer[0])
+    }
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct HoldsManual {
+    #[bondrewd(bit_length = 4, reserve)]
+    reserve: u8,
+    #[bondrewd(bit_length = 8)]
+    manual: Manual,
+    #[bondrewd(bit_length = 4)]
+    four: u8,
+}
+
+#[test]
+fn try_nested_manual() -> anyhow::Result<()> {
+    let bytes = [0x0A, 0xB3];
+    assert_eq!(
+        HoldsManual::try_from_bytes(bytes)?,
+        HoldsManual {
+            reserve: 0,
+            manual: Manual(0xAB),
+            four: 3,
+        }
+    );
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use bondrewd::{Bitfields, BitfieldsVariable};
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Entry {
+    #[bondrewd(bit_length = 4)]
+    kind: u8,
+    #[bondrewd(bit_length = 12)]
+    value: u16,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Entries {
+    #[bondrewd(bit_length = 3)]
+    version: u8,
+    #[bondrewd(bit_length = 5)]
+    num_entries: u8,
+    #[bondrewd(count_from = "num_entries", max = 4, element_byte_length = 2)]
+    entries: Vec<Entry>,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "le")]
+struct Samples {
+    count: u8,
+    #[bondrewd(count_from = "count", max = 8)]
+    samples: Vec<u16>,
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be")]
+struct Fixed {
+    count: u8,
+    #[bondrewd(count_from = "count", max = 4, element_byte_length = 2)]
+    entries: heapless::Vec<Entry, 4>,
+}
+
+#[test]
+fn variable_struct_elements() -> anyhow::Result<()> {
+    let entries = Entries {
+        version: 5,
+        // count fields are always written using the length of the array.
+        num_entries: 0,
+        entries: vec![
+            Entry { kind: 1, value: 0x234 },
+            Entry { kind: 0xF, value: 0x001 },
+        ],
+    };
+    assert_eq!(Entries::HEADER_BYTE_SIZE, 1);
+    assert_eq!(Entries::ELEMENT_BYTE_SIZE, 2);
+    assert_eq!(Entries::MAX_BYTE_SIZE, 9);
+    assert_eq!(entries.byte_size(), 5);
+    let bytes = entries.clone().into_vec()?;
+    assert_eq!(bytes, vec![0b101_00010, 0x12, 0x34, 0xF0, 0x01]);
+    assert_eq!(Entries::read_num_entries(&[bytes[0]]), 2);
+
+    let new_entries = Entries::from_slice(&bytes)?;
+    assert_eq!(new_entries.num_entries, 2);
+    assert_eq!(new_entries.entries, entries.entries);
+    Ok(())
+}
+
+#[test]
+fn variable_primitive_elements() -> anyhow::Result<()> {
+    let samples = Samples {
+        count: 3,
+        samples: vec![1, 0x0203, 0xFFFF],
+    };
+    let mut bytes = [0u8; 10];
+    assert_eq!(samples.clone().into_slice(&mut bytes)?, 7);
+    assert_eq!(bytes, [3, 0x01, 0x00, 0x03, 0x02, 0xFF, 0xFF, 0, 0, 0]);
+    // extra bytes are ignored.
+    assert_eq!(Samples::from_slice(&bytes)?, samples);
+    Ok(())
+}
+
+#[test]
+fn variable_heapless_elements() -> anyhow::Result<()> {
+    let mut entries = heapless::Vec::new();
+    entries
+        .push(Entry {
+            kind: 2,
+            value: 0xABC,
+        })
+        .unwrap();
+    entries.push(Entry { kind: 0, value: 7 }).unwrap();
+    let fixed = Fixed { count: 0, entries };
+    assert_eq!(Fixed::MAX_BYTE_SIZE, 9);
+    let mut bytes = [0u8; 9];
+    assert_eq!(fixed.clone().into_slice(&mut bytes)?, 5);
+    assert_eq!(bytes[..5], [2, 0x2A, 0xBC, 0x00, 0x07]);
+    let new_fixed = Fixed::from_slice(&bytes)?;
+    assert_eq!(new_fixed.count, 2);
+    assert_eq!(new_fixed.entries, fixed.entries);
+    Ok(())
+}
+
+#[test]
+fn variable_errors() {
+    // not enough bytes for the amount of elements the count field describes.
+    assert!(matches!(
+        Samples::from_slice(&[2, 0x01, 0x00, 0x03]),
+        Err(bondrewd::BitfieldVariableError::Length(_))
+    ));
+    // count field is larger than max.
+    assert!(matches!(
+        Samples::from_slice(&[9; 20]),
+        Err(bondrewd::BitfieldVariableError::Count(9, 8))
+    ));
+    let too_many = Samples {
+        count: 0,
+        samples: vec![0; 9],
+    };
+    assert!(matches!(
+        too_many.into_vec(),
+        Err(bondrewd::BitfieldVariableError::Count(9, 8))
+    ));
+    let mut small = [0u8; 2];
+    assert!(matches!(
+        Samples {
+            count: 0,
+            samples: vec![0; 2],
+        }
+        .into_slice(&mut small),
+        Err(bondrewd::BitfieldVariableError::Length(_))
+    ));
+}
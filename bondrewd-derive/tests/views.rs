@@ -0,0 +1,107 @@
+use bondrewd::Bitfields;
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", reverse)]
+struct Header {
+    #[bondrewd(bit_length = 3)]
+    version: u8,
+    flag: bool,
+    #[bondrewd(bit_length = 12)]
+    length: u16,
+    #[bondrewd(element_bit_length = 4)]
+    nibbles: [u8; 2],
+}
+
+#[derive(Bitfields, Clone, PartialEq, Eq, Debug)]
+#[bondrewd(default_endianness = "be", id_bit_length = 2, enforce_bytes = 2)]
+enum Command {
+    Stop,
+    Move {
+        #[bondrewd(bit_length = 14)]
+        distance: u16,
+    },
+    Other {
+        #[bondrewd(capture_id)]
+        id: u8,
+    },
+}
+
+fn header() -> Header {
+    Header {
+        version: 5,
+        flag: true,
+        length: 0xABC,
+        nibbles: [3, 12],
+    }
+}
+
+#[test]
+fn view_reads_from_any_container() -> anyhow::Result<()> {
+    let bytes = header().into_bytes();
+
+    let view = HeaderView::new(bytes)?;
+    assert_eq!(view.read_version(), 5);
+    assert!(view.read_flag());
+    assert_eq!(view.read_length(), 0xABC);
+    assert_eq!(view.read_nibbles(), [3, 12]);
+    assert_eq!(view.into_inner(), bytes);
+
+    let view = HeaderView::new(&bytes[..])?;
+    assert_eq!(view.read_length(), Header::read_length(&bytes));
+
+    let mut vec = bytes.to_vec();
+    vec.push(0xFF);
+    let view = HeaderView::new(vec)?;
+    assert_eq!(view.read_nibbles(), [3, 12]);
+    assert_eq!(view.into_inner().len(), Header::BYTE_SIZE + 1);
+
+    match HeaderView::new(&bytes[..2]) {
+        Err(bondrewd::BitfieldLengthError(2, 3)) => {}
+        Err(err) => panic!("wrong error {err}"),
+        Ok(_) => panic!("view of 2 bytes should fail"),
+    }
+    Ok(())
+}
+
+#[test]
+fn view_mut_writes_in_place() -> anyhow::Result<()> {
+    let mut view = HeaderViewMut::new(vec![0u8; Header::BYTE_SIZE])?;
+    view.write_version(5);
+    view.write_flag(true);
+    view.write_length(0xABC);
+    view.write_nibbles([3, 12]);
+    assert_eq!(view.read_length(), 0xABC);
+    let vec = view.into_inner();
+    assert_eq!(vec[..], header().into_bytes()[..]);
+
+    let mut bytes = header().into_bytes();
+    {
+        let mut view = HeaderViewMut::new(&mut bytes[..])?;
+        view.write_length(7);
+    }
+    assert_eq!(
+        Header::from_bytes(bytes),
+        Header {
+            length: 7,
+            ..header()
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn enum_views() -> anyhow::Result<()> {
+    let bytes = Command::Move { distance: 1000 }.into_bytes();
+    let view = CommandView::new(bytes)?;
+    assert_eq!(view.read_variant_id(), 1);
+    assert_eq!(view.read_move_distance(), 1000);
+
+    let mut view = CommandViewMut::new([0u8; Command::BYTE_SIZE])?;
+    view.write_variant_id(1);
+    view.write_move_distance(1234);
+    assert_eq!(
+        Command::from_bytes(view.into_inner()),
+        Command::Move { distance: 1234 }
+    );
+    Ok(())
+}
@@ -0,0 +1,418 @@
+//! Runtime cursors for reading and writing numbers at any bit offset within a byte slice, using the
+//! same bit layout the [Bitfields](crate::Bitfields) derive generates code for. This allows the parts
+//! of a protocol that can not be described by a structure to be handled consistently with the derived
+//! parts.
+//!
+//! # Layout
+//! - Bit positions are [Msb0](BitOrder::Msb0) by default, bit 0 is the most significant bit of the
+//!   first byte. With [Lsb0](BitOrder::Lsb0) bit 0 is the least significant bit of the last byte and
+//!   the cursor moves towards the start of the slice, like `read_from = "lsb0"`. Derived structures
+//!   count Lsb0 bits from their `BIT_SIZE` instead of the end of the last byte, use `with_bit_size`
+//!   to do the same.
+//! - [Big](Endianness::Big) endian values are placed most significant bit first.
+//! - [Little](Endianness::Little) endian values are placed as the little endian bytes of the value,
+//!   each byte most significant bit first. When the bit length is not a multiple of 8 the last
+//!   (most significant) byte only contributes its lower bits.
+//! - `reverse` flips the byte order of the entire slice, like `#[bondrewd(reverse)]`.
+//! - Signed values are sign extended from the highest bit of the field.
+use crate::BitfieldLengthError;
+
+/// Byte order of a value within the bits it occupies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    Big,
+    Little,
+}
+
+/// Which bit of the slice is bit 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum BitOrder {
+    /// Bit 0 is the most significant bit of the first byte.
+    #[default]
+    Msb0,
+    /// Bit 0 is the least significant bit of the last byte.
+    Lsb0,
+}
+
+/// Primitive types a [BitReader] or [BitWriter] can read or write.
+pub trait BitPrimitive: Sized {
+    /// Maximum amount of bits the type can hold.
+    const BITS: usize;
+    /// Converts the lower `bit_length` bits of `bits` into the type, sign extending signed types.
+    fn from_bits(bits: u128, bit_length: usize) -> Self;
+    /// Converts the type into bits, only the lower `bit_length` bits will be used.
+    fn into_bits(self) -> u128;
+}
+
+macro_rules! impl_unsigned_bit_primitive {
+    ($($ty:ty),*) => {
+        $(
+            impl BitPrimitive for $ty {
+                const BITS: usize = <$ty>::BITS as usize;
+                fn from_bits(bits: u128, _bit_length: usize) -> Self {
+                    bits as $ty
+                }
+                fn into_bits(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed_bit_primitive {
+    ($($ty:ty => $unsigned:ty),*) => {
+        $(
+            impl BitPrimitive for $ty {
+                const BITS: usize = <$ty>::BITS as usize;
+                fn from_bits(bits: u128, bit_length: usize) -> Self {
+                    if bit_length == 0 {
+                        return 0;
+                    }
+                    let shift = 128 - bit_length;
+                    ((bits << shift) as i128 >> shift) as $ty
+                }
+                fn into_bits(self) -> u128 {
+                    self as $unsigned as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_bit_primitive!(u8, u16, u32, u64, u128);
+impl_signed_bit_primitive!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128);
+
+impl BitPrimitive for bool {
+    const BITS: usize = 1;
+    fn from_bits(bits: u128, _bit_length: usize) -> Self {
+        bits & 1 != 0
+    }
+    fn into_bits(self) -> u128 {
+        self as u128
+    }
+}
+
+impl BitPrimitive for f32 {
+    const BITS: usize = 32;
+    fn from_bits(bits: u128, _bit_length: usize) -> Self {
+        f32::from_bits(bits as u32)
+    }
+    fn into_bits(self) -> u128 {
+        self.to_bits() as u128
+    }
+}
+
+impl BitPrimitive for f64 {
+    const BITS: usize = 64;
+    fn from_bits(bits: u128, _bit_length: usize) -> Self {
+        f64::from_bits(bits as u64)
+    }
+    fn into_bits(self) -> u128 {
+        self.to_bits() as u128
+    }
+}
+
+/// Layout options shared by [BitReader] and [BitWriter].
+#[derive(Clone, Copy, Debug)]
+struct Layout {
+    len: usize,
+    bit_size: usize,
+    order: BitOrder,
+    reverse: bool,
+}
+
+impl Layout {
+    /// Returns the Msb0 index of the first bit of a field `bit_length` bits long at `position`.
+    fn start(&self, position: usize, bit_length: usize) -> Result<usize, BitfieldLengthError> {
+        // a cursor moved past the end with `seek` can overflow.
+        let end = match position.checked_add(bit_length) {
+            Some(end) if end <= self.bit_size => end,
+            end => {
+                return Err(BitfieldLengthError(
+                    self.len,
+                    end.unwrap_or(usize::MAX).div_ceil(8),
+                ))
+            }
+        };
+        Ok(match self.order {
+            BitOrder::Msb0 => position,
+            BitOrder::Lsb0 => self.bit_size - end,
+        })
+    }
+    fn byte_index(&self, index: usize) -> usize {
+        if self.reverse {
+            self.len - 1 - index
+        } else {
+            index
+        }
+    }
+    /// Splits `bit_length` bits into the chunks the value is stored in, as (bit_length, shift) pairs
+    /// in the order they appear in the slice. The shift is where the chunk lives within the value.
+    fn for_each_chunk(endianness: Endianness, bit_length: usize, mut f: impl FnMut(usize, usize)) {
+        match endianness {
+            Endianness::Big => {
+                let mut remaining = bit_length;
+                while remaining != 0 {
+                    let amount = if remaining.is_multiple_of(8) {
+                        8
+                    } else {
+                        remaining % 8
+                    };
+                    remaining -= amount;
+                    f(amount, remaining);
+                }
+            }
+            Endianness::Little => {
+                let mut shift = 0;
+                while shift < bit_length {
+                    let amount = (bit_length - shift).min(8);
+                    f(amount, shift);
+                    shift += amount;
+                }
+            }
+        }
+    }
+}
+
+/// Reads values at any bit offset from a byte slice.
+#[derive(Clone, Debug)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    layout: Layout,
+}
+
+impl<'a> BitReader<'a> {
+    /// Creates a [Msb0](BitOrder::Msb0) reader starting at bit 0 of `bytes`.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self::with_layout(bytes, BitOrder::Msb0, false)
+    }
+    /// Creates a reader starting at bit 0 of `bytes` using `order` to number the bits. If `reverse`
+    /// is true the byte order of `bytes` is flipped.
+    pub fn with_layout(bytes: &'a [u8], order: BitOrder, reverse: bool) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            layout: Layout {
+                len: bytes.len(),
+                bit_size: bytes.len() * 8,
+                order,
+                reverse,
+            },
+        }
+    }
+    /// Limits the reader to the first `bit_size` bits of the slice, values past it can not be read.
+    /// With [Lsb0](BitOrder::Lsb0) numbering bit 0 becomes the last bit within `bit_size`, which
+    /// matches a derived structure using `read_from = "lsb0"` when `bit_size` is its `BIT_SIZE`.
+    pub fn with_bit_size(mut self, bit_size: usize) -> Self {
+        self.layout.bit_size = bit_size.min(self.layout.len * 8);
+        self
+    }
+    /// Returns the bit position the next read will start at.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+    /// Moves the cursor to `position`.
+    pub fn seek(&mut self, position: usize) {
+        self.position = position;
+    }
+    /// Advances the cursor `bit_length` bits without reading them.
+    pub fn skip(&mut self, bit_length: usize) {
+        self.position = self.position.saturating_add(bit_length);
+    }
+    /// Returns the amount of bits after the cursor.
+    pub fn remaining(&self) -> usize {
+        self.layout.bit_size.saturating_sub(self.position)
+    }
+    /// Reads a `bit_length` bit value at the cursor and advances past it. `bit_length` is limited to
+    /// the size of `T`.
+    ///
+    /// # Errors
+    /// Returns [BitfieldLengthError] if the slice ends before the value does.
+    pub fn read<T: BitPrimitive>(
+        &mut self,
+        bit_length: usize,
+        endianness: Endianness,
+    ) -> Result<T, BitfieldLengthError> {
+        let bit_length = bit_length.min(T::BITS);
+        let value = self.peek_at(self.position, bit_length, endianness)?;
+        self.position += bit_length;
+        Ok(value)
+    }
+    /// Reads a single bit at the cursor as a `bool` and advances past it.
+    ///
+    /// # Errors
+    /// Returns [BitfieldLengthError] if the cursor is at the end of the slice.
+    pub fn read_bool(&mut self) -> Result<bool, BitfieldLengthError> {
+        self.read(1, Endianness::Big)
+    }
+    /// Reads a `bit_length` bit value at `position` without moving the cursor.
+    ///
+    /// # Errors
+    /// Returns [BitfieldLengthError] if the slice ends before the value does.
+    pub fn peek_at<T: BitPrimitive>(
+        &self,
+        position: usize,
+        bit_length: usize,
+        endianness: Endianness,
+    ) -> Result<T, BitfieldLengthError> {
+        let bit_length = bit_length.min(T::BITS);
+        let mut index = self.layout.start(position, bit_length)?;
+        let mut bits = 0u128;
+        Layout::for_each_chunk(endianness, bit_length, |amount, shift| {
+            bits |= (self.read_chunk(index, amount) as u128) << shift;
+            index += amount;
+        });
+        Ok(T::from_bits(bits, bit_length))
+    }
+    /// Reads `amount` (at most 8) bits starting at the Msb0 `index`.
+    fn read_chunk(&self, index: usize, amount: usize) -> u8 {
+        let first = self.bytes[self.layout.byte_index(index / 8)] as u16;
+        let zeros_on_left = index % 8;
+        let window = if zeros_on_left + amount > 8 {
+            (first << 8) | self.bytes[self.layout.byte_index((index / 8) + 1)] as u16
+        } else {
+            first << 8
+        };
+        ((window >> (16 - zeros_on_left - amount)) & ((1u16 << amount) - 1)) as u8
+    }
+}
+
+/// Writes values at any bit offset within a mutable byte slice. Bits outside of the written values
+/// are left untouched.
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    bytes: &'a mut [u8],
+    position: usize,
+    layout: Layout,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Creates a [Msb0](BitOrder::Msb0) writer starting at bit 0 of `bytes`.
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self::with_layout(bytes, BitOrder::Msb0, false)
+    }
+    /// Creates a writer starting at bit 0 of `bytes` using `order` to number the bits. If `reverse`
+    /// is true the byte order of `bytes` is flipped.
+    pub fn with_layout(bytes: &'a mut [u8], order: BitOrder, reverse: bool) -> Self {
+        let len = bytes.len();
+        Self {
+            bytes,
+            position: 0,
+            layout: Layout {
+                len,
+                bit_size: len * 8,
+                order,
+                reverse,
+            },
+        }
+    }
+    /// Limits the writer to the first `bit_size` bits of the slice, values past it can not be written.
+    /// With [Lsb0](BitOrder::Lsb0) numbering bit 0 becomes the last bit within `bit_size`, which
+    /// matches a derived structure using `read_from = "lsb0"` when `bit_size` is its `BIT_SIZE`.
+    pub fn with_bit_size(mut self, bit_size: usize) -> Self {
+        self.layout.bit_size = bit_size.min(self.layout.len * 8);
+        self
+    }
+    /// Returns the bit position the next write will start at.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+    /// Moves the cursor to `position`.
+    pub fn seek(&mut self, position: usize) {
+        self.position = position;
+    }
+    /// Advances the cursor `bit_length` bits without writing them.
+    pub fn skip(&mut self, bit_length: usize) {
+        self.position = self.position.saturating_add(bit_length);
+    }
+    /// Returns the amount of bits after the cursor.
+    pub fn remaining(&self) -> usize {
+        self.layout.bit_size.saturating_sub(self.position)
+    }
+    /// Writes the lower `bit_length` bits of `value` at the cursor and advances past them.
+    /// `bit_length` is limited to the size of `T`.
+    ///
+    /// # Errors
+    /// Returns [BitfieldLengthError] if the slice ends before the value does, nothing is written.
+    pub fn write<T: BitPrimitive>(
+        &mut self,
+        value: T,
+        bit_length: usize,
+        endianness: Endianness,
+    ) -> Result<(), BitfieldLengthError> {
+        let bit_length = bit_length.min(T::BITS);
+        self.write_at(self.position, value, bit_length, endianness)?;
+        self.position += bit_length;
+        Ok(())
+    }
+    /// Writes a `bool` as a single bit at the cursor and advances past it.
+    ///
+    /// # Errors
+    /// Returns [BitfieldLengthError] if the cursor is at the end of the slice.
+    pub fn write_bool(&mut self, value: bool) -> Result<(), BitfieldLengthError> {
+        self.write(value, 1, Endianness::Big)
+    }
+    /// Writes the lower `bit_length` bits of `value` at `position` without moving the cursor.
+    ///
+    /// # Errors
+    /// Returns [BitfieldLengthError] if the slice ends before the value does, nothing is written.
+    pub fn write_at<T: BitPrimitive>(
+        &mut self,
+        position: usize,
+        value: T,
+        bit_length: usize,
+        endianness: Endianness,
+    ) -> Result<(), BitfieldLengthError> {
+        let bit_length = bit_length.min(T::BITS);
+        let mut index = self.layout.start(position, bit_length)?;
+        let bits = value.into_bits();
+        Layout::for_each_chunk(endianness, bit_length, |amount, shift| {
+            self.write_chunk(index, amount, (bits >> shift) as u8);
+            index += amount;
+        });
+        Ok(())
+    }
+    /// Writes the lower `amount` (at most 8) bits of `chunk` starting at the Msb0 `index`.
+    fn write_chunk(&mut self, index: usize, amount: usize, chunk: u8) {
+        let zeros_on_left = index % 8;
+        let shift = 16 - zeros_on_left - amount;
+        let mask = ((1u16 << amount) - 1) << shift;
+        let window = ((chunk as u16) << shift) & mask;
+        let first = self.layout.byte_index(index / 8);
+        self.bytes[first] = (self.bytes[first] & !((mask >> 8) as u8)) | (window >> 8) as u8;
+        if zeros_on_left + amount > 8 {
+            let second = self.layout.byte_index((index / 8) + 1);
+            self.bytes[second] = (self.bytes[second] & !(mask as u8)) | window as u8;
+        }
+    }
+}
+
+/// Copies `bit_length` bits starting at the [Msb0](BitOrder::Msb0) bit `from_start` of `from` to
+/// the bits starting at `to_start` of `to`, derived enums use this to remove and insert the bits of
+/// their id.
+///
+/// # Panics
+/// Panics if either range goes past the end of its slice.
+pub fn copy_bits(
+    from: &[u8],
+    from_start: usize,
+    to: &mut [u8],
+    to_start: usize,
+    bit_length: usize,
+) {
+    let reader = BitReader::new(from);
+    let mut writer = BitWriter::new(to);
+    let mut copied = 0;
+    while copied < bit_length {
+        let amount = (bit_length - copied).min(8);
+        let chunk: u8 = reader
+            .peek_at(from_start + copied, amount, Endianness::Big)
+            .expect("copy_bits source range goes past the end of the slice");
+        writer
+            .write_at(to_start + copied, chunk, amount, Endianness::Big)
+            .expect("copy_bits destination range goes past the end of the slice");
+        copied += amount;
+    }
+}
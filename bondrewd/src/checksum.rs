@@ -0,0 +1,125 @@
+//! Checksums used by `crc` and `parity` fields, which `into_bytes` and `write_to` fill in and
+//! `verify_{field}` functions check.
+
+/// Parameters of a CRC algorithm using the "Rocksoft" model, `width` must be between 8 and 64.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Crc {
+    /// Amount of bits in the checksum.
+    pub width: u32,
+    /// Generator polynomial, without the leading 1 bit.
+    pub poly: u64,
+    /// Initial value of the register.
+    pub init: u64,
+    /// Reflect each input byte before processing it.
+    pub reflect_in: bool,
+    /// Reflect the register before `xor_out` is applied.
+    pub reflect_out: bool,
+    /// Value xor-ed with the register to get the checksum.
+    pub xor_out: u64,
+}
+
+impl Crc {
+    /// Computes the checksum of `bytes`.
+    pub fn checksum(&self, bytes: &[u8]) -> u64 {
+        let mask = u64::MAX >> (64 - self.width);
+        let top = 1u64 << (self.width - 1);
+        let mut crc = self.init & mask;
+        for byte in bytes {
+            let byte = if self.reflect_in {
+                byte.reverse_bits()
+            } else {
+                *byte
+            };
+            crc ^= (byte as u64) << (self.width - 8);
+            for _ in 0..8 {
+                crc = if crc & top != 0 {
+                    (crc << 1) ^ self.poly
+                } else {
+                    crc << 1
+                } & mask;
+            }
+        }
+        if self.reflect_out {
+            crc = crc.reverse_bits() >> (64 - self.width);
+        }
+        (crc ^ self.xor_out) & mask
+    }
+}
+
+/// CRC-8/SMBUS, `crc = "crc8"`.
+pub const CRC8: Crc = Crc {
+    width: 8,
+    poly: 0x07,
+    init: 0x00,
+    reflect_in: false,
+    reflect_out: false,
+    xor_out: 0x00,
+};
+
+/// CRC-16/CCITT-FALSE (also known as CRC-16/IBM-3740), used by CCSDS frames, `crc = "crc16_ccitt_false"`.
+pub const CRC16_CCITT_FALSE: Crc = Crc {
+    width: 16,
+    poly: 0x1021,
+    init: 0xFFFF,
+    reflect_in: false,
+    reflect_out: false,
+    xor_out: 0x0000,
+};
+
+/// CRC-16/XMODEM, `crc = "crc16_xmodem"`.
+pub const CRC16_XMODEM: Crc = Crc {
+    width: 16,
+    poly: 0x1021,
+    init: 0x0000,
+    reflect_in: false,
+    reflect_out: false,
+    xor_out: 0x0000,
+};
+
+/// CRC-16/ARC, `crc = "crc16_arc"`.
+pub const CRC16_ARC: Crc = Crc {
+    width: 16,
+    poly: 0x8005,
+    init: 0x0000,
+    reflect_in: true,
+    reflect_out: true,
+    xor_out: 0x0000,
+};
+
+/// CRC-32/ISO-HDLC, the CRC used by ethernet and zip, `crc = "crc32"`.
+pub const CRC32: Crc = Crc {
+    width: 32,
+    poly: 0x04C1_1DB7,
+    init: 0xFFFF_FFFF,
+    reflect_in: true,
+    reflect_out: true,
+    xor_out: 0xFFFF_FFFF,
+};
+
+/// CRC-32/ISCSI (Castagnoli), `crc = "crc32c"`.
+pub const CRC32C: Crc = Crc {
+    width: 32,
+    poly: 0x1EDC_6F41,
+    init: 0xFFFF_FFFF,
+    reflect_in: true,
+    reflect_out: true,
+    xor_out: 0xFFFF_FFFF,
+};
+
+/// Counts the set bits in `bytes` after masking the first byte with `first_mask` and the last
+/// byte with `last_mask`, so parity can cover ranges which do not start or end on a byte boundary.
+pub fn count_ones(bytes: &[u8], first_mask: u8, last_mask: u8) -> u32 {
+    let last = bytes.len().saturating_sub(1);
+    let mut ones = 0;
+    for (i, byte) in bytes.iter().enumerate() {
+        let mut byte = *byte;
+        if i == 0 {
+            byte &= first_mask;
+        }
+        if i == last {
+            byte &= last_mask;
+        }
+        ones += byte.count_ones();
+    }
+    ones
+}
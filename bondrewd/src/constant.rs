@@ -0,0 +1,63 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// The zero-sized type of a `#[bondrewd(constant = VALUE)]` field, `T` is the number type (or `bool`)
+/// the value is stored as in bitfield form.
+///
+/// The value only exists in bitfield form, `into_bytes` and `write_{field}` always write it while
+/// `read_{field}` and `check_constants` read what the bytes contain.
+pub struct Constant<T>(PhantomData<T>);
+
+impl<T> Constant<T> {
+    /// Creates the marker, every `Constant<T>` is the same value.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for Constant<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for Constant<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Constant<T> {}
+
+impl<T> PartialEq for Constant<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for Constant<T> {}
+
+impl<T> Hash for Constant<T> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl<T> fmt::Debug for Constant<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "Constant")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Constant<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit_struct("Constant")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Constant<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <() as serde::Deserialize>::deserialize(deserializer).map(|()| Self::new())
+    }
+}
@@ -88,3 +88,165 @@ impl From<BitfieldLengthError> for BitfieldHexDynError {
         Self::Length(value)
     }
 }
+
+/// Error type describing why hex, base64 or binary text could not be decoded.
+#[cfg(feature = "hex_fns")]
+#[derive(Debug)]
+pub enum BitfieldTextError {
+    /// A character which is not valid at its position was found, the index counts characters (not bytes)
+    /// from the start of the text.
+    Character(BitfieldHexError),
+    /// The text did not contain the expected amount of digits (separators and prefixes are not counted).
+    Length(BitfieldLengthError),
+}
+
+#[cfg(feature = "hex_fns")]
+impl fmt::Display for BitfieldTextError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitfieldTextError::Character(err) => write!(fmt, "{}", err),
+            BitfieldTextError::Length(err) => write!(fmt, "{}", err),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", feature = "hex_fns"))]
+impl std::error::Error for BitfieldTextError {}
+
+#[cfg(feature = "hex_fns")]
+impl From<BitfieldHexError> for BitfieldTextError {
+    fn from(value: BitfieldHexError) -> Self {
+        Self::Character(value)
+    }
+}
+
+#[cfg(feature = "hex_fns")]
+impl From<BitfieldLengthError> for BitfieldTextError {
+    fn from(value: BitfieldLengthError) -> Self {
+        Self::Length(value)
+    }
+}
+
+/// Error type describing why a structure containing a variable length array could not be read or written.
+#[derive(Debug)]
+pub enum BitfieldVariableError {
+    /// Not enough bytes were provided.
+    Length(BitfieldLengthError),
+    /// The amount of elements is larger than the defined maximum.
+    Count(
+        /// Amount of elements found.
+        usize,
+        /// Maximum amount of elements allowed.
+        usize,
+    ),
+    /// A field does not contain a valid value.
+    Invalid(BitfieldTryError),
+}
+
+impl fmt::Display for BitfieldVariableError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitfieldVariableError::Length(err) => write!(fmt, "{}", err),
+            BitfieldVariableError::Count(found, max) => write!(
+                fmt,
+                "Found {} elements, the maximum is {}.",
+                found, max
+            ),
+            BitfieldVariableError::Invalid(err) => write!(fmt, "{}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitfieldVariableError {}
+
+impl From<BitfieldLengthError> for BitfieldVariableError {
+    fn from(value: BitfieldLengthError) -> Self {
+        Self::Length(value)
+    }
+}
+
+impl From<BitfieldTryError> for BitfieldVariableError {
+    fn from(value: BitfieldTryError) -> Self {
+        Self::Invalid(value)
+    }
+}
+
+/// Describes why the value of a field is not valid.
+#[derive(Debug, PartialEq, Eq)]
+pub enum BitfieldTryErrorKind {
+    /// The id does not belong to any variant and there is no invalid variant to catch it.
+    InvalidVariant(u128),
+    /// The value is not a valid unicode code point.
+    InvalidChar(u32),
+    /// A reserve field contained bits that were not zero, or did not match its `fill_pattern`.
+    NonZeroReserve,
+    /// A `length_of` field does not match the size of the field it measures.
+    LengthMismatch(
+        /// The value of the length field.
+        u128,
+        /// The adjusted size in bytes of the measured field.
+        i128,
+    ),
+    /// The adjusted size of the field a `length_of` field measures can not be stored in the length field.
+    LengthOutOfRange(i128),
+    /// A `constant` field does not contain its value.
+    ConstantMismatch(
+        /// The value of the field.
+        u128,
+        /// The value the field should contain.
+        u128,
+    ),
+    /// A `capture_id` field holds an id which does not belong to its variant, written as is it would
+    /// be read back as another variant.
+    CapturedIdMismatch(u128),
+    /// A value given to a checked setter, or checked by `check_fields`, does not fit in the bits of
+    /// its field.
+    ValueOutOfRange,
+}
+
+/// Error type describing a field that does not contain a valid value.
+#[derive(Debug)]
+pub struct BitfieldTryError {
+    /// Name of the field.
+    pub field: &'static str,
+    /// Bit indices the field occupies.
+    pub bit_range: core::ops::Range<usize>,
+    /// What was wrong with the value.
+    pub kind: BitfieldTryErrorKind,
+}
+
+impl fmt::Display for BitfieldTryError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "Field {} @ bits {}..{} ",
+            self.field, self.bit_range.start, self.bit_range.end
+        )?;
+        match self.kind {
+            BitfieldTryErrorKind::InvalidVariant(id) => {
+                write!(fmt, "contains id {} which is not a valid variant.", id)
+            }
+            BitfieldTryErrorKind::InvalidChar(code_point) => {
+                write!(fmt, "contains {} which is not a valid char.", code_point)
+            }
+            BitfieldTryErrorKind::NonZeroReserve => write!(fmt, "is reserved but was not zero."),
+            BitfieldTryErrorKind::LengthMismatch(found, expected) => {
+                write!(fmt, "contains length {} but {} was expected.", found, expected)
+            }
+            BitfieldTryErrorKind::LengthOutOfRange(length) => {
+                write!(fmt, "can not store the length {}.", length)
+            }
+            BitfieldTryErrorKind::ConstantMismatch(found, expected) => {
+                write!(fmt, "contains {} but the constant {} was expected.", found, expected)
+            }
+            BitfieldTryErrorKind::CapturedIdMismatch(id) => {
+                write!(fmt, "captured id {} which does not belong to its variant.", id)
+            }
+            BitfieldTryErrorKind::ValueOutOfRange => write!(fmt, "can not store the value in its bits."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitfieldTryError {}
@@ -54,6 +54,148 @@ where
         }
         output
     }
+    /// Extracts the values of the Bitfields in this structure from hex text, such as `0xDEADBEEF`,
+    /// `de ad be ef` or `DE:AD:BE:EF`.
+    ///
+    /// Digits may be separated by whitespace, `:` or `_`, and each group of digits may start with a
+    /// `0x` prefix. Exactly `HEX_SIZE` digits are required.
+    fn from_hex_str(hex: &str) -> Result<Self, crate::BitfieldTextError> {
+        Ok(Self::from_bytes(decode_digits(hex, 'x', 4)?))
+    }
+    /// Extracts the values of the Bitfields in this structure from `0b` binary text, such as
+    /// `0b1010_0101` or `10100101 00001111`.
+    ///
+    /// Digits may be separated by whitespace, `:` or `_`, and each group of digits may start with a
+    /// `0b` prefix. Exactly `BYTE_SIZE * 8` digits are required, the first digit is the most
+    /// significant bit of the first byte.
+    fn from_binary_str(binary: &str) -> Result<Self, crate::BitfieldTextError> {
+        Ok(Self::from_bytes(decode_digits(binary, 'b', 1)?))
+    }
+    /// Extracts the values of the Bitfields in this structure from base64 text.
+    ///
+    /// Both the standard (`+`, `/`) and url safe (`-`, `_`) alphabets are accepted, `=` padding is
+    /// optional and whitespace is ignored.
+    fn from_base64_str(base64: &str) -> Result<Self, crate::BitfieldTextError> {
+        Ok(Self::from_bytes(decode_base64(base64)?))
+    }
+    /// Writes the Bitfields in this structure as padded base64 text using the standard alphabet.
+    fn write_base64<W: core::fmt::Write>(&self, output: &mut W) -> core::fmt::Result {
+        let bytes = self.to_bytes();
+        for chunk in bytes.chunks(3) {
+            let mut group = [0u8; 3];
+            group[..chunk.len()].copy_from_slice(chunk);
+            let sextets = [
+                group[0] >> 2,
+                ((group[0] & 0b00000011) << 4) | (group[1] >> 4),
+                ((group[1] & 0b00001111) << 2) | (group[2] >> 6),
+                group[2] & 0b00111111,
+            ];
+            for (i, sextet) in sextets.into_iter().enumerate() {
+                if i <= chunk.len() {
+                    output.write_char(BASE64[sextet as usize] as char)?;
+                } else {
+                    output.write_char('=')?;
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Writes the Bitfields in this structure as binary digits, without a prefix or separators.
+    fn write_binary<W: core::fmt::Write>(&self, output: &mut W) -> core::fmt::Result {
+        for byte in self.to_bytes() {
+            write!(output, "{byte:08b}")?;
+        }
+        Ok(())
+    }
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || c == ':' || c == '_'
+}
+
+/// Decodes text where each digit holds `digit_bits` bits (4 for hex, 1 for binary), ignoring
+/// separators and a `0{prefix}` at the start of each group of digits.
+fn decode_digits<const BYTE_SIZE: usize>(
+    text: &str,
+    prefix: char,
+    digit_bits: usize,
+) -> Result<[u8; BYTE_SIZE], crate::BitfieldTextError> {
+    let expected = (BYTE_SIZE * 8) / digit_bits;
+    let mut bytes = [0u8; BYTE_SIZE];
+    let mut digits = 0usize;
+    let mut group_start = true;
+    let mut chars = text.chars().enumerate().peekable();
+    while let Some((i, c)) = chars.next() {
+        if is_separator(c) {
+            group_start = true;
+            continue;
+        }
+        if group_start && c == '0' {
+            if let Some((_, next)) = chars.peek() {
+                if next.eq_ignore_ascii_case(&prefix) {
+                    chars.next();
+                    group_start = false;
+                    continue;
+                }
+            }
+        }
+        group_start = false;
+        let value = match c.to_digit(1 << digit_bits) {
+            Some(value) => value as u8,
+            None => return Err(crate::BitfieldHexError(c, i).into()),
+        };
+        if digits < expected {
+            let bit = digits * digit_bits;
+            bytes[bit / 8] |= value << (8 - digit_bits - (bit % 8));
+        }
+        digits += 1;
+    }
+    if digits != expected {
+        return Err(crate::BitfieldLengthError(digits, expected).into());
+    }
+    Ok(bytes)
+}
+
+fn decode_base64<const BYTE_SIZE: usize>(
+    text: &str,
+) -> Result<[u8; BYTE_SIZE], crate::BitfieldTextError> {
+    let expected = (BYTE_SIZE * 8).div_ceil(6);
+    let mut bytes = [0u8; BYTE_SIZE];
+    let mut digits = 0usize;
+    let mut padding = 0usize;
+    for (i, c) in text.chars().enumerate() {
+        if c.is_whitespace() {
+            continue;
+        }
+        if c == '=' && padding < 2 {
+            padding += 1;
+            continue;
+        }
+        let value = match c {
+            'A'..='Z' if padding == 0 => c as u8 - b'A',
+            'a'..='z' if padding == 0 => c as u8 - b'a' + 26,
+            '0'..='9' if padding == 0 => c as u8 - b'0' + 52,
+            '+' | '-' if padding == 0 => 62,
+            '/' | '_' if padding == 0 => 63,
+            _ => return Err(crate::BitfieldHexError(c, i).into()),
+        };
+        if digits < expected {
+            // each digit is 6 bits which may continue into the next byte.
+            let bit = digits * 6;
+            let shift = bit % 8;
+            bytes[bit / 8] |= (value << 2) >> shift;
+            if shift > 2 && (bit / 8) + 1 < BYTE_SIZE {
+                bytes[(bit / 8) + 1] |= value << (10 - shift);
+            }
+        }
+        digits += 1;
+    }
+    if digits != expected {
+        return Err(crate::BitfieldLengthError(digits, expected).into());
+    }
+    Ok(bytes)
 }
 
 #[cfg(feature = "dyn_fns")]
@@ -0,0 +1,44 @@
+//! Helpers for reading and writing [Bitfields](crate::Bitfields) values back to back in a
+//! [Read] or [Write] stream, such as a file of records.
+use std::io::{Read, Write};
+
+use crate::Bitfields;
+
+/// Reads a single `T` from `reader`.
+///
+/// Only [Bitfields::STREAM_HEADER_BYTE_SIZE] Bytes are read first, then the rest of the Bytes
+/// [Bitfields::stream_byte_size] says the value uses. For derived enums this means the id is read
+/// first and then only as much of the payload as the variant needs.
+///
+/// # Errors
+/// Returns the error from `reader`, [std::io::ErrorKind::UnexpectedEof] if the stream ends
+/// before the value is complete.
+pub fn read_from<T, R, const SIZE: usize>(reader: &mut R) -> std::io::Result<T>
+where
+    T: Bitfields<SIZE>,
+    R: Read + ?Sized,
+{
+    let mut input_byte_buffer = [0u8; SIZE];
+    let header_size = T::STREAM_HEADER_BYTE_SIZE.min(SIZE);
+    reader.read_exact(&mut input_byte_buffer[..header_size])?;
+    let size = T::stream_byte_size(&input_byte_buffer).clamp(header_size, SIZE);
+    reader.read_exact(&mut input_byte_buffer[header_size..size])?;
+    Ok(T::from_bytes(input_byte_buffer))
+}
+
+/// Writes `value` to `writer` without consuming it.
+///
+/// Only the Bytes [Bitfields::stream_byte_size] says the value uses are written, so values written
+/// with this function can be read back with [read_from].
+///
+/// # Errors
+/// Returns the error from `writer`.
+pub fn write_to<T, W, const SIZE: usize>(value: &T, writer: &mut W) -> std::io::Result<()>
+where
+    T: Bitfields<SIZE>,
+    W: Write + ?Sized,
+{
+    let output_byte_buffer = value.to_bytes();
+    let size = T::stream_byte_size(&output_byte_buffer).min(SIZE);
+    writer.write_all(&output_byte_buffer[..size])
+}
@@ -5,8 +5,11 @@
 mod error;
 #[cfg(feature = "hex_fns")]
 pub use error::BitfieldHexError;
-#[cfg(feature = "dyn_fns")]
+#[cfg(feature = "hex_fns")]
+pub use error::BitfieldTextError;
 pub use error::BitfieldLengthError;
+pub use error::BitfieldVariableError;
+pub use error::{BitfieldTryError, BitfieldTryErrorKind};
 #[cfg(all(feature = "dyn_fns", feature = "hex_fns"))]
 pub use error::BitfieldHexDynError;
 
@@ -18,11 +21,112 @@ pub trait Bitfields<const SIZE: usize> {
     /// Inserts the values of the Bitfields in this structure into a fixed size array, consuming the structure.
     ///
     /// Returns a fixed sized byte array containing the Bitfields of the provided structure.
-    fn into_bytes(self) -> [u8; SIZE];
+    ///
+    /// The default implementation uses [Bitfields::write_to], derived implementations move the fields
+    /// out of the structure instead.
+    fn into_bytes(self) -> [u8; SIZE]
+    where
+        Self: Sized,
+    {
+        let mut output_byte_buffer = [0u8; SIZE];
+        self.write_to(&mut output_byte_buffer);
+        output_byte_buffer
+    }
+    /// Inserts the values of the Bitfields in this structure into a fixed size array without
+    /// consuming the structure.
+    ///
+    /// Returns a fixed sized byte array containing the Bitfields of the provided structure.
+    fn to_bytes(&self) -> [u8; SIZE] {
+        let mut output_byte_buffer = [0u8; SIZE];
+        self.write_to(&mut output_byte_buffer);
+        output_byte_buffer
+    }
+    /// Overwrites all of `output_byte_buffer` with the values of the Bitfields in this structure
+    /// without consuming the structure.
+    ///
+    /// Derived implementations write nested Bitfields fields with their own `write_to` and copy the
+    /// value of `enum_primitive` fields, which must be `Copy`.
+    fn write_to(&self, output_byte_buffer: &mut [u8; SIZE]);
     /// Extracts the values of the Bitfields in this structure from a fixed size array while consuming it.
     ///
     /// Returns Self with the fields containing the extracted values from provided fixed size array of bytes.
     fn from_bytes(input_byte_buffer: [u8; SIZE]) -> Self;
+    /// Extracts the values of the Bitfields in this structure from a fixed size array, rejecting
+    /// values [Bitfields::from_bytes] would silently accept. Derived [TryBitfields::try_from_bytes]
+    /// implementations use this to decode nested Bitfields fields.
+    ///
+    /// The default never fails, derived implementations return [TryBitfields::try_from_bytes].
+    ///
+    /// # Errors
+    /// Returns the errors [TryBitfields::try_from_bytes] describes.
+    fn checked_from_bytes(input_byte_buffer: [u8; SIZE]) -> Result<Self, BitfieldTryError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::from_bytes(input_byte_buffer))
+    }
+    /// Returns an error for the first field which holds a value that does not fit in its bits and would
+    /// be cut off by [Bitfields::into_bytes], nested Bitfields fields return the error of their own
+    /// `check_fields`.
+    ///
+    /// The default never fails, derived implementations check the fields of the structure (or of the
+    /// current variant).
+    ///
+    /// # Errors
+    /// Returns a [BitfieldTryErrorKind::ValueOutOfRange] error naming the field and its bit range.
+    fn check_fields(&self) -> Result<(), BitfieldTryError> {
+        Ok(())
+    }
+    /// Extracts the values of the Bitfields in this structure from a fixed size array directly into
+    /// an existing value.
+    ///
+    /// The default implementation, which derived enums also use, replaces the whole value with the
+    /// one [Bitfields::from_bytes] returns. Derived structures only overwrite the fields
+    /// [Bitfields::from_bytes] reads, so `reserve` fields keep their current value.
+    fn update_from(&mut self, input_byte_buffer: &[u8; SIZE])
+    where
+        Self: Sized,
+    {
+        *self = Self::from_bytes(*input_byte_buffer);
+    }
+    /// Amount of Bytes [io::read_from] reads before asking [Bitfields::stream_byte_size] how many
+    /// Bytes the value actually uses. Derived enums set this to the amount of Bytes the id uses.
+    const STREAM_HEADER_BYTE_SIZE: usize = SIZE;
+    /// Returns the amount of Bytes from the start of `input_byte_buffer` that contain the value, only
+    /// the first [Bitfields::STREAM_HEADER_BYTE_SIZE] Bytes are required to be filled in. Derived enums
+    /// return the amount of Bytes the variant described by the id uses, without the filler at the end.
+    fn stream_byte_size(_input_byte_buffer: &[u8; SIZE]) -> usize {
+        SIZE
+    }
+}
+
+/// Fallible versions of [Bitfields::from_bytes] and [Bitfields::into_bytes] which reject values the
+/// infallible versions would silently accept.
+pub trait TryBitfields<const SIZE: usize>: Bitfields<SIZE>
+where
+    Self: Sized,
+{
+    /// Extracts the values of the Bitfields in this structure from a fixed size array while consuming it.
+    ///
+    /// # Errors
+    /// Returns an error naming the field and its bit range if:
+    /// - An enum id does not match any variant and no variant is marked `invalid`.
+    /// - A `char` field does not contain a valid code point.
+    /// - A `reserve` field contains bits that are not zero (or do not match its `fill_pattern`).
+    /// - A `length_of` field does not match the size of the field it measures.
+    /// - A `constant` field does not contain its value.
+    /// - A nested Bitfields field returns an error from [Bitfields::checked_from_bytes], which is
+    ///   returned with the name of the outer field and the bit range moved to where the nested
+    ///   value is stored.
+    fn try_from_bytes(input_byte_buffer: [u8; SIZE]) -> Result<Self, BitfieldTryError>;
+    /// Fallible version of [Bitfields::into_bytes], the default never fails.
+    ///
+    /// # Errors
+    /// Returns an error naming the field and its bit range if a `capture_id` field of an enum holds an
+    /// id which does not belong to its variant, which [Bitfields::into_bytes] would write as is.
+    fn try_into_bytes(self) -> Result<[u8; SIZE], BitfieldTryError> {
+        Ok(self.into_bytes())
+    }
 }
 
 #[cfg(feature = "dyn_fns")]
@@ -34,6 +138,45 @@ where
     fn from_slice(input_byte_buffer: &[u8]) -> Result<Self, BitfieldLengthError>;
 }
 
+/// Implemented for structures with a `count_from` array, which can not implement [Bitfields] because
+/// the amount of bytes they use is only known at runtime. The fixed size fields are always placed
+/// before the variable length array.
+pub trait BitfieldsVariable
+where
+    Self: Sized,
+{
+    /// Amount of Bytes the fixed size fields take.
+    const HEADER_BYTE_SIZE: usize;
+    /// Amount of Bytes each element of the variable length array takes.
+    const ELEMENT_BYTE_SIZE: usize;
+    /// Maximum amount of elements the variable length array can contain.
+    const MAX_ELEMENTS: usize;
+    /// Maximum amount of Bytes this structure can take.
+    const MAX_BYTE_SIZE: usize =
+        Self::HEADER_BYTE_SIZE + (Self::ELEMENT_BYTE_SIZE * Self::MAX_ELEMENTS);
+    /// Returns the amount of Bytes this structure will take in bitfield form.
+    fn byte_size(&self) -> usize;
+    /// Extracts the fixed size fields, then uses the count field to determine how many elements
+    /// of the variable length array to extract.
+    ///
+    /// # Errors
+    /// Returns an error if there are not enough bytes, the count is larger than the maximum or
+    /// a `length_of` field does not match the size of the field it measures.
+    fn from_slice(input_byte_buffer: &[u8]) -> Result<Self, BitfieldVariableError>;
+    /// Inserts the values of this structure into the start of `output_byte_buffer`, consuming the
+    /// structure. The count field is always written as the length of the variable length array.
+    ///
+    /// Returns the amount of bytes written.
+    fn into_slice(self, output_byte_buffer: &mut [u8]) -> Result<usize, BitfieldVariableError>;
+    /// Inserts the values of this structure into a new `Vec`, consuming the structure.
+    #[cfg(feature = "std")]
+    fn into_vec(self) -> Result<Vec<u8>, BitfieldVariableError> {
+        let mut output = vec![0u8; self.byte_size()];
+        self.into_slice(&mut output)?;
+        Ok(output)
+    }
+}
+
 #[deprecated(
     since = "0.1.15",
     note = "please use `Bitfields` instead of `BitfieldEnum`"
@@ -43,12 +186,26 @@ pub trait BitfieldEnum {
     fn from_primitive(prim: Self::Primitive) -> Self;
     fn into_primitive(self) -> Self::Primitive;
 }
+pub mod bits;
+pub mod checksum;
+mod constant;
+pub use constant::Constant;
+mod packed;
+pub use packed::PackedArray;
+#[cfg(feature = "std")]
+pub mod io;
 #[cfg(feature = "hex_fns")]
 mod hex;
 #[cfg(feature = "hex_fns")]
 pub use hex::BitfieldHex;
 #[cfg(all(feature = "hex_fns", feature = "dyn_fns"))]
 pub use hex::BitfieldHexDyn;
+#[cfg(feature = "serde")]
+pub mod serde_bytes_form;
+// used by the code `#[bondrewd(serde)]` generates.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
 
 // re-export the derive stuff
 #[cfg(feature = "derive")]
@@ -0,0 +1,109 @@
+use core::marker::PhantomData;
+
+use crate::bits::{BitReader, BitWriter, Endianness};
+use crate::{BitfieldLengthError, Bitfields};
+
+/// `N` records of `T` stored back to back within a byte container `B` using only
+/// [Bitfields::BIT_SIZE] bits each, instead of padding each record to whole bytes.
+///
+/// Record `i` occupies bits `i * T::BIT_SIZE` through `((i + 1) * T::BIT_SIZE) - 1` of the container.
+/// A record's bits are the first `T::BIT_SIZE` bits of its bitfield form, so `T` must not use
+/// `reverse` unless its `BIT_SIZE` is a multiple of 8.
+///
+/// The amount of bytes needed can be computed in a const context to size an owned container, ex.
+/// `const BYTES: usize = (N * Record::BIT_SIZE).div_ceil(8);` then `PackedArray<Record, N, [u8; BYTES]>`.
+pub struct PackedArray<T, const N: usize, B> {
+    buffer: B,
+    record: PhantomData<T>,
+}
+
+impl<T, const N: usize, B: AsRef<[u8]>> PackedArray<T, N, B> {
+    /// Returns the amount of bytes `N` records of `T` take when packed.
+    pub fn byte_size<const SIZE: usize>() -> usize
+    where
+        T: Bitfields<SIZE>,
+    {
+        (N * T::BIT_SIZE).div_ceil(8)
+    }
+    /// Wraps `buffer` if it contains enough bytes for `N` packed records, otherwise a
+    /// [BitfieldLengthError] is returned.
+    pub fn new<const SIZE: usize>(buffer: B) -> Result<Self, BitfieldLengthError>
+    where
+        T: Bitfields<SIZE>,
+    {
+        let buf_len = buffer.as_ref().len();
+        let byte_size = Self::byte_size();
+        if buf_len >= byte_size {
+            Ok(Self {
+                buffer,
+                record: PhantomData,
+            })
+        } else {
+            Err(BitfieldLengthError(buf_len, byte_size))
+        }
+    }
+    /// Returns the amount of records, `N`.
+    pub const fn len(&self) -> usize {
+        N
+    }
+    /// Returns true if `N` is 0.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+    /// Returns the record at `index`, or `None` if `index` is not less than `N`.
+    pub fn get<const SIZE: usize>(&self, index: usize) -> Option<T>
+    where
+        T: Bitfields<SIZE>,
+    {
+        if index >= N {
+            return None;
+        }
+        let reader = BitReader::new(self.buffer.as_ref());
+        let mut output_byte_buffer = [0u8; SIZE];
+        let mut writer = BitWriter::new(&mut output_byte_buffer);
+        let start = index * T::BIT_SIZE;
+        let mut copied = 0;
+        while copied < T::BIT_SIZE {
+            let amount = (T::BIT_SIZE - copied).min(8);
+            let bits: u8 = reader
+                .peek_at(start + copied, amount, Endianness::Big)
+                .ok()?;
+            writer.write(bits, amount, Endianness::Big).ok()?;
+            copied += amount;
+        }
+        Some(T::from_bytes(output_byte_buffer))
+    }
+    /// Returns the wrapped byte container.
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+}
+
+impl<T, const N: usize, B: AsRef<[u8]> + AsMut<[u8]>> PackedArray<T, N, B> {
+    /// Overwrites the record at `index` with `value`, other records are left untouched.
+    ///
+    /// # Panics
+    /// If `index` is not less than `N`.
+    pub fn set<const SIZE: usize>(&mut self, index: usize, value: &T)
+    where
+        T: Bitfields<SIZE>,
+    {
+        assert!(index < N, "index {index} is out of bounds for a PackedArray of {N} records");
+        let input_byte_buffer = value.to_bytes();
+        let reader = BitReader::new(&input_byte_buffer);
+        let mut writer = BitWriter::new(self.buffer.as_mut());
+        writer.seek(index * T::BIT_SIZE);
+        let mut copied = 0;
+        while copied < T::BIT_SIZE {
+            let amount = (T::BIT_SIZE - copied).min(8);
+            // the buffer length was checked when the PackedArray was created.
+            let bits: u8 = reader
+                .peek_at(copied, amount, Endianness::Big)
+                .expect("record is smaller than its BIT_SIZE");
+            writer
+                .write(bits, amount, Endianness::Big)
+                .expect("PackedArray buffer is too small");
+            copied += amount;
+        }
+    }
+}
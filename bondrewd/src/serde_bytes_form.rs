@@ -0,0 +1,189 @@
+//! (De)serializes any [Bitfields] type through its bitfield form, for use with
+//! `#[serde(with = "bondrewd::serde_bytes_form")]`.
+//!
+//! Human readable formats (ex. JSON) use a hex string, other formats (ex. CBOR) use bytes. The
+//! [hex] and [bytes] modules can be used instead to always use one form.
+//! ```
+//! use bondrewd::Bitfields;
+//! # struct Packet;
+//! # impl Bitfields<1> for Packet {
+//! #     const BIT_SIZE: usize = 8;
+//! #     fn write_to(&self, output_byte_buffer: &mut [u8; 1]) { *output_byte_buffer = [0] }
+//! #     fn from_bytes(input_byte_buffer: [u8; 1]) -> Self { Packet }
+//! # }
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Config {
+//!     #[serde(with = "bondrewd::serde_bytes_form")]
+//!     packet: Packet,
+//! }
+//! ```
+use core::fmt;
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserializer, Serializer};
+
+use crate::Bitfields;
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+/// Serializes `value` as a hex string for human readable formats, otherwise as bytes.
+///
+/// # Errors
+/// Returns the error from `serializer`.
+pub fn serialize<T, S, const SIZE: usize>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Bitfields<SIZE>,
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        hex::serialize(value, serializer)
+    } else {
+        bytes::serialize(value, serializer)
+    }
+}
+
+/// Deserializes a hex string for human readable formats, otherwise bytes.
+///
+/// # Errors
+/// Returns an error if the input is not exactly `SIZE` bytes (`SIZE * 2` hex characters).
+pub fn deserialize<'de, T, D, const SIZE: usize>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Bitfields<SIZE>,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        hex::deserialize(deserializer)
+    } else {
+        bytes::deserialize(deserializer)
+    }
+}
+
+/// Always (de)serializes the bitfield form as bytes.
+pub mod bytes {
+    use super::*;
+
+    /// Serializes `value` as bytes.
+    ///
+    /// # Errors
+    /// Returns the error from `serializer`.
+    pub fn serialize<T, S, const SIZE: usize>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Bitfields<SIZE>,
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&value.to_bytes())
+    }
+
+    /// Deserializes bytes, a sequence of bytes is also accepted.
+    ///
+    /// # Errors
+    /// Returns an error if the input is not exactly `SIZE` bytes.
+    pub fn deserialize<'de, T, D, const SIZE: usize>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Bitfields<SIZE>,
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_bytes(BytesVisitor::<SIZE>)
+            .map(T::from_bytes)
+    }
+
+    struct BytesVisitor<const SIZE: usize>;
+
+    impl<'de, const SIZE: usize> Visitor<'de> for BytesVisitor<SIZE> {
+        type Value = [u8; SIZE];
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{SIZE} bytes")
+        }
+        fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            v.try_into()
+                .map_err(|_| E::invalid_length(v.len(), &self))
+        }
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut input_byte_buffer = [0u8; SIZE];
+            for (i, byte) in input_byte_buffer.iter_mut().enumerate() {
+                *byte = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+            }
+            if seq.next_element::<u8>()?.is_some() {
+                return Err(A::Error::invalid_length(SIZE + 1, &self));
+            }
+            Ok(input_byte_buffer)
+        }
+    }
+}
+
+/// Always (de)serializes the bitfield form as a lower case hex string. Upper case hex is also
+/// accepted when deserializing.
+pub mod hex {
+    use super::*;
+
+    struct HexDisplay<'a>(&'a [u8]);
+
+    impl fmt::Display for HexDisplay<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for byte in self.0 {
+                let chars = [
+                    HEX_CHARS[(byte >> 4) as usize],
+                    HEX_CHARS[(byte & 0b0000_1111) as usize],
+                ];
+                // both characters are always ascii.
+                f.write_str(core::str::from_utf8(&chars).map_err(|_| fmt::Error)?)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Serializes `value` as a hex string.
+    ///
+    /// # Errors
+    /// Returns the error from `serializer`.
+    pub fn serialize<T, S, const SIZE: usize>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Bitfields<SIZE>,
+        S: Serializer,
+    {
+        serializer.collect_str(&HexDisplay(&value.to_bytes()))
+    }
+
+    /// Deserializes a hex string.
+    ///
+    /// # Errors
+    /// Returns an error if the input is not exactly `SIZE * 2` hex characters.
+    pub fn deserialize<'de, T, D, const SIZE: usize>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Bitfields<SIZE>,
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_str(HexVisitor::<SIZE>)
+            .map(T::from_bytes)
+    }
+
+    struct HexVisitor<const SIZE: usize>;
+
+    impl<'de, const SIZE: usize> Visitor<'de> for HexVisitor<SIZE> {
+        type Value = [u8; SIZE];
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a hex string of {SIZE} bytes")
+        }
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            let hex = v.as_bytes();
+            if hex.len() != SIZE * 2 {
+                return Err(E::invalid_length(hex.len(), &self));
+            }
+            let decode_nibble = |c: u8| match c {
+                b'A'..=b'F' => Ok(c - b'A' + 10u8),
+                b'a'..=b'f' => Ok(c - b'a' + 10u8),
+                b'0'..=b'9' => Ok(c - b'0'),
+                _ => Err(E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+            };
+            let mut input_byte_buffer = [0u8; SIZE];
+            for (byte, pair) in input_byte_buffer.iter_mut().zip(hex.chunks_exact(2)) {
+                *byte = (decode_nibble(pair[0])? << 4) | decode_nibble(pair[1])?;
+            }
+            Ok(input_byte_buffer)
+        }
+    }
+}